@@ -0,0 +1,79 @@
+//! 큰 분석 결과에서 복사하는 API와 빌려 보는 반복자를 비교한다.
+//!
+//! `cargo bench --bench tagged_iter`
+
+use std::hint::black_box;
+
+use bareun_rs::Tagged;
+use bareun_rs::bareun::AnalyzeSyntaxResponse;
+use bareun_rs::mock::analyze_syntax_response;
+use bareun_rs::model::Tag;
+use criterion::{Criterion, criterion_group, criterion_main};
+
+/// 어절 `n_sentences * 3`개짜리 가짜 응답
+fn large_response(n_sentences: usize) -> Tagged {
+    let sentence = "나비가 허리에 앉았다";
+    let tokens: &[(&str, &[(&str, &str)])] = &[
+        ("나비가", &[("나비", "NNG"), ("가", "JKS")]),
+        ("허리에", &[("허리", "NNG"), ("에", "JKB")]),
+        ("앉았다", &[("앉", "VV"), ("았", "EP"), ("다", "EF")]),
+    ];
+    let one = analyze_syntax_response(sentence, tokens);
+    let res = AnalyzeSyntaxResponse {
+        sentences: std::iter::repeat_n(one.sentences[0].clone(), n_sentences).collect(),
+        ..one
+    };
+    let phrase = vec![sentence; n_sentences].join("\n");
+    Tagged::new(phrase, res)
+}
+
+fn bench_iteration(c: &mut Criterion) {
+    let tagged = large_response(20_000);
+    let mut group = c.benchmark_group("tagged_20k_sentences");
+
+    group.bench_function("sentences_clone", |b| {
+        b.iter(|| {
+            tagged
+                .sentences()
+                .iter()
+                .map(|s| black_box(s).tokens.len())
+                .sum::<usize>()
+        })
+    });
+    group.bench_function("sentences_iter", |b| {
+        b.iter(|| {
+            tagged
+                .sentences_iter()
+                .map(|s| black_box(s).raw().tokens.len())
+                .sum::<usize>()
+        })
+    });
+
+    group.bench_function("nouns_vec", |b| b.iter(|| black_box(tagged.nouns()).len()));
+    group.bench_function("nouns_iter", |b| {
+        b.iter(|| {
+            tagged
+                .morphemes()
+                .filter(|m| m.tag() == Tag::Nng)
+                .map(|m| black_box(m.text()).len())
+                .sum::<usize>()
+        })
+    });
+
+    group.bench_function("sentences_clone_then_morphs", |b| {
+        b.iter(|| {
+            tagged
+                .sentences()
+                .iter()
+                .flat_map(|s| &s.tokens)
+                .flat_map(|t| &t.morphemes)
+                .count()
+        })
+    });
+    group.bench_function("morphemes_iter", |b| b.iter(|| tagged.morphemes().count()));
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_iteration);
+criterion_main!(benches);
@@ -5,8 +5,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
     }
 
+    // 서버 코드는 `mock-server` 기능에서만 필요하다.
+    let build_server = std::env::var_os("CARGO_FEATURE_MOCK_SERVER").is_some();
+
     tonic_prost_build::configure()
-        .build_server(false)
+        .build_server(build_server)
         .type_attribute(".", "#[derive(serde::Serialize, serde::Deserialize)]")
         .compile_protos(
             &[
@@ -0,0 +1,198 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Duration;
+
+use tokio_stream::{Stream, StreamExt};
+use tonic::Status;
+
+use crate::bareun::{
+    AnalyzeSyntaxListRequest, AnalyzeSyntaxListResponse, AnalyzeSyntaxRequest,
+    AnalyzeSyntaxResponse, CorrectErrorRequest, CorrectErrorResponse, StreamCorrectErrorRequest,
+    StreamCorrectErrorResponse, TokenizeRequest, TokenizeResponse,
+};
+use crate::config::{CallOptions, status_error};
+use crate::error::Result;
+use crate::lang_service_client::BareunLanguageServiceClient;
+use crate::revision_service_client::{BareunRevisionServiceClient, STREAM_CORRECT_ERROR};
+
+/// 백엔드 메서드가 돌려주는 future
+pub type BackendFuture<'a, T> = Pin<Box<dyn Future<Output = Result<T>> + Send + 'a>>;
+
+/// 스트리밍 교정 응답. 스트림 도중 받은 오류도 `BareunError`로 바뀌어 있다.
+pub type RevisionStream = Pin<Box<dyn Stream<Item = Result<StreamCorrectErrorResponse>> + Send>>;
+
+/// `Tagger`와 `Tokenizer`가 요청을 보내는 곳
+///
+/// 기본 구현은 gRPC 클라이언트(`BareunLanguageServiceClient`)다. 가짜 구현, 캐시, 기록기 등
+/// 다른 구현을 `Tagger::with_backend`, `Tokenizer::with_backend`로 넣을 수 있다.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use bareun_rs::bareun::{AnalyzeSyntaxRequest, AnalyzeSyntaxResponse};
+/// use bareun_rs::{BackendFuture, CallOptions, LanguageBackend, Tagger};
+///
+/// struct Fake;
+///
+/// impl LanguageBackend for Fake {
+///     fn analyze_syntax<'a>(
+///         &'a self,
+///         request: AnalyzeSyntaxRequest,
+///         _options: &'a CallOptions,
+///     ) -> BackendFuture<'a, AnalyzeSyntaxResponse> {
+///         Box::pin(async move { Ok(AnalyzeSyntaxResponse::default()) })
+///     }
+///     // analyze_syntax_list, tokenize ...
+/// }
+///
+/// let tagger = Tagger::with_backend(Fake, vec![]);
+/// ```
+pub trait LanguageBackend: Send + Sync {
+    /// 형태소 분석
+    fn analyze_syntax<'a>(
+        &'a self,
+        request: AnalyzeSyntaxRequest,
+        options: &'a CallOptions,
+    ) -> BackendFuture<'a, AnalyzeSyntaxResponse>;
+
+    /// 문장 단위가 입력과 일치하는 형태소 분석
+    fn analyze_syntax_list<'a>(
+        &'a self,
+        request: AnalyzeSyntaxListRequest,
+        options: &'a CallOptions,
+    ) -> BackendFuture<'a, AnalyzeSyntaxListResponse>;
+
+    /// 토크나이즈
+    fn tokenize<'a>(
+        &'a self,
+        request: TokenizeRequest,
+        options: &'a CallOptions,
+    ) -> BackendFuture<'a, TokenizeResponse>;
+
+    /// 요청을 받을 수 있는지 확인한다. 기본 구현은 항상 준비되어 있다.
+    fn ready(&self) -> BackendFuture<'_, ()> {
+        Box::pin(async { Ok(()) })
+    }
+
+    /// 준비될 때까지 최대 `timeout` 동안 기다린다. 기본 구현은 `ready`를 한 번 부른다.
+    fn wait_ready(&self, timeout: Duration) -> BackendFuture<'_, ()> {
+        let _ = timeout;
+        self.ready()
+    }
+}
+
+/// `Corrector`가 요청을 보내는 곳
+///
+/// 기본 구현은 gRPC 클라이언트(`BareunRevisionServiceClient`)다. 스트리밍 응답을 흉내 낼 때는
+/// `stream_from_messages`로 `RevisionStream`을 만든다.
+pub trait RevisionBackend: Send + Sync {
+    /// 맞춤법 교정
+    fn correct_error<'a>(
+        &'a self,
+        request: CorrectErrorRequest,
+        options: &'a CallOptions,
+    ) -> BackendFuture<'a, CorrectErrorResponse>;
+
+    /// 스트리밍 맞춤법 교정
+    fn stream_correct_error<'a>(
+        &'a self,
+        request: StreamCorrectErrorRequest,
+        options: &'a CallOptions,
+    ) -> BackendFuture<'a, RevisionStream>;
+
+    /// 요청을 받을 수 있는지 확인한다. 기본 구현은 항상 준비되어 있다.
+    fn ready(&self) -> BackendFuture<'_, ()> {
+        Box::pin(async { Ok(()) })
+    }
+
+    /// 준비될 때까지 최대 `timeout` 동안 기다린다. 기본 구현은 `ready`를 한 번 부른다.
+    fn wait_ready(&self, timeout: Duration) -> BackendFuture<'_, ()> {
+        let _ = timeout;
+        self.ready()
+    }
+}
+
+/// 메시지 목록으로 스트리밍 교정 응답을 만든다. `status`가 있으면 메시지를 다 보낸 뒤
+/// gRPC 클라이언트와 같은 규칙으로 바꾼 오류(`ServerUnavailable`, `PermissionDenied` 등)로 끝난다.
+///
+/// 실제 서버가 없으므로 `ServerUnavailable`의 `host`는 비어 있고 `port`는 0이다.
+pub fn stream_from_messages(
+    messages: Vec<StreamCorrectErrorResponse>,
+    status: Option<Status>,
+) -> RevisionStream {
+    Box::pin(crate::cassette::replay_stream(messages, status).map(|r| {
+        r.map_err(|e| status_error(&e, STREAM_CORRECT_ERROR.path, None, "", String::new(), 0))
+    }))
+}
+
+impl LanguageBackend for BareunLanguageServiceClient {
+    fn analyze_syntax<'a>(
+        &'a self,
+        request: AnalyzeSyntaxRequest,
+        options: &'a CallOptions,
+    ) -> BackendFuture<'a, AnalyzeSyntaxResponse> {
+        Box::pin(self.send_analyze_syntax(request, options))
+    }
+
+    fn analyze_syntax_list<'a>(
+        &'a self,
+        request: AnalyzeSyntaxListRequest,
+        options: &'a CallOptions,
+    ) -> BackendFuture<'a, AnalyzeSyntaxListResponse> {
+        Box::pin(self.send_analyze_syntax_list(request, options))
+    }
+
+    fn tokenize<'a>(
+        &'a self,
+        request: TokenizeRequest,
+        options: &'a CallOptions,
+    ) -> BackendFuture<'a, TokenizeResponse> {
+        Box::pin(self.send_tokenize(request, options))
+    }
+
+    fn ready(&self) -> BackendFuture<'_, ()> {
+        Box::pin(BareunLanguageServiceClient::ready(self))
+    }
+
+    fn wait_ready(&self, timeout: Duration) -> BackendFuture<'_, ()> {
+        Box::pin(BareunLanguageServiceClient::wait_ready(self, timeout))
+    }
+}
+
+impl RevisionBackend for BareunRevisionServiceClient {
+    fn correct_error<'a>(
+        &'a self,
+        request: CorrectErrorRequest,
+        options: &'a CallOptions,
+    ) -> BackendFuture<'a, CorrectErrorResponse> {
+        Box::pin(self.correct_error_with(request, options))
+    }
+
+    fn stream_correct_error<'a>(
+        &'a self,
+        request: StreamCorrectErrorRequest,
+        options: &'a CallOptions,
+    ) -> BackendFuture<'a, RevisionStream> {
+        Box::pin(async move {
+            let (streaming, (host, port)) =
+                self.stream_correct_error_picked(request, options).await?;
+            let config = self.config.clone();
+            let timeout = config.timeout_for(options);
+            // 스트림 도중의 오류도 스트림을 연 서버의 것으로 보고한다.
+            let stream: RevisionStream = Box::pin(streaming.map(move |r| {
+                r.map_err(|e| {
+                    config.handle_grpc_error(&e, &STREAM_CORRECT_ERROR, timeout, host.clone(), port)
+                })
+            }));
+            Ok(stream)
+        })
+    }
+
+    fn ready(&self) -> BackendFuture<'_, ()> {
+        Box::pin(BareunRevisionServiceClient::ready(self))
+    }
+
+    fn wait_ready(&self, timeout: Duration) -> BackendFuture<'_, ()> {
+        Box::pin(BareunRevisionServiceClient::wait_ready(self, timeout))
+    }
+}
@@ -0,0 +1,261 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use tonic::transport::Channel;
+
+/// 여러 서버 중 요청을 보낼 곳을 고르는 방법
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BalanceStrategy {
+    /// 정상 서버를 차례로 돌아가며 사용한다.
+    #[default]
+    RoundRobin,
+    /// 처리 중인 요청이 가장 적은 서버를 사용한다.
+    LeastOutstanding,
+}
+
+/// 여러 서버를 사용할 때의 부하 분산 설정
+///
+/// `Unavailable`을 돌려준 서버는 `eject_cooldown` 동안 후보에서 빠졌다가 다시 사용된다.
+/// 모든 서버가 빠져 있으면 가장 먼저 복귀할 서버로 요청을 보낸다.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use std::time::Duration;
+/// use bareun_rs::{BalanceStrategy, BareunClientConfig, LoadBalancing};
+///
+/// let config = BareunClientConfig::builder("YOUR_API_KEY")
+///     .endpoint("10.0.0.1", 5656)
+///     .endpoint("10.0.0.2", 5656)
+///     .load_balancing(
+///         LoadBalancing::new(BalanceStrategy::LeastOutstanding)
+///             .eject_cooldown(Duration::from_secs(10)),
+///     )
+///     .build();
+/// ```
+#[derive(Clone, Debug)]
+pub struct LoadBalancing {
+    pub strategy: BalanceStrategy,
+    /// `Unavailable`을 돌려준 서버를 후보에서 빼 두는 시간
+    pub eject_cooldown: Duration,
+}
+
+impl Default for LoadBalancing {
+    fn default() -> Self {
+        LoadBalancing {
+            strategy: BalanceStrategy::default(),
+            eject_cooldown: Duration::from_secs(30),
+        }
+    }
+}
+
+impl LoadBalancing {
+    pub fn new(strategy: BalanceStrategy) -> Self {
+        LoadBalancing {
+            strategy,
+            ..Default::default()
+        }
+    }
+
+    pub fn strategy(mut self, strategy: BalanceStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    pub fn eject_cooldown(mut self, cooldown: Duration) -> Self {
+        self.eject_cooldown = cooldown;
+        self
+    }
+}
+
+/// `EndpointPool::status`가 돌려주는 서버 하나의 상태
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EndpointStatus {
+    pub host: String,
+    pub port: u16,
+    /// 처리 중인 요청 수
+    pub outstanding: usize,
+    /// `Unavailable`로 후보에서 빠져 있는지 여부
+    pub ejected: bool,
+}
+
+struct PoolEndpoint {
+    host: String,
+    port: u16,
+    channel: Channel,
+    outstanding: AtomicUsize,
+    ejected_until: Mutex<Option<Instant>>,
+}
+
+impl PoolEndpoint {
+    fn ejected_until(&self, now: Instant) -> Option<Instant> {
+        let mut until = self.ejected_until.lock().unwrap_or_else(|e| e.into_inner());
+        match *until {
+            Some(t) if t > now => Some(t),
+            Some(_) => {
+                // 냉각 시간이 지나면 다시 후보로 넣는다.
+                *until = None;
+                None
+            }
+            None => None,
+        }
+    }
+}
+
+struct PoolInner {
+    endpoints: Vec<PoolEndpoint>,
+    balancing: LoadBalancing,
+    next: AtomicUsize,
+}
+
+/// 서버별 gRPC 채널 묶음
+///
+/// 세션과 모든 서비스 클라이언트가 복제해 함께 사용하므로, 서버 상태(처리 중인 요청 수,
+/// 제외 여부)는 형태소 분석·맞춤법 교정·사용자 사전 호출 사이에 공유된다.
+#[derive(Clone)]
+pub struct EndpointPool {
+    inner: Arc<PoolInner>,
+}
+
+impl EndpointPool {
+    /// 서버 하나만 사용하는 풀
+    pub fn single(host: &str, port: u16, channel: Channel) -> Self {
+        Self::new(
+            vec![(host.to_string(), port, channel)],
+            LoadBalancing::default(),
+        )
+    }
+
+    pub(crate) fn new(endpoints: Vec<(String, u16, Channel)>, balancing: LoadBalancing) -> Self {
+        let endpoints = endpoints
+            .into_iter()
+            .map(|(host, port, channel)| PoolEndpoint {
+                host,
+                port,
+                channel,
+                outstanding: AtomicUsize::new(0),
+                ejected_until: Mutex::new(None),
+            })
+            .collect();
+        EndpointPool {
+            inner: Arc::new(PoolInner {
+                endpoints,
+                balancing,
+                next: AtomicUsize::new(0),
+            }),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.inner.endpoints.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inner.endpoints.is_empty()
+    }
+
+    /// 첫 번째 서버의 채널
+    pub fn primary_channel(&self) -> Channel {
+        self.inner.endpoints[0].channel.clone()
+    }
+
+    /// 각 서버의 현재 상태
+    pub fn status(&self) -> Vec<EndpointStatus> {
+        let now = Instant::now();
+        self.inner
+            .endpoints
+            .iter()
+            .map(|e| EndpointStatus {
+                host: e.host.clone(),
+                port: e.port,
+                outstanding: e.outstanding.load(Ordering::SeqCst),
+                ejected: e.ejected_until(now).is_some(),
+            })
+            .collect()
+    }
+
+    /// `index`번째 서버를 냉각 시간 동안 후보에서 뺀다.
+    pub(crate) fn eject(&self, index: usize) {
+        let cooldown = self.inner.balancing.eject_cooldown;
+        *self.inner.endpoints[index]
+            .ejected_until
+            .lock()
+            .unwrap_or_else(|e| e.into_inner()) = Some(Instant::now() + cooldown);
+    }
+
+    /// 전략에 따라 다음 요청을 보낼 서버를 고른다.
+    pub(crate) fn pick(&self) -> PickedEndpoint {
+        let inner = &self.inner;
+        let now = Instant::now();
+        let ejected: Vec<Option<Instant>> = inner
+            .endpoints
+            .iter()
+            .map(|e| e.ejected_until(now))
+            .collect();
+        let mut healthy: Vec<usize> = (0..inner.endpoints.len())
+            .filter(|&i| ejected[i].is_none())
+            .collect();
+        if healthy.is_empty() {
+            let earliest = (0..inner.endpoints.len())
+                .min_by_key(|&i| ejected[i])
+                .unwrap_or(0);
+            healthy.push(earliest);
+        }
+
+        let start = inner.next.fetch_add(1, Ordering::Relaxed) % healthy.len();
+        let index = match inner.balancing.strategy {
+            BalanceStrategy::RoundRobin => healthy[start],
+            BalanceStrategy::LeastOutstanding => {
+                // 같은 수라면 돌아가며 고르도록 시작 위치를 바꾼다.
+                let rotated = healthy[start..].iter().chain(&healthy[..start]);
+                *rotated
+                    .min_by_key(|&&i| inner.endpoints[i].outstanding.load(Ordering::SeqCst))
+                    .unwrap_or(&healthy[start])
+            }
+        };
+
+        inner.endpoints[index]
+            .outstanding
+            .fetch_add(1, Ordering::SeqCst);
+        PickedEndpoint {
+            pool: self.clone(),
+            index,
+        }
+    }
+}
+
+/// 고른 서버. drop되면 처리 중인 요청 수를 줄인다.
+pub(crate) struct PickedEndpoint {
+    pool: EndpointPool,
+    index: usize,
+}
+
+impl PickedEndpoint {
+    fn endpoint(&self) -> &PoolEndpoint {
+        &self.pool.inner.endpoints[self.index]
+    }
+
+    pub(crate) fn channel(&self) -> Channel {
+        self.endpoint().channel.clone()
+    }
+
+    pub(crate) fn host(&self) -> &str {
+        &self.endpoint().host
+    }
+
+    pub(crate) fn port(&self) -> u16 {
+        self.endpoint().port
+    }
+
+    /// 서버를 냉각 시간 동안 후보에서 뺀다.
+    pub(crate) fn eject(&self) {
+        self.pool.eject(self.index);
+    }
+}
+
+impl Drop for PickedEndpoint {
+    fn drop(&mut self) {
+        self.endpoint().outstanding.fetch_sub(1, Ordering::SeqCst);
+    }
+}
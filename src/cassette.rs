@@ -0,0 +1,425 @@
+use std::collections::{HashMap, VecDeque};
+use std::convert::Infallible;
+use std::fmt;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::task::{Context, Poll};
+
+use http_body::Frame;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tonic::codec::Codec;
+use tonic::codegen::Bytes;
+use tonic::codegen::http::{HeaderMap, StatusCode};
+use tonic::{Code, Status, Streaming};
+
+use crate::config::{BareunClientConfig, CallOptions, RpcMethod};
+use crate::error::{BareunError, Result};
+
+const CASSETTE_VERSION: u32 = 1;
+
+/// 카세트를 기록하는지 재생하는지
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CassetteMode {
+    /// 실제 서버에 요청하고, 요청과 응답을 파일에 기록한다.
+    Record,
+    /// 서버에 연결하지 않고 파일에 기록된 응답을 돌려준다.
+    Replay,
+}
+
+/// 서버가 돌려준 gRPC 오류
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct RecordedStatus {
+    code: i32,
+    message: String,
+}
+
+impl RecordedStatus {
+    fn to_status(&self) -> Status {
+        Status::new(Code::from_i32(self.code), self.message.clone())
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum Outcome {
+    Response(Value),
+    Stream {
+        messages: Vec<Value>,
+        /// 스트림이 오류로 끝났을 때의 상태
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        status: Option<RecordedStatus>,
+    },
+    Error(RecordedStatus),
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct Interaction {
+    method: String,
+    request: Value,
+    #[serde(flatten)]
+    outcome: Outcome,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CassetteFile {
+    version: u32,
+    interactions: Vec<Interaction>,
+}
+
+struct State {
+    interactions: Vec<Interaction>,
+    /// 같은 요청이 여러 번 기록되었을 때 다음에 재생할 순번
+    cursors: HashMap<String, usize>,
+}
+
+struct Inner {
+    path: PathBuf,
+    mode: CassetteMode,
+    state: Mutex<State>,
+}
+
+/// 요청과 응답을 파일에 기록하고 다시 재생하는 카세트
+///
+/// `BareunClientConfigBuilder::cassette`로 지정하면 형태소 분석, 토크나이즈, 맞춤법 교정(스트리밍 포함),
+/// 사용자 사전 RPC가 모두 카세트를 거친다. 요청은 메서드와 요청 메시지 전체(문장, 사용자 사전 이름,
+/// `RevisionConfig` 등)가 같아야 일치한다. 같은 요청이 여러 번 기록되었으면 기록된 순서대로 재생하고,
+/// 다 쓰면 마지막 응답을 반복한다. 일치하는 기록이 없으면 `BareunError::CassetteMiss`를 돌려준다.
+///
+/// 기록 모드에서 스트리밍 응답은 끝까지 받은 뒤 기록하고 돌려준다.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use bareun_rs::{BareunClientConfig, Cassette, Tagger};
+///
+/// // 서버가 있는 곳에서 한 번 기록한다.
+/// let config = BareunClientConfig::builder("YOUR_API_KEY")
+///     .host("localhost")
+///     .cassette(Cassette::record("tests/cassettes/tagger.json"))
+///     .build();
+/// let tagger = Tagger::from_config(config, vec![]).await?;
+/// tagger.morphs("오늘은 정말 추운 날이네요.").await?;
+///
+/// // CI에서는 서버 없이 재생한다.
+/// let config = BareunClientConfig::builder("YOUR_API_KEY")
+///     .cassette(Cassette::replay("tests/cassettes/tagger.json")?)
+///     .build();
+/// let tagger = Tagger::from_config(config, vec![]).await?;
+/// ```
+#[derive(Clone)]
+pub struct Cassette {
+    inner: Arc<Inner>,
+}
+
+impl Cassette {
+    /// 요청과 응답을 `path`에 기록하는 카세트. 파일이 이미 있으면 첫 기록 때 덮어쓴다.
+    pub fn record(path: impl AsRef<Path>) -> Self {
+        Self::with_interactions(path.as_ref(), CassetteMode::Record, Vec::new())
+    }
+
+    /// `path`에 기록된 응답을 재생하는 카세트
+    ///
+    /// # Errors
+    ///
+    /// 파일을 읽을 수 없으면 `BareunError::CassetteIo`, 형식이 잘못되었으면
+    /// `BareunError::SerializationError`를 돌려준다.
+    pub fn replay(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let data = std::fs::read(path).map_err(|source| BareunError::CassetteIo {
+            path: path.display().to_string(),
+            source,
+        })?;
+        let file: CassetteFile = serde_json::from_slice(&data)?;
+        Ok(Self::with_interactions(
+            path,
+            CassetteMode::Replay,
+            file.interactions,
+        ))
+    }
+
+    fn with_interactions(path: &Path, mode: CassetteMode, interactions: Vec<Interaction>) -> Self {
+        Cassette {
+            inner: Arc::new(Inner {
+                path: path.to_path_buf(),
+                mode,
+                state: Mutex::new(State {
+                    interactions,
+                    cursors: HashMap::new(),
+                }),
+            }),
+        }
+    }
+
+    pub fn mode(&self) -> CassetteMode {
+        self.inner.mode
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.inner.path
+    }
+
+    /// 기록된 요청-응답 쌍의 수
+    pub fn len(&self) -> usize {
+        self.lock().interactions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 지금까지 기록한 내용을 파일에 쓴다. 기록 모드에서는 요청마다 자동으로 불린다.
+    pub fn save(&self) -> Result<()> {
+        let file = CassetteFile {
+            version: CASSETTE_VERSION,
+            interactions: self.lock().interactions.clone(),
+        };
+        let data = serde_json::to_vec_pretty(&file)?;
+        std::fs::write(&self.inner.path, data).map_err(|source| BareunError::CassetteIo {
+            path: self.inner.path.display().to_string(),
+            source,
+        })
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.inner.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn push(&self, method: &RpcMethod, request: Value, outcome: Outcome) -> Result<()> {
+        self.lock().interactions.push(Interaction {
+            method: method.path.to_string(),
+            request,
+            outcome,
+        });
+        self.save()
+    }
+
+    /// 서버가 돌려준 오류만 기록한다. 연결 실패처럼 서버 응답이 없는 오류와, 연결 실패와
+    /// 구별할 수 없는 `Unavailable`은 기록하지 않는다.
+    fn record_error(&self, method: &RpcMethod, request: Value, e: &BareunError) -> Result<()> {
+        match e.status() {
+            Some(status) if status.code != Code::Unavailable => self.push(
+                method,
+                request,
+                Outcome::Error(RecordedStatus {
+                    code: status.code as i32,
+                    message: status.message.clone(),
+                }),
+            ),
+            _ => Ok(()),
+        }
+    }
+
+    fn find(&self, method: &RpcMethod, request: &Value) -> Result<Outcome> {
+        let key = format!("{} {}", method.path, request);
+        let mut state = self.lock();
+        let matches: Vec<usize> = state
+            .interactions
+            .iter()
+            .enumerate()
+            .filter(|(_, i)| i.method == method.path && &i.request == request)
+            .map(|(n, _)| n)
+            .collect();
+        let Some(&last) = matches.last() else {
+            return Err(BareunError::CassetteMiss {
+                path: self.inner.path.display().to_string(),
+                method: method.path,
+                request: request.to_string(),
+            });
+        };
+
+        let cursor = state.cursors.entry(key).or_insert(0);
+        let index = matches.get(*cursor).copied().unwrap_or(last);
+        *cursor += 1;
+        Ok(state.interactions[index].outcome.clone())
+    }
+
+    fn miss(&self, method: &RpcMethod, request: &Value, expected: &str) -> BareunError {
+        BareunError::CassetteMiss {
+            path: self.inner.path.display().to_string(),
+            method: method.path,
+            request: format!("{} (recorded interaction is not a {})", request, expected),
+        }
+    }
+
+    /// 단항 RPC를 기록하거나 재생한다. 재생할 때는 `live`를 실행하지 않는다.
+    pub(crate) async fn unary<M, T, Fut>(
+        &self,
+        config: &BareunClientConfig,
+        method: &RpcMethod,
+        request: &M,
+        options: &CallOptions,
+        live: Fut,
+    ) -> Result<T>
+    where
+        M: Serialize,
+        T: Serialize + DeserializeOwned,
+        Fut: Future<Output = Result<T>>,
+    {
+        let request = serde_json::to_value(request)?;
+        match self.inner.mode {
+            CassetteMode::Replay => match self.find(method, &request)? {
+                Outcome::Response(value) => Ok(serde_json::from_value(value)?),
+                // 재생할 때는 요청을 보낸 서버가 없으므로 설정한 서버로 보고한다.
+                Outcome::Error(status) => Err(config.handle_grpc_error(
+                    &status.to_status(),
+                    method,
+                    config.timeout_for(options),
+                    config.host.clone(),
+                    config.port,
+                )),
+                Outcome::Stream { .. } => Err(self.miss(method, &request, "unary response")),
+            },
+            CassetteMode::Record => {
+                let result = live.await;
+                match &result {
+                    Ok(res) => self.push(
+                        method,
+                        request,
+                        Outcome::Response(serde_json::to_value(res)?),
+                    )?,
+                    Err(e) => self.record_error(method, request, e)?,
+                }
+                result
+            }
+        }
+    }
+
+    /// 서버 스트리밍 RPC를 기록하거나 재생한다. 스트림과 함께 응답한 서버의 (주소, 포트)를
+    /// 돌려주며, 재생할 때는 설정한 서버를 돌려준다.
+    pub(crate) async fn stream<M, T, Fut>(
+        &self,
+        config: &BareunClientConfig,
+        method: &RpcMethod,
+        request: &M,
+        options: &CallOptions,
+        live: Fut,
+    ) -> Result<(Streaming<T>, (String, u16))>
+    where
+        M: Serialize,
+        T: prost::Message + Default + Serialize + DeserializeOwned + Send + 'static,
+        Fut: Future<Output = Result<(Streaming<T>, (String, u16))>>,
+    {
+        let request = serde_json::to_value(request)?;
+        match self.inner.mode {
+            CassetteMode::Replay => match self.find(method, &request)? {
+                Outcome::Stream { messages, status } => {
+                    let messages = messages
+                        .into_iter()
+                        .map(serde_json::from_value)
+                        .collect::<std::result::Result<Vec<T>, _>>()?;
+                    Ok((
+                        replay_stream(messages, status.map(|s| s.to_status())),
+                        (config.host.clone(), config.port),
+                    ))
+                }
+                Outcome::Error(status) => Err(config.handle_grpc_error(
+                    &status.to_status(),
+                    method,
+                    config.timeout_for(options),
+                    config.host.clone(),
+                    config.port,
+                )),
+                Outcome::Response(_) => Err(self.miss(method, &request, "stream")),
+            },
+            CassetteMode::Record => {
+                let (mut stream, endpoint) = match live.await {
+                    Ok(live) => live,
+                    Err(e) => {
+                        self.record_error(method, request, &e)?;
+                        return Err(e);
+                    }
+                };
+                let mut messages = Vec::new();
+                let mut end = None;
+                loop {
+                    match stream.message().await {
+                        Ok(Some(message)) => messages.push(message),
+                        Ok(None) => break,
+                        Err(status) => {
+                            end = Some(status);
+                            break;
+                        }
+                    }
+                }
+                let outcome = Outcome::Stream {
+                    messages: messages
+                        .iter()
+                        .map(serde_json::to_value)
+                        .collect::<std::result::Result<_, _>>()?,
+                    status: end.as_ref().map(|s| RecordedStatus {
+                        code: s.code() as i32,
+                        message: s.message().to_string(),
+                    }),
+                };
+                self.push(method, request, outcome)?;
+                Ok((replay_stream(messages, end), endpoint))
+            }
+        }
+    }
+}
+
+impl fmt::Debug for Cassette {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Cassette")
+            .field("path", &self.inner.path)
+            .field("mode", &self.inner.mode)
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+/// gRPC 프레임을 차례로 내보내는 응답 본문
+struct ReplayBody(VecDeque<Frame<Bytes>>);
+
+impl http_body::Body for ReplayBody {
+    type Data = Bytes;
+    type Error = Infallible;
+
+    fn poll_frame(
+        mut self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+    ) -> Poll<Option<std::result::Result<Frame<Bytes>, Infallible>>> {
+        Poll::Ready(self.0.pop_front().map(Ok))
+    }
+}
+
+/// 기록된 메시지로 서버가 보낸 것과 같은 `Streaming`을 만든다.
+pub(crate) fn replay_stream<T>(messages: Vec<T>, status: Option<Status>) -> Streaming<T>
+where
+    T: prost::Message + Default + Send + 'static,
+{
+    let mut frames: VecDeque<Frame<Bytes>> = messages
+        .iter()
+        .map(|message| {
+            let encoded = message.encode_to_vec();
+            // 압축하지 않음(0) + 길이(4바이트, big endian) + 메시지
+            let mut frame = Vec::with_capacity(encoded.len() + 5);
+            frame.push(0);
+            frame.extend_from_slice(&(encoded.len() as u32).to_be_bytes());
+            frame.extend_from_slice(&encoded);
+            Frame::data(Bytes::from(frame))
+        })
+        .collect();
+
+    let mut trailers = HeaderMap::new();
+    let status = status.unwrap_or_else(|| Status::ok(""));
+    if status.add_header(&mut trailers).is_err() {
+        Status::internal("invalid recorded status")
+            .add_header(&mut trailers)
+            .ok();
+    }
+    frames.push_back(Frame::trailers(trailers));
+
+    let mut codec = tonic_prost::ProstCodec::<T, T>::default();
+    Streaming::new_response(
+        codec.decoder(),
+        ReplayBody(frames),
+        StatusCode::OK,
+        None,
+        None,
+    )
+}
@@ -0,0 +1,671 @@
+use std::fmt;
+use std::future::Future;
+use std::time::Duration;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use tonic::metadata::{Ascii, MetadataValue};
+use tonic::transport::{Certificate, Channel, ClientTlsConfig, Endpoint};
+use tonic::{Code, Request, Response, Status};
+
+use crate::balancer::{EndpointPool, LoadBalancing};
+use crate::bareun::EncodingType;
+use crate::cassette::{Cassette, CassetteMode};
+use crate::constants::{CA_BUNDLE, MAX_MESSAGE_LENGTH};
+use crate::error::{BareunError, Result, RpcStatus, mask_api_key};
+use crate::retry::{RetryPolicy, with_retry};
+
+/// 호스트를 지정하지 않았을 때 사용하는 공식 서버 주소
+pub const DEFAULT_HOST: &str = "api.bareun.ai";
+/// TLS를 사용하지 않는 자체 서버의 기본 포트
+pub const DEFAULT_PORT: u16 = 5656;
+/// TLS 서버의 기본 포트
+pub const DEFAULT_TLS_PORT: u16 = 443;
+
+/// tonic이 클라이언트 쪽 제한 시간 만료에 붙이는 메시지
+const TIMEOUT_EXPIRED: &str = "Timeout expired";
+
+fn is_official_host(host: &str) -> bool {
+    host.to_lowercase().starts_with(DEFAULT_HOST)
+}
+
+/// 모든 서비스 클라이언트(형태소 분석, 맞춤법 교정, 사용자 사전)가 공유하는 연결 설정
+///
+/// `BareunClientConfig::builder`로 만든다. 지정하지 않은 값은 `build()` 시점에
+/// 다음과 같이 결정된다.
+///
+/// - host: 비어 있으면 `api.bareun.ai`
+/// - tls: `api.bareun.ai`로 시작하는 호스트면 사용, 그 외에는 사용하지 않음
+/// - port: TLS를 사용하면 443, 그렇지 않으면 5656
+///
+/// `endpoint`로 서버를 여러 개 지정하면 요청을 `load_balancing` 설정에 따라 나눠 보낸다.
+/// 이때 host/port는 첫 번째 서버를 가리킨다.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use std::time::Duration;
+/// use bareun_rs::{BareunClientConfig, Tagger};
+///
+/// let config = BareunClientConfig::builder("YOUR_API_KEY")
+///     .host("bareun.example.com")
+///     .tls(true)
+///     .connect_timeout(Duration::from_secs(3))
+///     .build();
+/// let tagger = Tagger::from_config(config, vec![]).await?;
+/// ```
+#[derive(Clone)]
+pub struct BareunClientConfig {
+    pub apikey: String,
+    pub host: String,
+    pub port: u16,
+    /// 요청을 보낼 모든 서버의 (host, port). 첫 번째는 항상 (host, port)이다.
+    pub endpoints: Vec<(String, u16)>,
+    pub load_balancing: LoadBalancing,
+    pub tls: bool,
+    /// PEM 형식의 CA 인증서. 없으면 내장 CA 번들과 webpki 루트 인증서를 사용한다.
+    pub ca_certificate: Option<Vec<u8>>,
+    pub connect_timeout: Option<Duration>,
+    /// 모든 RPC에 적용하는 기본 제한 시간. 없으면 제한하지 않는다.
+    pub request_timeout: Option<Duration>,
+    pub keepalive_interval: Option<Duration>,
+    pub keepalive_timeout: Option<Duration>,
+    pub user_agent: Option<String>,
+    pub max_decoding_message_size: usize,
+    pub max_encoding_message_size: usize,
+    /// 일시적인 오류에 대한 재시도 정책. 없으면 재시도하지 않는다.
+    pub retry_policy: Option<RetryPolicy>,
+    /// 요청과 응답을 기록하거나 재생하는 카세트
+    pub cassette: Option<Cassette>,
+    /// 서버가 돌려줄 위치(`begin_offset`)의 단위. 기본값은 `EncodingType::Utf32`(문자 단위)다.
+    pub encoding: EncodingType,
+}
+
+impl BareunClientConfig {
+    /// 설정 빌더를 만든다.
+    ///
+    /// Args:
+    ///     apikey: Bareun API 키
+    pub fn builder(apikey: &str) -> BareunClientConfigBuilder {
+        BareunClientConfigBuilder::new(apikey)
+    }
+
+    /// 기존 생성자들의 (apikey, host, port) 인자로 설정을 만든다.
+    pub fn new(apikey: &str, host: &str, port: Option<u16>) -> Self {
+        let mut builder = Self::builder(apikey).host(host);
+        if let Some(port) = port {
+            builder = builder.port(port);
+        }
+        builder.build()
+    }
+
+    /// 연결할 (첫 번째) 서버의 URI
+    pub fn uri(&self) -> String {
+        self.uri_for(&self.host, self.port)
+    }
+
+    fn uri_for(&self, host: &str, port: u16) -> String {
+        let scheme = if self.tls { "https" } else { "http" };
+        format!("{}://{}:{}", scheme, host, port)
+    }
+
+    pub(crate) fn endpoint(&self, host: &str, port: u16) -> Result<Endpoint> {
+        let mut endpoint = Endpoint::from_shared(self.uri_for(host, port))?;
+
+        if let Some(timeout) = self.connect_timeout {
+            endpoint = endpoint.connect_timeout(timeout);
+        }
+        if let Some(interval) = self.keepalive_interval {
+            endpoint = endpoint
+                .http2_keep_alive_interval(interval)
+                .keep_alive_while_idle(true);
+        }
+        if let Some(timeout) = self.keepalive_timeout {
+            endpoint = endpoint.keep_alive_timeout(timeout);
+        }
+        if let Some(user_agent) = &self.user_agent {
+            endpoint = endpoint.user_agent(user_agent.clone())?;
+        }
+
+        if self.tls {
+            let tls = match &self.ca_certificate {
+                Some(pem) => ClientTlsConfig::new().ca_certificate(Certificate::from_pem(pem)),
+                None => ClientTlsConfig::new()
+                    .ca_certificate(Certificate::from_pem(CA_BUNDLE))
+                    .with_webpki_roots(),
+            };
+            endpoint = endpoint.tls_config(tls)?;
+        }
+
+        Ok(endpoint)
+    }
+
+    /// 서버에 연결하지 않고 채널 묶음을 만든다. 실제 연결은 첫 요청 때 이루어진다.
+    ///
+    /// 주소나 TLS 설정이 잘못된 경우에만 실패한다. Tokio 런타임 안에서 호출해야 한다.
+    pub(crate) fn connect_lazy(&self) -> Result<EndpointPool> {
+        let mut channels = Vec::with_capacity(self.endpoints.len());
+        for (host, port) in &self.endpoints {
+            let channel = self.endpoint(host, *port)?.connect_lazy();
+            channels.push((host.clone(), *port, channel));
+        }
+        Ok(EndpointPool::new(channels, self.load_balancing.clone()))
+    }
+
+    /// 설정의 모든 서버에 연결한 채널 묶음을 만든다.
+    ///
+    /// 연결하지 못한 서버는 후보에서 뺀 채로 두고 냉각 시간이 지나면 다시 시도한다.
+    /// 모든 서버에 연결하지 못하면 첫 번째 오류를 돌려준다.
+    /// 재생 모드 카세트가 있으면 서버에 연결하지 않는다.
+    pub(crate) async fn connect(&self) -> Result<EndpointPool> {
+        if self.replaying() {
+            return self.connect_lazy();
+        }
+        let mut channels = Vec::with_capacity(self.endpoints.len());
+        let mut failed = Vec::new();
+        let mut first_error = None;
+
+        for (i, (host, port)) in self.endpoints.iter().enumerate() {
+            let endpoint = self.endpoint(host, *port)?;
+            let channel = match endpoint.connect().await {
+                Ok(channel) => channel,
+                Err(source) => {
+                    first_error.get_or_insert(BareunError::ConnectionFailed {
+                        host: host.clone(),
+                        port: *port,
+                        source,
+                    });
+                    failed.push(i);
+                    endpoint.connect_lazy()
+                }
+            };
+            channels.push((host.clone(), *port, channel));
+        }
+
+        if failed.len() == channels.len()
+            && let Some(e) = first_error
+        {
+            return Err(e);
+        }
+
+        let pool = EndpointPool::new(channels, self.load_balancing.clone());
+        for i in failed {
+            pool.eject(i);
+        }
+        Ok(pool)
+    }
+
+    /// 재생 모드 카세트를 사용하는지 여부
+    pub(crate) fn replaying(&self) -> bool {
+        self.cassette
+            .as_ref()
+            .is_some_and(|c| c.mode() == CassetteMode::Replay)
+    }
+
+    /// API 키를 `api-key` 메타데이터 값으로 바꾼다. 출력할 수 있는 ASCII 문자만 허용한다.
+    fn api_key(&self) -> Result<MetadataValue<Ascii>> {
+        if !self.apikey.bytes().all(|b| b.is_ascii_graphic()) {
+            return Err(BareunError::invalid_argument(
+                "API key must contain only printable ASCII characters",
+            ));
+        }
+        self.apikey
+            .parse()
+            .map_err(BareunError::InvalidMetadataValue)
+    }
+
+    /// 호출 옵션을 반영한 위치의 단위
+    pub(crate) fn encoding_for(&self, options: &CallOptions) -> EncodingType {
+        options.encoding.unwrap_or(self.encoding)
+    }
+
+    /// 호출 옵션과 설정을 합쳐 실제로 적용할 제한 시간을 정한다.
+    pub(crate) fn timeout_for(&self, options: &CallOptions) -> Option<Duration> {
+        options.timeout.or(self.request_timeout)
+    }
+
+    /// 요청을 보내고 응답을 받는다. 카세트가 있으면 기록하거나 재생한다.
+    pub(crate) async fn call<M, T, F, Fut>(
+        &self,
+        pool: &EndpointPool,
+        method: &RpcMethod,
+        msg: M,
+        options: &CallOptions,
+        call: F,
+    ) -> Result<T>
+    where
+        M: Clone + Serialize,
+        T: Serialize + DeserializeOwned,
+        F: FnMut(Channel, Request<M>) -> Fut,
+        Fut: Future<Output = std::result::Result<Response<T>, Status>>,
+    {
+        match &self.cassette {
+            Some(cassette) => {
+                let request = msg.clone();
+                let live = self.send(pool, method, msg, options, call);
+                cassette.unary(self, method, &request, options, live).await
+            }
+            None => self.send(pool, method, msg, options, call).await,
+        }
+    }
+
+    /// API 키를 메타데이터에 담아 요청을 보내고, 실패하면 `BareunError`로 바꾼다.
+    pub(crate) async fn send<M, T, F, Fut>(
+        &self,
+        pool: &EndpointPool,
+        method: &RpcMethod,
+        msg: M,
+        options: &CallOptions,
+        call: F,
+    ) -> Result<T>
+    where
+        M: Clone,
+        F: FnMut(Channel, Request<M>) -> Fut,
+        Fut: Future<Output = std::result::Result<Response<T>, Status>>,
+    {
+        self.send_picked(pool, method, msg, options, call)
+            .await
+            .map(|(res, _)| res)
+    }
+
+    /// `send`와 같지만 응답과 함께 응답한 서버의 (주소, 포트)를 돌려준다.
+    ///
+    /// 시도마다 `pool`에서 서버를 새로 고르므로, 재시도는 다른 서버로 갈 수 있다.
+    /// `Unavailable`을 돌려준 서버는 냉각 시간 동안 후보에서 빠진다.
+    ///
+    /// 제한 시간은 시도마다 `grpc-timeout` 헤더로 서버에 전달되며, 클라이언트도 같은 시간이
+    /// 지나면 기다리기를 멈춘다. 멱등인 `method`에만 재시도 정책을 적용한다.
+    pub(crate) async fn send_picked<M, T, F, Fut>(
+        &self,
+        pool: &EndpointPool,
+        method: &RpcMethod,
+        msg: M,
+        options: &CallOptions,
+        mut call: F,
+    ) -> Result<(T, (String, u16))>
+    where
+        M: Clone,
+        F: FnMut(Channel, Request<M>) -> Fut,
+        Fut: Future<Output = std::result::Result<Response<T>, Status>>,
+    {
+        let api_key = self.api_key()?;
+        let timeout = self.timeout_for(options);
+        let policy = if method.idempotent {
+            self.retry_policy.as_ref()
+        } else {
+            None
+        };
+
+        let mut last_endpoint = (self.host.clone(), self.port);
+        let mut last_timeout = timeout;
+
+        let result = with_retry(policy, |remaining| {
+            let picked = pool.pick();
+            last_endpoint = (picked.host().to_string(), picked.port());
+
+            // 재시도 제한 시간이 남은 만큼만 기다린다.
+            let timeout = match (timeout, remaining) {
+                (Some(t), Some(r)) => Some(t.min(r)),
+                (t, r) => t.or(r),
+            };
+            last_timeout = timeout;
+
+            let mut req = Request::new(msg.clone());
+            req.metadata_mut().insert("api-key", api_key.clone());
+            if let Some(timeout) = timeout {
+                req.set_timeout(timeout);
+            }
+            let fut = call(picked.channel(), req);
+            async move {
+                let result = match timeout {
+                    Some(timeout) => tokio::time::timeout(timeout, fut)
+                        .await
+                        .unwrap_or_else(|_| Err(Status::deadline_exceeded(TIMEOUT_EXPIRED))),
+                    None => fut.await,
+                };
+                if let Err(status) = &result
+                    && status.code() == Code::Unavailable
+                {
+                    picked.eject();
+                }
+                result
+            }
+        })
+        .await;
+
+        match result {
+            Ok(res) => Ok((res.into_inner(), last_endpoint)),
+            Err(e) => {
+                let (host, port) = last_endpoint;
+                Err(self.handle_grpc_error(&e, method, last_timeout, host, port))
+            }
+        }
+    }
+
+    /// 받은 오류를 `BareunError`로 바꾼다. `host`와 `port`는 요청을 보낸 서버다.
+    pub(crate) fn handle_grpc_error(
+        &self,
+        e: &Status,
+        method: &RpcMethod,
+        timeout: Option<Duration>,
+        host: String,
+        port: u16,
+    ) -> BareunError {
+        status_error(e, method.path, timeout, &self.apikey, host, port)
+    }
+}
+
+/// gRPC 상태를 상태 코드에 맞는 `BareunError`로 바꾼다.
+///
+/// `timeout`은 호출에 적용한 제한 시간이고, `host`와 `port`는 요청을 보낸 서버다.
+pub(crate) fn status_error(
+    e: &Status,
+    method: &'static str,
+    timeout: Option<Duration>,
+    apikey: &str,
+    host: String,
+    port: u16,
+) -> BareunError {
+    let status = Box::new(RpcStatus::from_status(e, method));
+    // 클라이언트 쪽에서 grpc-timeout이 만료되면 tonic은 Cancelled로 알려준다.
+    let expired = e.code() == Code::DeadlineExceeded
+        || (e.code() == Code::Cancelled && e.message() == TIMEOUT_EXPIRED);
+
+    match e.code() {
+        _ if expired && timeout.is_some() => BareunError::Timeout { timeout, status },
+        Code::DeadlineExceeded => BareunError::DeadlineExceeded { status },
+        Code::PermissionDenied => BareunError::PermissionDenied {
+            apikey: mask_api_key(apikey),
+            status,
+        },
+        Code::Unauthenticated => BareunError::Unauthenticated {
+            apikey: mask_api_key(apikey),
+            status,
+        },
+        Code::Unavailable => BareunError::ServerUnavailable { host, port, status },
+        Code::ResourceExhausted => BareunError::ResourceExhausted { status },
+        Code::NotFound => BareunError::NotFound { status },
+        Code::InvalidArgument => BareunError::InvalidArgument {
+            message: status.message.clone(),
+            status: Some(status),
+        },
+        _ => BareunError::GrpcError(status),
+    }
+}
+
+/// API 키는 가려서 출력한다.
+impl fmt::Debug for BareunClientConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BareunClientConfig")
+            .field("apikey", &mask_api_key(&self.apikey))
+            .field("host", &self.host)
+            .field("port", &self.port)
+            .field("endpoints", &self.endpoints)
+            .field("load_balancing", &self.load_balancing)
+            .field("tls", &self.tls)
+            .field(
+                "ca_certificate",
+                &self.ca_certificate.as_ref().map(Vec::len),
+            )
+            .field("connect_timeout", &self.connect_timeout)
+            .field("request_timeout", &self.request_timeout)
+            .field("keepalive_interval", &self.keepalive_interval)
+            .field("keepalive_timeout", &self.keepalive_timeout)
+            .field("user_agent", &self.user_agent)
+            .field("max_decoding_message_size", &self.max_decoding_message_size)
+            .field("max_encoding_message_size", &self.max_encoding_message_size)
+            .field("retry_policy", &self.retry_policy)
+            .field("cassette", &self.cassette)
+            .field("encoding", &self.encoding)
+            .finish()
+    }
+}
+
+/// 재시도와 오류 보고에 필요한 RPC 정보
+pub(crate) struct RpcMethod {
+    /// `bareun.LanguageService/AnalyzeSyntax` 형식의 이름
+    pub(crate) path: &'static str,
+    /// 같은 요청을 다시 보내도 결과가 달라지지 않는지 여부. 재시도 여부를 정한다.
+    pub(crate) idempotent: bool,
+}
+
+impl RpcMethod {
+    pub(crate) const fn new(path: &'static str, idempotent: bool) -> Self {
+        RpcMethod { path, idempotent }
+    }
+}
+
+/// 호출 하나에만 적용하는 옵션
+///
+/// 지정하지 않은 값은 `BareunClientConfig`의 기본값을 따른다.
+/// 진행 중인 호출을 취소하려면 반환된 future를 drop하면 된다. 이때 서버에도 취소가 전달된다.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use std::time::Duration;
+/// use bareun_rs::CallOptions;
+///
+/// let opts = CallOptions::new().timeout(Duration::from_millis(500));
+/// let tagged = tagger.tag_with("오늘은 정말 추운 날이네요.", false, true, false, &opts).await?;
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CallOptions {
+    /// 이 호출의 제한 시간. 재시도할 때는 시도마다 새로 적용된다.
+    pub timeout: Option<Duration>,
+    /// 이 호출에서 서버가 돌려줄 위치의 단위. `None`이면 클라이언트 설정을 따른다.
+    pub encoding: Option<EncodingType>,
+}
+
+impl CallOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn encoding(mut self, encoding: EncodingType) -> Self {
+        self.encoding = Some(encoding);
+        self
+    }
+}
+
+/// `BareunClientConfig` 빌더
+#[derive(Clone)]
+pub struct BareunClientConfigBuilder {
+    apikey: String,
+    host: String,
+    port: Option<u16>,
+    endpoints: Vec<(String, u16)>,
+    load_balancing: LoadBalancing,
+    tls: Option<bool>,
+    ca_certificate: Option<Vec<u8>>,
+    connect_timeout: Option<Duration>,
+    request_timeout: Option<Duration>,
+    keepalive_interval: Option<Duration>,
+    keepalive_timeout: Option<Duration>,
+    user_agent: Option<String>,
+    max_decoding_message_size: usize,
+    max_encoding_message_size: usize,
+    retry_policy: Option<RetryPolicy>,
+    cassette: Option<Cassette>,
+    encoding: EncodingType,
+}
+
+/// API 키는 가려서 출력한다.
+impl fmt::Debug for BareunClientConfigBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BareunClientConfigBuilder")
+            .field("apikey", &mask_api_key(&self.apikey))
+            .field("host", &self.host)
+            .field("port", &self.port)
+            .field("endpoints", &self.endpoints)
+            .field("tls", &self.tls)
+            .finish_non_exhaustive()
+    }
+}
+
+impl BareunClientConfigBuilder {
+    pub fn new(apikey: &str) -> Self {
+        BareunClientConfigBuilder {
+            apikey: apikey.to_string(),
+            host: String::new(),
+            port: None,
+            endpoints: Vec::new(),
+            load_balancing: LoadBalancing::default(),
+            tls: None,
+            ca_certificate: None,
+            connect_timeout: None,
+            request_timeout: None,
+            keepalive_interval: None,
+            keepalive_timeout: None,
+            user_agent: None,
+            max_decoding_message_size: MAX_MESSAGE_LENGTH,
+            max_encoding_message_size: MAX_MESSAGE_LENGTH,
+            retry_policy: None,
+            cassette: None,
+            encoding: EncodingType::Utf32,
+        }
+    }
+
+    /// 서버 호스트. 비어 있으면 `api.bareun.ai`를 사용한다.
+    pub fn host(mut self, host: &str) -> Self {
+        self.host = host.trim().to_string();
+        self
+    }
+
+    pub fn port(mut self, port: u16) -> Self {
+        self.port = Some(port);
+        self
+    }
+
+    /// 요청을 나눠 보낼 서버를 추가한다. host를 지정하지 않았다면 첫 번째 서버가 host/port가 된다.
+    pub fn endpoint(mut self, host: &str, port: u16) -> Self {
+        self.endpoints.push((host.trim().to_string(), port));
+        self
+    }
+
+    /// 서버가 여러 개일 때의 부하 분산 전략과 제외 냉각 시간
+    pub fn load_balancing(mut self, balancing: LoadBalancing) -> Self {
+        self.load_balancing = balancing;
+        self
+    }
+
+    /// TLS 사용 여부. 지정하지 않으면 호스트 이름으로 판단한다.
+    pub fn tls(mut self, v: bool) -> Self {
+        self.tls = Some(v);
+        self
+    }
+
+    /// 서버 인증서 검증에 사용할 PEM 형식의 CA 인증서. 지정하면 TLS를 켠다.
+    pub fn ca_certificate(mut self, pem: impl Into<Vec<u8>>) -> Self {
+        self.ca_certificate = Some(pem.into());
+        self.tls.get_or_insert(true);
+        self
+    }
+
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// 요청 하나가 끝날 때까지 기다리는 기본 제한 시간. `grpc-timeout` 헤더로 서버에도 전달된다.
+    /// 호출마다 `CallOptions::timeout`으로 바꿀 수 있다.
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
+
+    /// HTTP/2 keepalive ping 간격
+    pub fn keepalive_interval(mut self, interval: Duration) -> Self {
+        self.keepalive_interval = Some(interval);
+        self
+    }
+
+    /// keepalive ping 응답을 기다리는 시간
+    pub fn keepalive_timeout(mut self, timeout: Duration) -> Self {
+        self.keepalive_timeout = Some(timeout);
+        self
+    }
+
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.user_agent = Some(user_agent.to_string());
+        self
+    }
+
+    /// 수신 메시지의 최대 크기 (기본값: 100 MB)
+    pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+        self.max_decoding_message_size = limit;
+        self
+    }
+
+    /// 송신 메시지의 최대 크기 (기본값: 100 MB)
+    pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+        self.max_encoding_message_size = limit;
+        self
+    }
+
+    /// 일시적인 오류(기본값: `Unavailable`)를 재시도하는 정책
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    /// 모든 RPC를 파일에 기록하거나 파일에서 재생하는 카세트
+    ///
+    /// 재생 모드에서는 서버에 연결하지 않는다.
+    pub fn cassette(mut self, cassette: Cassette) -> Self {
+        self.cassette = Some(cassette);
+        self
+    }
+
+    /// 서버가 돌려줄 위치의 단위. JavaScript처럼 UTF-16 문자열을 쓰는 곳에서는
+    /// `EncodingType::Utf16`을 지정하면 위치를 바꾸지 않고 쓸 수 있다.
+    pub fn encoding(mut self, encoding: EncodingType) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
+    pub fn build(mut self) -> BareunClientConfig {
+        let (host, port) = if self.host.is_empty() && !self.endpoints.is_empty() {
+            let (host, port) = self.endpoints.remove(0);
+            (host, Some(port))
+        } else if self.host.is_empty() {
+            (DEFAULT_HOST.to_string(), self.port)
+        } else {
+            (self.host, self.port)
+        };
+        let tls = self.tls.unwrap_or_else(|| is_official_host(&host));
+        let port = port.unwrap_or(if tls { DEFAULT_TLS_PORT } else { DEFAULT_PORT });
+
+        let mut endpoints = vec![(host.clone(), port)];
+        for endpoint in self.endpoints {
+            if !endpoints.contains(&endpoint) {
+                endpoints.push(endpoint);
+            }
+        }
+
+        BareunClientConfig {
+            apikey: self.apikey,
+            host,
+            port,
+            endpoints,
+            load_balancing: self.load_balancing,
+            tls,
+            ca_certificate: self.ca_certificate,
+            connect_timeout: self.connect_timeout,
+            request_timeout: self.request_timeout,
+            keepalive_interval: self.keepalive_interval,
+            keepalive_timeout: self.keepalive_timeout,
+            user_agent: self.user_agent,
+            max_decoding_message_size: self.max_decoding_message_size,
+            max_encoding_message_size: self.max_encoding_message_size,
+            retry_policy: self.retry_policy,
+            cassette: self.cassette,
+            encoding: self.encoding,
+        }
+    }
+}
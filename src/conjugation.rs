@@ -0,0 +1,380 @@
+use crate::hangul::{CHOSEONG, JONGSEONG, Syllable, decompose};
+use crate::model::Tag;
+
+/// 어미의 첫 글자 모양
+enum Head {
+    /// 자모 하나(`ㄴ다`의 `ㄴ`)
+    Jamo(char),
+    /// 매개모음 `으`로 시작하는 음절(`으`, `은`, `을`, `음`). 종성을 가진다.
+    Eu(Option<char>),
+    /// `아`, `어`로 시작하는 음절(`아`, `어`, `았`, `었`). 중성과 종성을 가진다.
+    AEo(char, Option<char>),
+    /// 그 밖의 `ㅇ` 초성 음절(`오`, `여`)
+    Vowel,
+    /// 그 밖의 음절(`니`, `세요`). 초성을 가진다.
+    Other(char),
+}
+
+impl Head {
+    fn of(c: char) -> Option<Head> {
+        if JONGSEONG.contains(&c) || CHOSEONG.contains(&c) {
+            return Some(Head::Jamo(c));
+        }
+        let s = decompose(c)?;
+        Some(match (s.cho, s.jung) {
+            ('ㅇ', 'ㅡ') => Head::Eu(s.jong),
+            ('ㅇ', 'ㅏ' | 'ㅓ') => Head::AEo(s.jung, s.jong),
+            ('ㅇ', _) => Head::Vowel,
+            (cho, _) => Head::Other(cho),
+        })
+    }
+
+    fn vowel_initial(&self) -> bool {
+        matches!(self, Head::Eu(_) | Head::AEo(..) | Head::Vowel)
+    }
+
+    /// 받침 뒤에서 `으`와 함께 붙는 자모(`먹+ㄴ` → `먹은`). `rest`는 자모 뒤의 어미다.
+    ///
+    /// `ㅂ니다`, `ㅂ니까`는 `습니다`, `습니까`가 되므로 해당하지 않는다.
+    fn eu_jamo(&self, rest: &str) -> Option<char> {
+        match self {
+            Head::Jamo(j @ ('ㄴ' | 'ㄹ' | 'ㅁ')) => Some(*j),
+            Head::Jamo('ㅂ') if !rest.starts_with('니') => Some('ㅂ'),
+            _ => None,
+        }
+    }
+}
+
+/// 형태소를 이어 어절의 표면형을 만든다.
+///
+/// 서버가 원형으로 돌려준 형태소(`춥/VA+ㄴ/ETM`, `하/XSV+았/EP+다/EF`)를 실제 어절(`추운`,
+/// `했다`)로 되돌린다. 형태소 하나를 바꾼 뒤 어절을 다시 쓸 때 쓴다.
+///
+/// 용언 어간(VV, VA, VX, VCP, VCN, XSV, XSA)이나 선어말 어미(EP) 뒤에 어미가 오면 활용 규칙을
+/// 적용한다.
+/// - `ㄴ`, `ㄹ`, `ㅁ`, `ㅂ`처럼 자모만 있는 어미는 앞 음절의 받침이 된다. 받침이 있으면 `으`를 넣고,
+///   `ㅂ니다`는 `습니다`가 된다(`먹+ㅂ니다` → `먹습니다`).
+/// - `ㄹ` 탈락(`살+니` → `사니`, `살+습니다` → `삽니다`), 매개모음 `으` 탈락(`가+으면` → `가면`)
+/// - `ㅂ`, `ㄷ`, `ㅅ`, `르`, `ㅎ` 불규칙 활용(`춥+어` → `추워`, `듣+어` → `들어`, `짓+어` → `지어`,
+///   `부르+어` → `불러`, `빨갛+아` → `빨개`)
+/// - 모음 축약(`하+았` → `했`, `보+아` → `봐`, `마시+어` → `마셔`, `쓰+어` → `써`). `보아`, `되어`처럼
+///   줄이지 않아도 되는 경우도 줄인다.
+/// - 어미의 `아`, `어`는 어간 모음에 맞춘다(`먹+았` → `먹었`). 어간만 바꿔 넣어도 맞는 활용이 된다.
+///
+/// 그 밖의 형태소는 그대로 잇는다. 조사 `ㄴ`, `ㄹ`(`나+ㄴ` → `난`)은 앞 음절의 받침이 된다.
+/// 불규칙 용언은 어간의 글자로 판단하므로 `묻다`(埋)처럼 규칙과 불규칙이 모두 있는 어간은
+/// 불규칙으로 활용한다. `러` 불규칙(`이르+어` → `이르러`)은 다루지 않는다.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use bareun_rs::{model::Tag, surface_form};
+///
+/// assert_eq!(surface_form([("춥", Tag::Va), ("ㄴ", Tag::Etm)]), "추운");
+/// assert_eq!(surface_form([("돕", Tag::Vv), ("았", Tag::Ep), ("다", Tag::Ef)]), "도왔다");
+/// ```
+pub fn surface_form<'a>(morphemes: impl IntoIterator<Item = (&'a str, Tag)>) -> String {
+    let mut out = String::new();
+    let mut prev: Option<(&str, Tag)> = None;
+    for (text, tag) in morphemes {
+        match prev {
+            Some((stem, stem_tag)) if tag.is_ending() && conjugates(stem_tag) => {
+                attach_ending(&mut out, stem, stem_tag, text);
+            }
+            _ => attach(&mut out, text),
+        }
+        prev = Some((text, tag));
+    }
+    out
+}
+
+/// 뒤에 오는 어미와 활용하는 형태소인지 여부
+fn conjugates(tag: Tag) -> bool {
+    tag.is_predicate() || matches!(tag, Tag::Xsv | Tag::Xsa | Tag::Ep)
+}
+
+/// 활용하지 않는 형태소를 잇는다. 자모 하나로 시작하면 앞 음절의 받침으로 붙인다.
+fn attach(out: &mut String, text: &str) {
+    let mut chars = text.chars();
+    if let Some(Head::Jamo(j)) = chars.next().and_then(Head::of)
+        && let Some(last) = out.chars().last().and_then(decompose)
+        && last.jong.is_none()
+        && let Some(c) = last.with_jong(Some(j)).compose()
+    {
+        out.pop();
+        out.push(c);
+        out.push_str(chars.as_str());
+        return;
+    }
+    out.push_str(text);
+}
+
+/// 어간(`out`의 끝)에 어미를 붙인다.
+fn attach_ending(out: &mut String, stem: &str, tag: Tag, ending: &str) {
+    let Some(mut last) = out.chars().last().and_then(decompose) else {
+        return attach(out, ending);
+    };
+    out.pop();
+    // 어간을 바꿔 넣었을 때도 맞도록 `아`, `어`는 어간 모음에 맞춘다. `았어요`의 `어`는 그대로 둔다.
+    let harmonized;
+    let ending = if tag == Tag::Ep {
+        ending
+    } else {
+        harmonized = harmonize(ending, harmony(out, last));
+        harmonized.as_str()
+    };
+    let mut chars = ending.chars();
+    let Some(head) = chars.next().and_then(Head::of) else {
+        push(out, last);
+        return out.push_str(ending);
+    };
+    let rest = chars.as_str();
+
+    match last.jong {
+        Some('ㅂ')
+            if (head.vowel_initial() || head.eu_jamo(rest).is_some()) && b_irregular(stem, tag) =>
+        {
+            // 춥+어 → 추워, 돕+아 → 도와, 춥+ㄴ → 추운, 돕+ㄹ → 도울. 춥+ㅂ니다는 규칙대로 춥습니다
+            push(out, last.with_jong(None));
+            let glide = if matches!(head, Head::AEo(..)) && matches!(stem, "돕" | "곱") {
+                'ㅗ'
+            } else {
+                'ㅜ'
+            };
+            let ending = harmonize(ending, if glide == 'ㅗ' { 'ㅏ' } else { 'ㅓ' });
+            attach_to_open(out, Syllable::new('ㅇ', glide, None), stem, tag, &ending);
+        }
+        // 듣+어 → 들어, 듣+ㄴ → 들은
+        Some('ㄷ')
+            if (head.vowel_initial() || head.eu_jamo(rest).is_some()) && d_irregular(stem, tag) =>
+        {
+            push(out, last.with_jong(Some('ㄹ')));
+            attach_after_batchim(out, &head, ending, rest);
+        }
+        // 짓+어 → 지어, 짓+ㄴ → 지은, 낫+ㄹ → 나을
+        Some('ㅅ')
+            if (head.vowel_initial() || head.eu_jamo(rest).is_some()) && s_irregular(stem, tag) =>
+        {
+            push(out, last.with_jong(None));
+            attach_after_batchim(out, &head, ending, rest);
+        }
+        Some('ㅎ') if h_irregular(last, tag) && !matches!(head, Head::Other(_) | Head::Vowel) => {
+            last = last.with_jong(None);
+            match head {
+                // 빨갛+아 → 빨개, 하얗+아 → 하얘
+                Head::AEo(_, jong) => {
+                    let jung = match last.jung {
+                        'ㅑ' => 'ㅒ',
+                        'ㅕ' => 'ㅖ',
+                        _ => 'ㅐ',
+                    };
+                    push(out, last.with_jung(jung).with_jong(jong));
+                }
+                // 빨갛+으면 → 빨가면, 빨갛+ㄴ → 빨간
+                Head::Eu(jong) => push(out, last.with_jong(jong)),
+                Head::Jamo(j) => push(out, last.with_jong(Some(j))),
+                Head::Vowel | Head::Other(_) => unreachable!(),
+            }
+            out.push_str(rest);
+        }
+        Some('ㄹ') => match head {
+            // 살+ㄴ다 → 산다, 살+ㅂ니다 → 삽니다, 살+ㅁ → 삶, 만들+ㄹ → 만들
+            Head::Jamo(j @ ('ㄴ' | 'ㅂ' | 'ㄹ')) => {
+                push(out, last.with_jong(Some(j)));
+                out.push_str(rest);
+            }
+            Head::Jamo('ㅁ') => {
+                push(out, last.with_jong(Some('ㄻ')));
+                out.push_str(rest);
+            }
+            // 살+으면 → 살면, 살+은 → 산
+            Head::Eu(jong) => {
+                push(out, last);
+                match jong {
+                    Some(j) => attach_ending(out, stem, tag, &format!("{}{}", j, rest)),
+                    None => out.push_str(rest),
+                }
+            }
+            // 살+습니다 → 삽니다
+            _ if ending.starts_with('습') => {
+                push(out, last.with_jong(Some('ㅂ')));
+                out.push_str(rest);
+            }
+            // 살+니 → 사니, 살+세요 → 사세요, 놀+는 → 노는, 살+오 → 사오
+            _ if matches!(head, Head::Other('ㄴ' | 'ㅂ' | 'ㅅ')) || ending.starts_with('오') => {
+                push(out, last.with_jong(None));
+                out.push_str(ending);
+            }
+            _ => {
+                push(out, last);
+                out.push_str(ending);
+            }
+        },
+        Some(_) => {
+            push(out, last);
+            attach_after_batchim(out, &head, ending, rest);
+        }
+        None => attach_to_open(out, last, stem, tag, ending),
+    }
+}
+
+/// 받침 있는 어간 뒤에 어미를 붙인다.
+///
+/// `ㄴ`, `ㄹ`, `ㅁ`, `ㅂ`은 `으`와 함께 붙고(`먹+ㄴ` → `먹은`, `먹+ㅂ시다` → `먹읍시다`),
+/// `ㅂ니다`는 `습니다`가 된다(`먹+ㅂ니다` → `먹습니다`).
+fn attach_after_batchim(out: &mut String, head: &Head, ending: &str, rest: &str) {
+    if let Some(j) = head.eu_jamo(rest) {
+        push(out, Syllable::new('ㅇ', 'ㅡ', Some(j)));
+        out.push_str(rest);
+    } else if matches!(head, Head::Jamo('ㅂ')) {
+        out.push('습');
+        out.push_str(rest);
+    } else {
+        out.push_str(ending);
+    }
+}
+
+/// 받침 없는 음절 `last`(아직 `out`에 넣지 않음)에 어미를 붙인다.
+fn attach_to_open(out: &mut String, last: Syllable, stem: &str, tag: Tag, ending: &str) {
+    let mut chars = ending.chars();
+    let Some(head) = chars.next().and_then(Head::of) else {
+        push(out, last);
+        return out.push_str(ending);
+    };
+    let rest = chars.as_str();
+    let contracted = match head {
+        Head::Jamo(j) => Some(last.with_jong(Some(j))),
+        Head::Eu(jong) => Some(last.with_jong(jong)),
+        Head::AEo(jung, jong) => contract(out, last, stem, tag, jung, jong),
+        Head::Vowel | Head::Other(_) => None,
+    };
+    match contracted.and_then(Syllable::compose) {
+        Some(c) => {
+            out.push(c);
+            out.push_str(rest);
+        }
+        None => {
+            push(out, last);
+            out.push_str(ending);
+        }
+    }
+}
+
+/// 어간 끝 음절과 `아`, `어`로 시작하는 어미를 한 음절로 줄인다. 줄일 수 없으면 `None`
+fn contract(
+    out: &mut String,
+    last: Syllable,
+    stem: &str,
+    tag: Tag,
+    jung: char,
+    jong: Option<char>,
+) -> Option<Syllable> {
+    let prev = out.chars().last().and_then(decompose);
+    let jung = match (last.cho, last.jung, jung) {
+        // 하+아 → 해
+        ('ㅎ', 'ㅏ', _) if stem.ends_with('하') => 'ㅐ',
+        // 부르+어 → 불러: 앞 음절에 ㄹ 받침을 더한다.
+        ('ㄹ', 'ㅡ', _) if stem.ends_with('르') && reu_irregular(stem) => match prev {
+            Some(p) if p.jong.is_none() => {
+                out.pop();
+                push(out, p.with_jong(Some('ㄹ')));
+                return Some(Syllable::new('ㄹ', jung, jong));
+            }
+            _ => jung,
+        },
+        // 쓰+어 → 써, 바쁘+아 → 바빠
+        (_, 'ㅡ', _) => jung,
+        // 푸+어 → 퍼
+        ('ㅍ', 'ㅜ', _) if stem == "푸" => 'ㅓ',
+        // 가+아 → 가, 서+어 → 서, 보내+어 → 보내, 켜+어 → 켜
+        (_, 'ㅏ' | 'ㅓ' | 'ㅐ' | 'ㅔ' | 'ㅕ', _) => last.jung,
+        (_, 'ㅗ', 'ㅏ') => 'ㅘ',
+        (_, 'ㅜ', 'ㅓ') => 'ㅝ',
+        (_, 'ㅚ', 'ㅓ') => 'ㅙ',
+        // 학생+이+었 은 줄이지 않는다. 의사+이+었 → 의사였
+        ('ㅇ', 'ㅣ', 'ㅓ') if tag == Tag::Vcp && prev.is_none_or(|p| p.jong.is_some()) => {
+            return None;
+        }
+        (_, 'ㅣ', 'ㅓ') => 'ㅕ',
+        _ => return None,
+    };
+    Some(Syllable::new(last.cho, jung, jong))
+}
+
+/// 어간 끝 음절 `last`에 맞는 어미 모음(`ㅏ` 또는 `ㅓ`)
+///
+/// `ㅏ`, `ㅑ`, `ㅗ`, `ㅘ`, `ㅛ`이면 `ㅏ`이다. 받침 없는 `ㅡ`(`바쁘`, `모르`)는 앞 음절의 모음을 본다.
+fn harmony(out: &str, last: Syllable) -> char {
+    let jung = match (last.jung, last.jong) {
+        ('ㅡ', None) => out
+            .chars()
+            .last()
+            .and_then(decompose)
+            .map_or('ㅓ', |p| p.jung),
+        (jung, _) => jung,
+    };
+    if matches!(jung, 'ㅏ' | 'ㅑ' | 'ㅗ' | 'ㅘ' | 'ㅛ') {
+        'ㅏ'
+    } else {
+        'ㅓ'
+    }
+}
+
+/// 어미가 `아`, `어`로 시작하면 첫 모음을 `jung`으로 바꾼다.
+fn harmonize(ending: &str, jung: char) -> String {
+    let mut chars = ending.chars();
+    match chars.next().map(Head::of) {
+        Some(Some(Head::AEo(_, jong))) => match Syllable::new('ㅇ', jung, jong).compose() {
+            Some(c) => format!("{}{}", c, chars.as_str()),
+            None => ending.to_string(),
+        },
+        _ => ending.to_string(),
+    }
+}
+
+/// 음절을 붙인다. 받침으로 쓸 수 없는 자모(`ㄸ`, `ㅃ`, `ㅉ`)는 음절 뒤에 그대로 붙인다.
+fn push(out: &mut String, s: Syllable) {
+    match s.compose() {
+        Some(c) => out.push(c),
+        None => {
+            out.extend(s.with_jong(None).compose());
+            out.extend(s.jong);
+        }
+    }
+}
+
+/// `ㅂ` 불규칙 어간인지. 형용사(`좁다`, `수줍다`, `굽다` 제외)와 `-답다`, `-롭다`, `-스럽다`,
+/// 몇몇 동사가 해당한다.
+fn b_irregular(stem: &str, tag: Tag) -> bool {
+    match tag {
+        Tag::Va => !["좁", "수줍", "굽"].iter().any(|s| stem.ends_with(s)),
+        Tag::Xsa => true,
+        _ => matches!(stem, "돕" | "눕" | "줍" | "굽" | "깁" | "여쭙"),
+    }
+}
+
+fn d_irregular(stem: &str, tag: Tag) -> bool {
+    tag == Tag::Vv
+        && ["듣", "걷", "묻", "싣", "깨닫", "긷", "붇", "일컫"]
+            .iter()
+            .any(|s| stem.ends_with(s))
+}
+
+fn s_irregular(stem: &str, tag: Tag) -> bool {
+    matches!(tag, Tag::Vv | Tag::Va)
+        && ["낫", "짓", "붓", "잇", "젓", "긋", "잣"]
+            .iter()
+            .any(|s| stem.ends_with(s))
+}
+
+/// `ㅎ` 불규칙 어간인지. 모음이 `ㅏ`, `ㅓ`, `ㅑ`, `ㅕ`인 형용사(`빨갛다`, `그렇다`)가 해당한다.
+fn h_irregular(last: Syllable, tag: Tag) -> bool {
+    tag == Tag::Va && matches!(last.jung, 'ㅏ' | 'ㅓ' | 'ㅑ' | 'ㅕ')
+}
+
+/// `르` 불규칙 어간인지. `따르다`, `치르다`처럼 `ㅡ`만 탈락하는 어간은 제외한다.
+fn reu_irregular(stem: &str) -> bool {
+    !["따르", "치르", "들르", "다다르", "우러르"]
+        .iter()
+        .any(|s| stem.ends_with(s))
+}
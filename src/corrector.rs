@@ -1,12 +1,19 @@
+use std::ops::Deref;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::backend::{RevisionBackend, RevisionStream};
 use crate::bareun::stream_correct_error_response::Res as StreamRes;
 use crate::bareun::{
     CancelledRevision, CorrectErrorRequest, CorrectErrorResponse, Document, EncodingType,
     PostRevision, ProgressRevision, RevisionConfig, StreamCorrectErrorRequest,
     StreamCorrectErrorResponse, StreamFirstCorrectError,
 };
+use crate::config::{BareunClientConfig, CallOptions};
 use crate::error::Result;
 use crate::revision_service_client::BareunRevisionServiceClient;
-use tonic::Streaming;
+use crate::session::BareunSession;
+use tokio_stream::StreamExt;
 
 /// RevisionConfig 편의 빌더
 ///
@@ -94,8 +101,53 @@ impl StreamRevisionEvent {
     }
 }
 
+/// 맞춤법 교정 결과
+///
+/// `CorrectErrorResponse`로 역참조되므로 `res.revised`처럼 응답의 필드를 그대로 쓸 수 있다.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Corrected {
+    pub r: CorrectErrorResponse,
+    /// 응답의 위치(`begin_offset`, `offset` 등)가 쓰는 단위
+    pub encoding: EncodingType,
+}
+
+impl Corrected {
+    pub fn new(res: CorrectErrorResponse, encoding: EncodingType) -> Self {
+        Corrected { r: res, encoding }
+    }
+
+    pub fn msg(&self) -> &CorrectErrorResponse {
+        &self.r
+    }
+
+    /// 응답의 위치가 쓰는 단위
+    pub fn encoding(&self) -> EncodingType {
+        self.encoding
+    }
+
+    pub fn into_inner(self) -> CorrectErrorResponse {
+        self.r
+    }
+}
+
+impl Deref for Corrected {
+    type Target = CorrectErrorResponse;
+
+    fn deref(&self) -> &CorrectErrorResponse {
+        &self.r
+    }
+}
+
+impl From<Corrected> for CorrectErrorResponse {
+    fn from(corrected: Corrected) -> Self {
+        corrected.r
+    }
+}
+
+#[derive(Clone)]
 pub struct Corrector {
-    pub client: BareunRevisionServiceClient,
+    backend: Arc<dyn RevisionBackend>,
+    encoding: EncodingType,
 }
 
 impl Corrector {
@@ -106,30 +158,91 @@ impl Corrector {
     ///     host: gRPC 서버 호스트 (기본값: api.bareun.ai)
     ///     port: gRPC 서버 포트 (기본값: 443)
     pub async fn new(apikey: &str, host: &str, port: Option<u16>) -> Result<Self> {
-        let client = BareunRevisionServiceClient::new(apikey, host, port).await?;
+        Self::from_config(BareunClientConfig::new(apikey, host, port)).await
+    }
+
+    /// 설정 객체로 Corrector를 만든다.
+    pub async fn from_config(config: BareunClientConfig) -> Result<Self> {
+        let encoding = config.encoding;
+        let client = BareunRevisionServiceClient::from_config(config).await?;
+
+        let mut corrector = Self::with_backend(client);
+        corrector.encoding = encoding;
+        Ok(corrector)
+    }
+
+    /// 서버에 연결하지 않고 Corrector를 만든다. 연결 오류는 첫 요청에서 드러난다.
+    pub fn connect_lazy(config: BareunClientConfig) -> Result<Self> {
+        let session = BareunSession::connect_lazy(config)?;
+        Ok(Self::from_session(&session))
+    }
+
+    /// 서버가 요청을 받을 수 있는지 한 번 확인한다.
+    pub async fn ready(&self) -> Result<()> {
+        self.backend.ready().await
+    }
 
-        Ok(Corrector { client })
+    /// 서버가 준비될 때까지 최대 `timeout` 동안 기다린다.
+    pub async fn wait_ready(&self, timeout: Duration) -> Result<()> {
+        self.backend.wait_ready(timeout).await
+    }
+
+    /// 세션의 연결을 함께 사용하는 Corrector를 만든다.
+    pub fn from_session(session: &BareunSession) -> Self {
+        let mut corrector = Self::with_backend(session.revision_client());
+        corrector.encoding = session.config().encoding;
+        corrector
+    }
+
+    /// 지정한 백엔드로 요청을 보내는 Corrector를 만든다.
+    pub fn with_backend(backend: impl RevisionBackend + 'static) -> Self {
+        Self::with_shared_backend(Arc::new(backend))
+    }
+
+    /// 다른 객체와 함께 쓰는 백엔드로 Corrector를 만든다.
+    pub fn with_shared_backend(backend: Arc<dyn RevisionBackend>) -> Self {
+        Corrector {
+            backend,
+            encoding: EncodingType::Utf32,
+        }
+    }
+
+    /// 요청을 보내는 백엔드
+    pub fn backend(&self) -> &Arc<dyn RevisionBackend> {
+        &self.backend
+    }
+
+    /// 서버가 돌려줄 위치의 기본 단위를 바꾼다. 호출마다 `CallOptions::encoding`으로 바꿀 수도 있다.
+    ///
+    /// 스트리밍 교정의 위치도 같은 단위를 쓴다.
+    pub fn set_encoding(&mut self, encoding: EncodingType) {
+        self.encoding = encoding;
+    }
+
+    /// 서버가 돌려줄 위치의 기본 단위
+    pub fn encoding(&self) -> EncodingType {
+        self.encoding
     }
 
     /// `RevisionConfigBuilder`로 바로 교정을 요청하는 편의 메서드
     pub async fn correct_error_with(
-        &mut self,
+        &self,
         content: &str,
         custom_dicts: &[String],
         builder: RevisionConfigBuilder,
-    ) -> Result<CorrectErrorResponse> {
+    ) -> Result<Corrected> {
         self.correct_error(content, custom_dicts, Some(builder.build()))
             .await
     }
 
     /// `RevisionConfigBuilder`로 바로 스트리밍 교정을 요청하는 편의 메서드
     pub async fn stream_correct_error_builder(
-        &mut self,
+        &self,
         content: &str,
         custom_dicts: &[String],
         builder: RevisionConfigBuilder,
         req_id: i64,
-    ) -> Result<Streaming<StreamCorrectErrorResponse>> {
+    ) -> Result<RevisionStream> {
         self.stream_correct_error(content, custom_dicts, Some(builder.build()), req_id)
             .await
     }
@@ -141,24 +254,38 @@ impl Corrector {
     ///     custom_dicts: 커스텀 도메인 정보
     ///     config: 요청 설정
     pub async fn correct_error(
-        &mut self,
+        &self,
         content: &str,
         custom_dicts: &[String],
         config: Option<RevisionConfig>,
-    ) -> Result<CorrectErrorResponse> {
+    ) -> Result<Corrected> {
+        self.correct_error_with_options(content, custom_dicts, config, &CallOptions::default())
+            .await
+    }
+
+    /// 호출 옵션(제한 시간 등)을 지정해 맞춤법 교정을 요청한다.
+    pub async fn correct_error_with_options(
+        &self,
+        content: &str,
+        custom_dicts: &[String],
+        config: Option<RevisionConfig>,
+        options: &CallOptions,
+    ) -> Result<Corrected> {
+        let encoding = options.encoding.unwrap_or(self.encoding);
         #[allow(deprecated)]
         let request = CorrectErrorRequest {
             document: Some(Document {
                 content: content.to_string(),
                 language: "ko_KR".to_string(),
             }),
-            encoding_type: EncodingType::Utf32.into(),
+            encoding_type: encoding.into(),
             custom_domain: String::new(), // deprecated field
             custom_dict_names: custom_dicts.to_vec(),
             config,
         };
 
-        self.client.correct_error(request).await
+        let res = self.backend.correct_error(request, options).await?;
+        Ok(Corrected::new(res, encoding))
     }
 
     /// 교정 결과를 출력
@@ -219,26 +346,45 @@ impl Corrector {
     ///     config: 요청 설정
     ///     req_id: 요청 ID (0이면 서버가 생성)
     pub async fn stream_correct_error(
-        &mut self,
+        &self,
+        content: &str,
+        custom_dicts: &[String],
+        config: Option<RevisionConfig>,
+        req_id: i64,
+    ) -> Result<RevisionStream> {
+        self.stream_correct_error_with_options(
+            content,
+            custom_dicts,
+            config,
+            req_id,
+            &CallOptions::default(),
+        )
+        .await
+    }
+
+    /// 호출 옵션을 지정해 스트리밍 교정을 요청한다. 제한 시간은 스트림 전체에 적용된다.
+    pub async fn stream_correct_error_with_options(
+        &self,
         content: &str,
         custom_dicts: &[String],
         config: Option<RevisionConfig>,
         req_id: i64,
-    ) -> Result<Streaming<StreamCorrectErrorResponse>> {
+        options: &CallOptions,
+    ) -> Result<RevisionStream> {
         #[allow(deprecated)]
         let request = StreamCorrectErrorRequest {
             document: Some(Document {
                 content: content.to_string(),
                 language: "ko_KR".to_string(),
             }),
-            encoding_type: EncodingType::Utf32.into(),
+            encoding_type: options.encoding.unwrap_or(self.encoding).into(),
             custom_domain: String::new(),
             custom_dict_names: custom_dicts.to_vec(),
             config,
             req_id,
         };
 
-        self.client.stream_correct_error(request).await
+        self.backend.stream_correct_error(request, options).await
     }
 
     /// 스트리밍 응답을 순차적으로 수신하며 `StreamRevisionEvent`로 변환해 처리한다.
@@ -252,7 +398,7 @@ impl Corrector {
     ///     req_id: 요청 ID (0이면 서버가 생성)
     ///     on_event: 이벤트 핸들러. `false`를 반환하면 루프를 조기 종료한다.
     pub async fn stream_correct_error_with<F>(
-        &mut self,
+        &self,
         content: &str,
         custom_dicts: &[String],
         config: Option<RevisionConfig>,
@@ -266,11 +412,11 @@ impl Corrector {
             .stream_correct_error(content, custom_dicts, config, req_id)
             .await?;
 
-        while let Some(msg) = stream.message().await? {
-            if let Some(event) = StreamRevisionEvent::from_message(msg) {
-                if !on_event(event) {
-                    break;
-                }
+        while let Some(msg) = stream.next().await {
+            if let Some(event) = StreamRevisionEvent::from_message(msg?)
+                && !on_event(event)
+            {
+                break;
             }
         }
 
@@ -1,9 +1,11 @@
 use crate::bareun::{CustomDictionary, DictSet};
+use crate::config::BareunClientConfig;
 use crate::custom_dict_client::CustomDictionaryServiceClient;
 use crate::error::{BareunError, Result};
 use std::collections::HashSet;
 use std::fs::File;
 use std::io::{BufRead, BufReader};
+use std::sync::OnceLock;
 
 /**
 사용자 사전의 파일을 읽어들입니다.
@@ -12,23 +14,29 @@ Args:
 
 Returns:
     `HashSet<String>`: 사용자 사전을 HashSet 형식으로 만들어서 돌려줍니다.
+
+Errors:
+    파일을 열 수 없거나 UTF-8이 아닌 줄이 있으면 `BareunError::DictFile`을 돌려줍니다.
 */
-pub fn read_dic_file(user_dict_path: &str) -> HashSet<String> {
+pub fn read_dic_file(user_dict_path: &str) -> Result<HashSet<String>> {
+    let file_error = |source| BareunError::DictFile {
+        path: user_dict_path.to_string(),
+        source,
+    };
     let mut dict_set = HashSet::new();
-    let file = File::open(user_dict_path).expect("Unable to open file");
+    let file = File::open(user_dict_path).map_err(file_error)?;
     let reader = BufReader::new(file);
 
     for line in reader.lines() {
-        if let Ok(w) = line {
-            if !w.starts_with("#") {
-                let w2 = w.trim();
-                if !w2.is_empty() {
-                    dict_set.insert(w2.to_string());
-                }
+        let w = line.map_err(file_error)?;
+        if !w.starts_with("#") {
+            let w2 = w.trim();
+            if !w2.is_empty() {
+                dict_set.insert(w2.to_string());
             }
         }
     }
-    dict_set
+    Ok(dict_set)
 }
 /**
 DictSet을 사전으로 변환합니다.
@@ -56,14 +64,14 @@ pub fn pb_map_to_set(ds: &DictSet) -> HashSet<String> {
 /// #[tokio::main]
 /// async fn main() -> anyhow::Result<()> {
 ///     let mut tagger = Tagger::new("YOUR_API_KEY", "api.bareun.ai", Some(443), vec![]).await?;
-///     let cd = tagger.custom_dict("law");
+///     let cd = tagger.custom_dict("law")?;
 ///
 ///     // 복합명사 추가
 ///     let cp_set = vec!["새단어".to_string(), "코로나19".to_string(), "K방역".to_string()];
 ///     cd.copy_cp_set(cp_set.into_iter().collect());
 ///
 ///     // 복합명사 (캐럿 구분) 추가
-///     cd.read_cp_caret_set_from_file("my_cp_caret.txt");
+///     cd.read_cp_caret_set_from_file("my_cp_caret.txt")?;
 ///
 ///     // 동사 추가
 ///     let vv_set = vec!["카톡하".to_string(), "신박하다".to_string()];
@@ -89,9 +97,10 @@ pub fn pb_map_to_set(ds: &DictSet) -> HashSet<String> {
 ///
 /// * `domain` - 사용자 사전의 이름, 반드시 지정되어야 합니다.
 ///
-/// # Panics
+/// # Errors
 ///
-/// 사용자 사전의 이름이 없으면 에러를 발생시킵니다.
+/// 사용자 사전의 이름이 비어 있으면 `BareunError::InvalidCustomDictName`을 돌려줍니다.
+#[derive(Clone)]
 pub struct CustomDict {
     pub domain: String,
     pub cp_set: HashSet<String>,
@@ -102,17 +111,17 @@ pub struct CustomDict {
     pub mm_set: HashSet<String>,
     pub mag_set: HashSet<String>,
     pub ic_set: HashSet<String>,
-    pub apikey: String,
-    pub host: String,
-    pub port: i32,
+    pub config: Option<BareunClientConfig>,
+    /// 한 번 만든 클라이언트. 복제한 사전도 같은 채널을 쓴다.
+    client: OnceLock<CustomDictionaryServiceClient>,
 }
 impl CustomDict {
-    pub fn new(domain: &str) -> Self {
+    pub fn new(domain: &str) -> Result<Self> {
         if domain.is_empty() {
-            panic!("domain name must be specified.");
+            return Err(BareunError::InvalidCustomDictName(domain.to_string()));
         }
 
-        CustomDict {
+        Ok(CustomDict {
             domain: domain.to_string(),
             cp_set: HashSet::new(),
             np_set: HashSet::new(),
@@ -122,57 +131,127 @@ impl CustomDict {
             mm_set: HashSet::new(),
             mag_set: HashSet::new(),
             ic_set: HashSet::new(),
-            apikey: String::new(),
-            host: String::new(),
-            port: 0,
-        }
+            config: None,
+            client: OnceLock::new(),
+        })
+    }
+
+    pub fn with_connection(domain: &str, apikey: &str, host: &str, port: i32) -> Result<Self> {
+        let mut d = Self::new(domain)?;
+        d.set_connection(apikey, host, port);
+        Ok(d)
+    }
+
+    /// 설정 객체로 서버 연결 정보를 지정한 사용자 사전을 만든다.
+    ///
+    /// 클라이언트는 서버에 연결하지 않고 만들어 두며(`connect_lazy`), 모든 요청이 같은 채널을 쓴다.
+    ///
+    /// # Errors
+    ///
+    /// 사전 이름이 비어 있거나 서버 주소가 잘못되었으면 오류를 돌려줍니다.
+    pub fn with_config(domain: &str, config: BareunClientConfig) -> Result<Self> {
+        let mut d = Self::new(domain)?;
+        d.config = Some(config);
+        d.init_client()?;
+        Ok(d)
     }
 
-    pub fn with_connection(domain: &str, apikey: &str, host: &str, port: i32) -> Self {
-        let mut d = Self::new(domain);
-        d.apikey = apikey.to_string();
-        d.host = host.to_string();
-        d.port = port;
-        d
+    /// 이미 연결된 클라이언트를 사용하는 사용자 사전을 만든다.
+    ///
+    /// `update`, `get`, `load`, `clear`가 새로 연결하지 않고 클라이언트의 채널을 재사용한다.
+    pub fn with_client(domain: &str, client: CustomDictionaryServiceClient) -> Result<Self> {
+        let mut d = Self::new(domain)?;
+        d.config = Some(client.config.clone());
+        d.client = OnceLock::from(client);
+        Ok(d)
     }
 
+    /// 서버 연결 정보를 지정합니다. port가 0 이하면 호스트에 맞춰 자동 설정합니다.
+    ///
+    /// 클라이언트는 한 번만 만들고 모든 요청이 같은 채널을 씁니다. 주소가 잘못되었으면
+    /// 첫 요청에서 오류가 납니다.
     pub fn set_connection(&mut self, apikey: &str, host: &str, port: i32) {
-        self.apikey = apikey.to_string();
-        self.host = host.to_string();
-        self.port = port;
+        let port = u16::try_from(port).ok().filter(|p| *p > 0);
+        self.set_config(BareunClientConfig::new(apikey, host, port));
+    }
+
+    /// 서버 연결 설정을 바꿉니다. 이전 클라이언트는 버리고 새로 만듭니다.
+    pub fn set_config(&mut self, config: BareunClientConfig) {
+        self.config = Some(config);
+        // 주소 오류는 첫 요청에서 다시 드러난다.
+        let _ = self.init_client();
+    }
+
+    /// 설정으로 클라이언트를 만들어 둔다.
+    ///
+    /// 채널은 Tokio 런타임 안에서만 만들 수 있으므로, 런타임 밖에서는 첫 요청 때 만든다.
+    fn init_client(&mut self) -> Result<()> {
+        self.client = OnceLock::new();
+        if tokio::runtime::Handle::try_current().is_ok()
+            && let Some(config) = self.config.as_ref().filter(|c| !c.apikey.is_empty())
+        {
+            let _ = self
+                .client
+                .set(CustomDictionaryServiceClient::connect_lazy(config.clone())?);
+        }
+        Ok(())
+    }
+
+    fn client(&self) -> Result<CustomDictionaryServiceClient> {
+        if let Some(client) = self.client.get() {
+            return Ok(client.clone());
+        }
+
+        match &self.config {
+            Some(config) if !config.apikey.is_empty() => {
+                let client = CustomDictionaryServiceClient::connect_lazy(config.clone())?;
+                Ok(self.client.get_or_init(|| client).clone())
+            }
+            _ => Err(BareunError::invalid_argument(
+                "Connection information not set. Use set_connection() first.",
+            )),
+        }
     }
 
     /// 고유명사 사전을 파일에서 읽어들입니다.
-    pub fn read_np_set_from_file(&mut self, user_dict_path: &str) {
-        self.np_set = read_dic_file(user_dict_path);
+    pub fn read_np_set_from_file(&mut self, user_dict_path: &str) -> Result<()> {
+        self.np_set = read_dic_file(user_dict_path)?;
+        Ok(())
     }
     /// 복합명사 사전을 파일에서 읽어들입니다.
-    pub fn read_cp_set_from_file(&mut self, user_dict_path: &str) {
-        self.cp_set = read_dic_file(user_dict_path);
+    pub fn read_cp_set_from_file(&mut self, user_dict_path: &str) -> Result<()> {
+        self.cp_set = read_dic_file(user_dict_path)?;
+        Ok(())
     }
     /// 복합명사 분리 사전을 파일에서 읽어들입니다.
-    pub fn read_cp_caret_set_from_file(&mut self, user_dict_path: &str) {
-        self.cp_caret_set = read_dic_file(user_dict_path);
+    pub fn read_cp_caret_set_from_file(&mut self, user_dict_path: &str) -> Result<()> {
+        self.cp_caret_set = read_dic_file(user_dict_path)?;
+        Ok(())
     }
     /// 동사 사전을 파일에서 읽어들입니다.
-    pub fn read_vv_set_from_file(&mut self, user_dict_path: &str) {
-        self.vv_set = read_dic_file(user_dict_path);
+    pub fn read_vv_set_from_file(&mut self, user_dict_path: &str) -> Result<()> {
+        self.vv_set = read_dic_file(user_dict_path)?;
+        Ok(())
     }
     /// 형용사 사전을 파일에서 읽어들입니다.
-    pub fn read_va_set_from_file(&mut self, user_dict_path: &str) {
-        self.va_set = read_dic_file(user_dict_path);
+    pub fn read_va_set_from_file(&mut self, user_dict_path: &str) -> Result<()> {
+        self.va_set = read_dic_file(user_dict_path)?;
+        Ok(())
     }
     /// 관형사 사전을 파일에서 읽어들입니다.
-    pub fn read_mm_set_from_file(&mut self, user_dict_path: &str) {
-        self.mm_set = read_dic_file(user_dict_path);
+    pub fn read_mm_set_from_file(&mut self, user_dict_path: &str) -> Result<()> {
+        self.mm_set = read_dic_file(user_dict_path)?;
+        Ok(())
     }
     /// 부사 사전을 파일에서 읽어들입니다.
-    pub fn read_mag_set_from_file(&mut self, user_dict_path: &str) {
-        self.mag_set = read_dic_file(user_dict_path);
+    pub fn read_mag_set_from_file(&mut self, user_dict_path: &str) -> Result<()> {
+        self.mag_set = read_dic_file(user_dict_path)?;
+        Ok(())
     }
     /// 감탄사 사전을 파일에서 읽어들입니다.
-    pub fn read_ic_set_from_file(&mut self, user_dict_path: &str) {
-        self.ic_set = read_dic_file(user_dict_path);
+    pub fn read_ic_set_from_file(&mut self, user_dict_path: &str) -> Result<()> {
+        self.ic_set = read_dic_file(user_dict_path)?;
+        Ok(())
     }
     /// 집합을 고유명사 사전으로 지정합니다.
     pub fn copy_np_set(&mut self, dict_set: HashSet<String>) {
@@ -215,14 +294,7 @@ impl CustomDict {
     ///
     /// grpc::Error - 원격 호출시 예외가 발생할 수 있습니다.
     pub async fn update(&self) -> Result<bool> {
-        if self.apikey.is_empty() || self.host.is_empty() {
-            return Err(BareunError::InvalidArgument {
-                message: "Connection information not set. Use set_connection() first.".to_string(),
-            });
-        }
-
-        let mut client =
-            CustomDictionaryServiceClient::new(&self.apikey, &self.host, self.port).await?;
+        let client = self.client()?;
         client
             .update(
                 &self.domain,
@@ -245,26 +317,12 @@ impl CustomDict {
         CustomDictionary: 사용자 사전 데이터 전체를 담고 있는 protobuf 메시지
     */
     pub async fn get(&self) -> Result<CustomDictionary> {
-        if self.apikey.is_empty() || self.host.is_empty() {
-            return Err(BareunError::InvalidArgument {
-                message: "Connection information not set. Use set_connection() first.".to_string(),
-            });
-        }
-
-        let mut client =
-            CustomDictionaryServiceClient::new(&self.apikey, &self.host, self.port).await?;
+        let client = self.client()?;
         client.get(&self.domain).await
     }
     /// 서버에 저정되어 있는 사용자 사전을 모두 가져옵니다.
     pub async fn load(&mut self) -> Result<()> {
-        if self.apikey.is_empty() || self.host.is_empty() {
-            return Err(BareunError::InvalidArgument {
-                message: "Connection information not set. Use set_connection() first.".to_string(),
-            });
-        }
-
-        let mut client =
-            CustomDictionaryServiceClient::new(&self.apikey, &self.host, self.port).await?;
+        let client = self.client()?;
         let d = client.get(&self.domain).await?;
 
         if let Some(np_set) = d.np_set {
@@ -296,11 +354,7 @@ impl CustomDict {
     }
     /// 사용자 사전의 내용을 삭제합니다.
     pub async fn clear(&mut self) -> Result<Vec<String>> {
-        if self.apikey.is_empty() || self.host.is_empty() {
-            return Err(BareunError::InvalidArgument {
-                message: "Connection information not set. Use set_connection() first.".to_string(),
-            });
-        }
+        let client = self.client()?;
 
         self.np_set.clear();
         self.cp_set.clear();
@@ -311,8 +365,6 @@ impl CustomDict {
         self.mag_set.clear();
         self.ic_set.clear();
 
-        let mut client =
-            CustomDictionaryServiceClient::new(&self.apikey, &self.host, self.port).await?;
-        client.remove(&[self.domain.clone()]).await
+        client.remove(std::slice::from_ref(&self.domain)).await
     }
 }
@@ -1,15 +1,35 @@
 use std::collections::{HashMap, HashSet};
-use tonic::transport::{Certificate, Channel, ClientTlsConfig, Endpoint};
-use tonic::{Request, Status};
+use std::time::Duration;
 
+use tonic::Code;
+use tonic::transport::Channel;
+
+use crate::balancer::EndpointPool;
 use crate::bareun::{
     CheckConflictRequest, CheckConflictResponse, CustomDictionary, CustomDictionaryMeta, DictSet,
     DictType, GetCustomDictionaryRequest, RemoveCustomDictionariesRequest,
     UpdateCustomDictionaryRequest,
     custom_dictionary_service_client::CustomDictionaryServiceClient as TonicClient,
 };
-use crate::constants::{CA_BUNDLE, MAX_MESSAGE_LENGTH};
-use crate::error::{BareunError, Result};
+use crate::config::{BareunClientConfig, CallOptions, RpcMethod};
+use crate::error::{BareunError, Result, RpcStatus};
+
+pub(crate) const GET_CUSTOM_DICTIONARY_LIST: RpcMethod = RpcMethod::new(
+    "bareun.CustomDictionaryService/GetCustomDictionaryList",
+    true,
+);
+pub(crate) const GET_CUSTOM_DICTIONARY: RpcMethod =
+    RpcMethod::new("bareun.CustomDictionaryService/GetCustomDictionary", true);
+pub(crate) const UPDATE_CUSTOM_DICTIONARY: RpcMethod = RpcMethod::new(
+    "bareun.CustomDictionaryService/UpdateCustomDictionary",
+    false,
+);
+pub(crate) const REMOVE_CUSTOM_DICTIONARIES: RpcMethod = RpcMethod::new(
+    "bareun.CustomDictionaryService/RemoveCustomDictionaries",
+    false,
+);
+pub(crate) const CHECK_CONFLICT: RpcMethod =
+    RpcMethod::new("bareun.CustomDictionaryService/CheckConflict", true);
 
 /// 주어진 파라미터를 사용하여 사용자 사전의 한 표현 형태인 DictSet protobuf 메시지를 만듭니다.
 ///
@@ -18,37 +38,22 @@ use crate::error::{BareunError, Result};
 ///     name (str): 사용자 사전에 대한 설명
 ///     dict_set (set): 사용자 사전에 들어가야 할 단어들의 집합
 pub fn build_dict_set(domain: &str, name: &str, dict_set: &HashSet<String>) -> DictSet {
-    let mut ret = DictSet::default();
-    ret.name = format!("{}-{}", domain, name);
-    ret.r#type = DictType::WordList as i32;
     let mut items = HashMap::default();
     for v in dict_set {
         items.insert(v.clone(), 1);
     }
-    ret.items = items;
-    ret
-}
-
-fn is_tls_host(host: &str) -> bool {
-    host.to_lowercase().starts_with("api.bareun.ai")
-}
-
-fn resolve_port(host: &str, port: i32) -> u16 {
-    if port > 0 {
-        port as u16
-    } else if is_tls_host(host) {
-        443
-    } else {
-        5656
+    DictSet {
+        items,
+        r#type: DictType::WordList as i32,
+        name: format!("{}-{}", domain, name),
     }
 }
 
 /// 커스텀 사전을 생성, 조회, 업데이트, 삭제하는 클라이언트
+#[derive(Clone)]
 pub struct CustomDictionaryServiceClient {
-    pub channel: Channel,
-    pub apikey: String,
-    pub host: String,
-    pub port: u16,
+    pool: EndpointPool,
+    pub config: BareunClientConfig,
 }
 
 impl CustomDictionaryServiceClient {
@@ -59,109 +64,124 @@ impl CustomDictionaryServiceClient {
     ///     host: Bareun 서버 호스트 주소
     ///     port: Bareun 서버 포트 번호. 0 이하면 호스트에 맞춰 자동 설정
     pub async fn new(apikey: &str, host: &str, port: i32) -> Result<Self> {
-        let host_trim = host.trim();
-        let host = if host_trim.is_empty() {
-            "api.bareun.ai"
-        } else {
-            host_trim
-        };
-        let port = resolve_port(host, port);
-        let channel = Self::create_channel(host, port).await?;
-
-        Ok(Self {
-            channel,
-            apikey: apikey.to_string(),
-            host: host.to_string(),
-            port,
-        })
+        let port = u16::try_from(port).ok().filter(|p| *p > 0);
+        Self::from_config(BareunClientConfig::new(apikey, host, port)).await
     }
 
-    async fn create_channel(host: &str, port: u16) -> Result<Channel> {
-        let uri = if is_tls_host(host) {
-            format!("https://{}:{}", host, port)
-        } else {
-            format!("http://{}:{}", host, port)
-        };
-
-        let endpoint = Endpoint::from_shared(uri)?;
+    /// 설정 객체로 클라이언트를 만든다.
+    pub async fn from_config(config: BareunClientConfig) -> Result<Self> {
+        let pool = config.connect().await?;
+        Ok(Self::from_pool(pool, config))
+    }
 
-        let channel = if is_tls_host(host) {
-            let cert = Certificate::from_pem(CA_BUNDLE);
-            let tls = ClientTlsConfig::new().ca_certificate(cert);
-            endpoint.tls_config(tls)?.connect().await?
-        } else {
-            endpoint.connect().await?
-        };
+    /// 서버에 연결하지 않고 클라이언트를 만든다. 연결 오류는 첫 요청에서 드러난다.
+    pub fn connect_lazy(config: BareunClientConfig) -> Result<Self> {
+        let pool = config.connect_lazy()?;
+        Ok(Self::from_pool(pool, config))
+    }
 
-        Ok(channel)
+    /// 이미 연결된 채널 하나를 사용하는 클라이언트를 만든다.
+    pub fn from_channel(channel: Channel, config: BareunClientConfig) -> Self {
+        let pool = EndpointPool::single(&config.host, config.port, channel);
+        Self::from_pool(pool, config)
     }
 
-    fn handle_grpc_error(&self, e: Status) -> BareunError {
-        let code = e.code();
-        let details = e.message();
-        let server_message = if details.is_empty() {
-            "서버에서 추가 메시지를 제공하지 않았습니다.".to_string()
-        } else {
-            details.to_string()
-        };
+    /// 여러 서버의 채널 묶음을 사용하는 클라이언트를 만든다.
+    pub fn from_pool(pool: EndpointPool, config: BareunClientConfig) -> Self {
+        Self { pool, config }
+    }
 
-        match code {
-            tonic::Code::PermissionDenied => BareunError::PermissionDenied {
-                apikey: self.apikey.clone(),
-                message: server_message,
-            },
-            tonic::Code::Unavailable => BareunError::ServerUnavailable {
-                host: self.host.clone(),
-                port: self.port,
-                message: server_message,
-            },
-            tonic::Code::InvalidArgument => BareunError::InvalidArgument {
-                message: server_message,
-            },
-            _ => BareunError::GrpcError(server_message),
-        }
+    fn tonic_client(&self, channel: Channel) -> TonicClient<Channel> {
+        TonicClient::new(channel)
+            .max_decoding_message_size(self.config.max_decoding_message_size)
+            .max_encoding_message_size(self.config.max_encoding_message_size)
     }
 
-    fn tonic_client(&self) -> TonicClient<Channel> {
-        TonicClient::new(self.channel.clone())
-            .max_decoding_message_size(MAX_MESSAGE_LENGTH)
-            .max_encoding_message_size(MAX_MESSAGE_LENGTH)
+    /// 사전 목록을 가져옵니다.
+    pub async fn get_list(&self) -> Result<Vec<CustomDictionaryMeta>> {
+        self.get_list_with(&CallOptions::default()).await
     }
 
-    fn build_request<T>(&self, msg: T) -> Result<Request<T>> {
-        let mut req = Request::new(msg);
-        let api_key = self
-            .apikey
-            .parse()
-            .map_err(BareunError::InvalidMetadataValue)?;
-        req.metadata_mut().insert("api-key", api_key);
-        Ok(req)
+    /// 호출 옵션(제한 시간 등)을 지정해 사전 목록을 가져옵니다.
+    pub async fn get_list_with(&self, options: &CallOptions) -> Result<Vec<CustomDictionaryMeta>> {
+        let res = self
+            .config
+            .call(
+                &self.pool,
+                &GET_CUSTOM_DICTIONARY_LIST,
+                (),
+                options,
+                |channel, req| {
+                    let mut client = self.tonic_client(channel);
+                    async move { client.get_custom_dictionary_list(req).await }
+                },
+            )
+            .await?;
+        Ok(res.domain_dicts)
     }
 
-    /// 사전 목록을 가져옵니다.
-    pub async fn get_list(&mut self) -> Result<Vec<CustomDictionaryMeta>> {
-        let mut client = self.tonic_client();
-        let req = self.build_request(())?;
+    /// `names`의 사용자 사전이 모두 서버에 있는지 확인한다.
+    ///
+    /// # Errors
+    ///
+    /// 없는 사전이 있으면 그 이름들을 `names`의 순서대로 담은 `BareunError::UnknownCustomDicts`를 돌려준다.
+    pub async fn check_exists(&self, names: &[String]) -> Result<()> {
+        self.check_exists_with(names, &CallOptions::default()).await
+    }
 
-        match client.get_custom_dictionary_list(req).await {
-            Ok(res) => Ok(res.into_inner().domain_dicts),
-            Err(e) => Err(self.handle_grpc_error(e)),
+    /// 호출 옵션(제한 시간 등)을 지정해 사용자 사전이 모두 서버에 있는지 확인한다.
+    pub async fn check_exists_with(&self, names: &[String], options: &CallOptions) -> Result<()> {
+        if names.is_empty() {
+            return Ok(());
+        }
+        let existing: HashSet<String> = self
+            .get_list_with(options)
+            .await?
+            .into_iter()
+            .map(|meta| meta.domain_name)
+            .collect();
+        let unknown: Vec<String> = names
+            .iter()
+            .filter(|name| !existing.contains(*name))
+            .cloned()
+            .collect();
+        if unknown.is_empty() {
+            Ok(())
+        } else {
+            Err(BareunError::UnknownCustomDicts(unknown))
         }
     }
 
     /// 정의된 사용자 사전의 내용 전체를 가져온다.
-    pub async fn get(&mut self, domain: &str) -> Result<CustomDictionary> {
-        let mut client = self.tonic_client();
-        let mut req_msg = GetCustomDictionaryRequest::default();
-        req_msg.domain_name = domain.to_string();
-        let req = self.build_request(req_msg)?;
+    pub async fn get(&self, domain: &str) -> Result<CustomDictionary> {
+        self.get_with(domain, &CallOptions::default()).await
+    }
 
-        match client.get_custom_dictionary(req).await {
-            Ok(res) => res.into_inner().dict.ok_or_else(|| BareunError::InvalidArgument {
-                message: format!("server returned empty dict for domain '{}'", domain),
-            }),
-            Err(e) => Err(self.handle_grpc_error(e)),
-        }
+    /// 호출 옵션(제한 시간 등)을 지정해 사용자 사전의 내용 전체를 가져온다.
+    pub async fn get_with(&self, domain: &str, options: &CallOptions) -> Result<CustomDictionary> {
+        let req_msg = GetCustomDictionaryRequest {
+            domain_name: domain.to_string(),
+        };
+        let res = self
+            .config
+            .call(
+                &self.pool,
+                &GET_CUSTOM_DICTIONARY,
+                req_msg,
+                options,
+                |channel, req| {
+                    let mut client = self.tonic_client(channel);
+                    async move { client.get_custom_dictionary(req).await }
+                },
+            )
+            .await?;
+        res.dict.ok_or_else(|| BareunError::NotFound {
+            status: Box::new(RpcStatus::new(
+                Code::NotFound,
+                GET_CUSTOM_DICTIONARY.path,
+                format!("custom dictionary '{}' does not exist", domain),
+            )),
+        })
     }
 
     /// 사용자 사전을 갱신합니다.
@@ -176,8 +196,9 @@ impl CustomDictionaryServiceClient {
     ///     mm: 관형사 단어 집합
     ///     mag: 부사 단어 집합
     ///     ic: 감탄사 단어 집합
+    #[allow(clippy::too_many_arguments)]
     pub async fn update(
-        &mut self,
+        &self,
         domain: &str,
         np: &HashSet<String>,
         cp: &HashSet<String>,
@@ -188,80 +209,155 @@ impl CustomDictionaryServiceClient {
         mag: &HashSet<String>,
         ic: &HashSet<String>,
     ) -> Result<bool> {
-        let mut client = self.tonic_client();
-        let mut req_msg = UpdateCustomDictionaryRequest::default();
-        req_msg.domain_name = domain.to_string();
-
-        let mut dict = CustomDictionary::default();
-        dict.domain_name = domain.to_string();
-        dict.np_set = Some(build_dict_set(domain, "np-set", np));
-        dict.cp_set = Some(build_dict_set(domain, "cp-set", cp));
-        dict.cp_caret_set = Some(build_dict_set(domain, "cp-caret-set", cp_caret));
-        dict.vv_set = Some(build_dict_set(domain, "vv-set", vv));
-        dict.va_set = Some(build_dict_set(domain, "va-set", va));
-        dict.mm_set = Some(build_dict_set(domain, "mm-set", mm));
-        dict.mag_set = Some(build_dict_set(domain, "mag-set", mag));
-        dict.ic_set = Some(build_dict_set(domain, "ic-set", ic));
-
-        req_msg.dict = Some(dict);
-        let req = self.build_request(req_msg)?;
+        self.update_with(
+            domain,
+            np,
+            cp,
+            cp_caret,
+            vv,
+            va,
+            mm,
+            mag,
+            ic,
+            &CallOptions::default(),
+        )
+        .await
+    }
 
-        match client.update_custom_dictionary(req).await {
-            Ok(res) => Ok(res.into_inner().updated_domain_name == domain),
-            Err(e) => Err(self.handle_grpc_error(e)),
-        }
+    /// 호출 옵션(제한 시간 등)을 지정해 사용자 사전을 갱신합니다.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update_with(
+        &self,
+        domain: &str,
+        np: &HashSet<String>,
+        cp: &HashSet<String>,
+        cp_caret: &HashSet<String>,
+        vv: &HashSet<String>,
+        va: &HashSet<String>,
+        mm: &HashSet<String>,
+        mag: &HashSet<String>,
+        ic: &HashSet<String>,
+        options: &CallOptions,
+    ) -> Result<bool> {
+        let dict = CustomDictionary {
+            domain_name: domain.to_string(),
+            np_set: Some(build_dict_set(domain, "np-set", np)),
+            cp_set: Some(build_dict_set(domain, "cp-set", cp)),
+            cp_caret_set: Some(build_dict_set(domain, "cp-caret-set", cp_caret)),
+            vv_set: Some(build_dict_set(domain, "vv-set", vv)),
+            va_set: Some(build_dict_set(domain, "va-set", va)),
+            mm_set: Some(build_dict_set(domain, "mm-set", mm)),
+            mag_set: Some(build_dict_set(domain, "mag-set", mag)),
+            ic_set: Some(build_dict_set(domain, "ic-set", ic)),
+        };
+        let req_msg = UpdateCustomDictionaryRequest {
+            domain_name: domain.to_string(),
+            dict: Some(dict),
+        };
+        let res = self
+            .config
+            .call(
+                &self.pool,
+                &UPDATE_CUSTOM_DICTIONARY,
+                req_msg,
+                options,
+                |channel, req| {
+                    let mut client = self.tonic_client(channel);
+                    async move { client.update_custom_dictionary(req).await }
+                },
+            )
+            .await?;
+        Ok(res.updated_domain_name == domain)
     }
 
     /// 모든 커스텀 사전을 삭제한 다음 삭제한 사전의 이름을 돌려줍니다.
-    pub async fn remove_all(&mut self) -> Result<Vec<String>> {
-        let mut client = self.tonic_client();
-        let mut msg = RemoveCustomDictionariesRequest::default();
-        msg.all = true;
-        let req = self.build_request(msg)?;
+    pub async fn remove_all(&self) -> Result<Vec<String>> {
+        self.remove_all_with(&CallOptions::default()).await
+    }
 
-        match client.remove_custom_dictionaries(req).await {
-            Ok(res) => Ok(res
-                .into_inner()
-                .deleted_domain_names
-                .keys()
-                .cloned()
-                .collect()),
-            Err(e) => Err(self.handle_grpc_error(e)),
-        }
+    /// 호출 옵션(제한 시간 등)을 지정해 모든 커스텀 사전을 삭제합니다.
+    pub async fn remove_all_with(&self, options: &CallOptions) -> Result<Vec<String>> {
+        let msg = RemoveCustomDictionariesRequest {
+            domain_names: vec![],
+            all: true,
+        };
+        let res = self
+            .config
+            .call(
+                &self.pool,
+                &REMOVE_CUSTOM_DICTIONARIES,
+                msg,
+                options,
+                |channel, req| {
+                    let mut client = self.tonic_client(channel);
+                    async move { client.remove_custom_dictionaries(req).await }
+                },
+            )
+            .await?;
+        Ok(res.deleted_domain_names.keys().cloned().collect())
     }
 
     /// 지정한 도메인의 사용자 사전을 삭제한 다음 삭제한 사전의 목록을 반환합니다.
-    pub async fn remove(&mut self, domains: &[String]) -> Result<Vec<String>> {
-        let mut client = self.tonic_client();
-        let mut msg = RemoveCustomDictionariesRequest::default();
-        msg.domain_names = domains.to_vec();
-        msg.all = false;
-        let req = self.build_request(msg)?;
+    pub async fn remove(&self, domains: &[String]) -> Result<Vec<String>> {
+        self.remove_with(domains, &CallOptions::default()).await
+    }
 
-        match client.remove_custom_dictionaries(req).await {
-            Ok(res) => Ok(res
-                .into_inner()
-                .deleted_domain_names
-                .keys()
-                .cloned()
-                .collect()),
-            Err(e) => Err(self.handle_grpc_error(e)),
-        }
+    /// 호출 옵션(제한 시간 등)을 지정해 사용자 사전을 삭제합니다.
+    pub async fn remove_with(
+        &self,
+        domains: &[String],
+        options: &CallOptions,
+    ) -> Result<Vec<String>> {
+        let msg = RemoveCustomDictionariesRequest {
+            domain_names: domains.to_vec(),
+            all: false,
+        };
+        let res = self
+            .config
+            .call(
+                &self.pool,
+                &REMOVE_CUSTOM_DICTIONARIES,
+                msg,
+                options,
+                |channel, req| {
+                    let mut client = self.tonic_client(channel);
+                    async move { client.remove_custom_dictionaries(req).await }
+                },
+            )
+            .await?;
+        Ok(res.deleted_domain_names.keys().cloned().collect())
     }
 
     /// 사용자 사전들 사이의 충돌을 점검합니다.
-    pub async fn check_conflict(
-        &mut self,
+    pub async fn check_conflict(&self, domain_names: &[String]) -> Result<CheckConflictResponse> {
+        self.check_conflict_with(domain_names, &CallOptions::default())
+            .await
+    }
+
+    /// 호출 옵션(제한 시간 등)을 지정해 사용자 사전들 사이의 충돌을 점검합니다.
+    pub async fn check_conflict_with(
+        &self,
         domain_names: &[String],
+        options: &CallOptions,
     ) -> Result<CheckConflictResponse> {
-        let mut client = self.tonic_client();
-        let mut msg = CheckConflictRequest::default();
-        msg.domain_names = domain_names.to_vec();
-        let req = self.build_request(msg)?;
+        let msg = CheckConflictRequest {
+            domain_names: domain_names.to_vec(),
+        };
+        self.config
+            .call(&self.pool, &CHECK_CONFLICT, msg, options, |channel, req| {
+                let mut client = self.tonic_client(channel);
+                async move { client.check_conflict(req).await }
+            })
+            .await
+    }
 
-        match client.check_conflict(req).await {
-            Ok(res) => Ok(res.into_inner()),
-            Err(e) => Err(self.handle_grpc_error(e)),
-        }
+    /// 서버가 요청을 받을 수 있는지 한 번 확인한다.
+    pub async fn ready(&self) -> Result<()> {
+        crate::health::ready(&self.config, &self.pool, &CallOptions::default()).await
+    }
+
+    /// 서버가 준비될 때까지 최대 `timeout` 동안 기다린다.
+    pub async fn wait_ready(&self, timeout: Duration) -> Result<()> {
+        crate::health::wait_ready(&self.config, &self.pool, timeout).await
     }
 }
@@ -1,4 +1,80 @@
+use std::fmt;
+use std::time::Duration;
+
 use thiserror::Error;
+use tonic::metadata::MetadataMap;
+use tonic::{Code, Status};
+
+/// 서버에서 추가 메시지를 주지 않았을 때 사용하는 메시지
+const NO_SERVER_MESSAGE: &str = "서버에서 추가 메시지를 제공하지 않았습니다.";
+
+/// 실패한 RPC의 gRPC 상태
+///
+/// 서버가 돌려준 상태 코드, 메시지, details, 메타데이터를 그대로 담는다.
+#[derive(Clone, Debug)]
+pub struct RpcStatus {
+    pub code: Code,
+    /// 실패한 RPC. 예: `bareun.LanguageService/AnalyzeSyntax`
+    pub method: &'static str,
+    pub message: String,
+    /// `grpc-status-details-bin`의 내용
+    pub details: Vec<u8>,
+    pub metadata: MetadataMap,
+}
+
+impl RpcStatus {
+    pub(crate) fn new(code: Code, method: &'static str, message: impl Into<String>) -> Self {
+        RpcStatus {
+            code,
+            method,
+            message: message.into(),
+            details: Vec::new(),
+            metadata: MetadataMap::new(),
+        }
+    }
+
+    pub(crate) fn from_status(status: &Status, method: &'static str) -> Self {
+        let message = if status.message().is_empty() {
+            NO_SERVER_MESSAGE.to_string()
+        } else {
+            status.message().to_string()
+        };
+        RpcStatus {
+            code: status.code(),
+            method,
+            message,
+            details: status.details().to_vec(),
+            metadata: status.metadata().clone(),
+        }
+    }
+}
+
+impl fmt::Display for RpcStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} failed with {:?}: {}",
+            self.method, self.code, self.message
+        )
+    }
+}
+
+/// 로그에 남겨도 되도록 API 키의 가운데를 가린다.
+///
+/// `koba-ABCDEFG-1234567-LMNOPQR-7654321`은 `koba-****-321`이 된다.
+/// 앞부분은 최대 4글자까지만 보인다. 짧거나 형식을 알 수 없는 키는 전부 가린다.
+pub fn mask_api_key(apikey: &str) -> String {
+    const MAX_PREFIX: usize = 4;
+    let chars: Vec<char> = apikey.chars().collect();
+    match apikey.split_once('-') {
+        Some((prefix, _)) if chars.len() >= 12 => {
+            let prefix: String = prefix.chars().take(MAX_PREFIX).collect();
+            let suffix: String = chars[chars.len() - 3..].iter().collect();
+            format!("{}-****-{}", prefix, suffix)
+        }
+        _ => "****".to_string(),
+    }
+}
 
 #[derive(Error, Debug)]
 pub enum BareunError {
@@ -12,21 +88,54 @@ pub enum BareunError {
         source: tonic::transport::Error,
     },
 
-    #[error("Permission denied. Check your API key: {apikey}\nServer message: {message}")]
-    PermissionDenied { apikey: String, message: String },
+    /// `apikey`는 `mask_api_key`로 가린 값이다.
+    #[error("Permission denied for {}. Check your API key: {apikey}\nServer message: {}", .status.method, .status.message)]
+    PermissionDenied {
+        apikey: String,
+        status: Box<RpcStatus>,
+    },
+
+    /// `apikey`는 `mask_api_key`로 가린 값이다.
+    #[error("Unauthenticated request to {}. Check your API key: {apikey}\nServer message: {}", .status.method, .status.message)]
+    Unauthenticated {
+        apikey: String,
+        status: Box<RpcStatus>,
+    },
 
-    #[error("Server unavailable at {host}:{port}\nServer message: {message}")]
+    #[error("Server unavailable at {host}:{port}\nServer message: {}", .status.message)]
     ServerUnavailable {
         host: String,
         port: u16,
-        message: String,
+        status: Box<RpcStatus>,
+    },
+
+    /// 호출에 지정한 제한 시간(`request_timeout` 또는 `CallOptions::timeout`)이 지났다.
+    #[error("Request timed out (deadline: {timeout:?}): {status}")]
+    Timeout {
+        timeout: Option<Duration>,
+        status: Box<RpcStatus>,
     },
 
+    /// 클라이언트가 제한 시간을 지정하지 않았는데 서버가 `DEADLINE_EXCEEDED`를 돌려줬다.
+    #[error("Deadline exceeded on server: {status}")]
+    DeadlineExceeded { status: Box<RpcStatus> },
+
+    #[error("Resource exhausted: {status}")]
+    ResourceExhausted { status: Box<RpcStatus> },
+
+    /// 없는 사용자 사전 등을 요청했다.
+    #[error("Not found: {status}")]
+    NotFound { status: Box<RpcStatus> },
+
+    /// 서버가 요청을 거부했거나(`status`가 있음) 클라이언트에서 인자를 검사하다 실패했다.
     #[error("Invalid argument: {message}")]
-    InvalidArgument { message: String },
+    InvalidArgument {
+        message: String,
+        status: Option<Box<RpcStatus>>,
+    },
 
     #[error("gRPC error: {0}")]
-    GrpcError(String),
+    GrpcError(Box<RpcStatus>),
 
     #[error("Serialization error: {0}")]
     SerializationError(#[from] serde_json::Error),
@@ -37,11 +146,96 @@ pub enum BareunError {
     #[error("Invalid metadata value")]
     InvalidMetadataValue(#[from] tonic::metadata::errors::InvalidMetadataValue),
 
-    #[error("Invalid custom dictionary name: {0}")]
+    #[error("Invalid custom dictionary name: '{0}'")]
     InvalidCustomDictName(String),
 
+    /// 사용자 사전 파일을 열거나 읽지 못했다.
+    #[error("Failed to read dictionary file {path}: {source}")]
+    DictFile {
+        path: String,
+        source: std::io::Error,
+    },
+
+    /// 서버에 없는 사용자 사전을 지정했다.
+    #[error("Unknown custom dictionaries: {}", .0.join(", "))]
+    UnknownCustomDicts(Vec<String>),
+
+    /// 서버 응답에 있어야 할 값이 비어 있다.
+    #[error("Malformed server response: {0}")]
+    MalformedResponse(String),
+
+    /// 재생 중인 카세트에 일치하는 요청이 기록되어 있지 않다.
+    #[error("No recorded interaction in cassette {path} for {method}: {request}")]
+    CassetteMiss {
+        path: String,
+        method: &'static str,
+        request: String,
+    },
+
+    /// 카세트 파일을 읽거나 쓰지 못했다.
+    #[error("Failed to access cassette file {path}: {source}")]
+    CassetteIo {
+        path: String,
+        source: std::io::Error,
+    },
+
     #[error("gRPC Status error: {0}")]
     StatusError(#[from] tonic::Status),
 }
 
+impl BareunError {
+    /// 클라이언트에서 만든 인자 오류
+    pub(crate) fn invalid_argument(message: impl Into<String>) -> Self {
+        BareunError::InvalidArgument {
+            message: message.into(),
+            status: None,
+        }
+    }
+
+    /// 서버가 돌려준 gRPC 상태. 서버와 통신하지 않고 생긴 오류는 `None`이다.
+    pub fn status(&self) -> Option<&RpcStatus> {
+        match self {
+            BareunError::PermissionDenied { status, .. }
+            | BareunError::Unauthenticated { status, .. }
+            | BareunError::ServerUnavailable { status, .. }
+            | BareunError::Timeout { status, .. }
+            | BareunError::DeadlineExceeded { status }
+            | BareunError::ResourceExhausted { status }
+            | BareunError::NotFound { status }
+            | BareunError::GrpcError(status) => Some(status),
+            BareunError::InvalidArgument { status, .. } => status.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// gRPC 상태 코드
+    pub fn code(&self) -> Option<Code> {
+        match self {
+            BareunError::StatusError(status) => Some(status.code()),
+            _ => self.status().map(|s| s.code),
+        }
+    }
+
+    /// 실패한 RPC 이름. 예: `bareun.LanguageService/AnalyzeSyntax`
+    pub fn method(&self) -> Option<&'static str> {
+        self.status().map(|s| s.method)
+    }
+
+    /// 잠시 뒤 같은 요청을 다시 보내면 성공할 수 있는 오류인지 여부
+    ///
+    /// 연결 실패, 서버 중단, 제한 시간 초과, 자원 부족, `ABORTED`가 해당한다.
+    /// 자동 재시도는 이 값과 별개로 `RetryPolicy::retryable_codes`를 따른다.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            BareunError::ConnectionFailed { .. }
+            | BareunError::ServerUnavailable { .. }
+            | BareunError::Timeout { .. }
+            | BareunError::DeadlineExceeded { .. }
+            | BareunError::ResourceExhausted { .. } => true,
+            BareunError::TransportError(_) => true,
+            _ => matches!(self.code(), Some(Code::Aborted | Code::Unavailable)),
+        }
+    }
+}
+
 pub type Result<T> = std::result::Result<T, BareunError>;
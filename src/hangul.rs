@@ -0,0 +1,194 @@
+//! 한글 음절과 자모
+//!
+//! 자모는 호환 자모(U+3131–U+3163, `ㄱ`, `ㅏ`)로 다룬다. 초성, 중성, 종성을 구분하는 첫가끝
+//! 자모(U+1100–U+11FF)는 `to_compatibility`로 바꾼다.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bareun_rs::hangul;
+//!
+//! let s = hangul::decompose('닭').unwrap();
+//! assert_eq!((s.cho, s.jung, s.jong), ('ㄷ', 'ㅏ', Some('ㄺ')));
+//! assert_eq!(hangul::compose('ㄴ', 'ㅏ', Some('ㄴ')), Some('난'));
+//! assert!(hangul::has_batchim('책'));
+//! assert_eq!(hangul::choseong("나비가 난다"), "ㄴㅂㄱ ㄴㄷ");
+//! ```
+
+const SYLLABLE_BASE: u32 = 0xAC00;
+const SYLLABLE_COUNT: u32 = 11172;
+
+/// 초성으로 쓰는 호환 자모
+pub const CHOSEONG: [char; 19] = [
+    'ㄱ', 'ㄲ', 'ㄴ', 'ㄷ', 'ㄸ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅃ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅉ', 'ㅊ', 'ㅋ',
+    'ㅌ', 'ㅍ', 'ㅎ',
+];
+/// 중성으로 쓰는 호환 자모
+pub const JUNGSEONG: [char; 21] = [
+    'ㅏ', 'ㅐ', 'ㅑ', 'ㅒ', 'ㅓ', 'ㅔ', 'ㅕ', 'ㅖ', 'ㅗ', 'ㅘ', 'ㅙ', 'ㅚ', 'ㅛ', 'ㅜ', 'ㅝ', 'ㅞ',
+    'ㅟ', 'ㅠ', 'ㅡ', 'ㅢ', 'ㅣ',
+];
+/// 종성(받침)으로 쓰는 호환 자모
+pub const JONGSEONG: [char; 27] = [
+    'ㄱ', 'ㄲ', 'ㄳ', 'ㄴ', 'ㄵ', 'ㄶ', 'ㄷ', 'ㄹ', 'ㄺ', 'ㄻ', 'ㄼ', 'ㄽ', 'ㄾ', 'ㄿ', 'ㅀ', 'ㅁ',
+    'ㅂ', 'ㅄ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅊ', 'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ',
+];
+
+/// 초성, 중성, 종성(호환 자모)으로 나눈 한글 음절
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Syllable {
+    pub cho: char,
+    pub jung: char,
+    pub jong: Option<char>,
+}
+
+impl Syllable {
+    pub fn new(cho: char, jung: char, jong: Option<char>) -> Self {
+        Syllable { cho, jung, jong }
+    }
+
+    /// 음절을 만든다. 초성, 중성, 종성에 쓸 수 없는 자모가 있으면 `None`
+    pub fn compose(self) -> Option<char> {
+        let cho = CHOSEONG.iter().position(|c| *c == self.cho)? as u32;
+        let jung = JUNGSEONG.iter().position(|c| *c == self.jung)? as u32;
+        let jong = match self.jong {
+            Some(j) => JONGSEONG.iter().position(|c| *c == j)? as u32 + 1,
+            None => 0,
+        };
+        char::from_u32(SYLLABLE_BASE + (cho * 21 + jung) * 28 + jong)
+    }
+
+    pub fn with_jong(self, jong: Option<char>) -> Self {
+        Syllable { jong, ..self }
+    }
+
+    pub fn with_jung(self, jung: char) -> Self {
+        Syllable { jung, ..self }
+    }
+}
+
+/// 한글 음절(`가`–`힣`)을 초성, 중성, 종성으로 나눈다. 음절이 아니면 `None`
+pub fn decompose(c: char) -> Option<Syllable> {
+    let index = (c as u32)
+        .checked_sub(SYLLABLE_BASE)
+        .filter(|i| *i < SYLLABLE_COUNT)? as usize;
+    let jong = index % 28;
+    Some(Syllable {
+        cho: CHOSEONG[index / 588],
+        jung: JUNGSEONG[index % 588 / 28],
+        jong: (jong > 0).then(|| JONGSEONG[jong - 1]),
+    })
+}
+
+/// 초성, 중성, 종성으로 음절을 만든다. 쓸 수 없는 자모가 있으면 `None`
+pub fn compose(cho: char, jung: char, jong: Option<char>) -> Option<char> {
+    Syllable::new(cho, jung, jong).compose()
+}
+
+/// 완성형 한글 음절인지 여부
+pub fn is_syllable(c: char) -> bool {
+    decompose(c).is_some()
+}
+
+/// 호환 자모(`ㄱ`–`ㅣ`)이거나 첫가끝 자모(U+1100–U+11FF)인지 여부
+pub fn is_jamo(c: char) -> bool {
+    matches!(c, '\u{3131}'..='\u{3163}' | '\u{1100}'..='\u{11FF}')
+}
+
+/// 한글 음절이나 자모인지 여부
+pub fn is_hangul(c: char) -> bool {
+    is_syllable(c) || is_jamo(c)
+}
+
+/// 음절의 받침. 받침이 없거나 음절이 아니면 `None`
+pub fn batchim(c: char) -> Option<char> {
+    decompose(c).and_then(|s| s.jong)
+}
+
+/// 음절에 받침이 있는지 여부. 조사 `이/가`, `을/를`을 고를 때 쓴다.
+pub fn has_batchim(c: char) -> bool {
+    batchim(c).is_some()
+}
+
+/// 문자열의 마지막 글자에 받침이 있는지 여부. 마지막 글자가 한글 음절이 아니면 `None`
+pub fn ends_with_batchim(s: &str) -> Option<bool> {
+    s.chars()
+        .last()
+        .and_then(decompose)
+        .map(|s| s.jong.is_some())
+}
+
+/// 문자열의 초성. 한글 음절이 아닌 글자는 그대로 둔다. 초성 검색에 쓴다.
+pub fn choseong(s: &str) -> String {
+    s.chars()
+        .map(|c| decompose(c).map_or(c, |s| s.cho))
+        .collect()
+}
+
+/// 첫가끝 자모 하나를 호환 자모로 바꾼다. 현대 한글 자모가 아니면 그대로 둔다.
+pub fn compatibility_jamo(c: char) -> char {
+    let table: &[char] = match c {
+        '\u{1100}'..='\u{1112}' => &CHOSEONG,
+        '\u{1161}'..='\u{1175}' => &JUNGSEONG,
+        '\u{11A8}'..='\u{11C2}' => &JONGSEONG,
+        _ => return c,
+    };
+    let first = match c {
+        '\u{1100}'..='\u{1112}' => 0x1100,
+        '\u{1161}'..='\u{1175}' => 0x1161,
+        _ => 0x11A8,
+    };
+    table[(c as u32 - first) as usize]
+}
+
+/// 첫가끝 자모를 완성형 음절과 호환 자모로 바꾼다(NFC와 비슷하다).
+///
+/// 초성+중성(+종성) 순서의 첫가끝 자모는 음절로 합치고, 남는 첫가끝 자모는 호환 자모로 바꾼다.
+/// macOS 파일 이름처럼 풀어 쓴 한글을 서버에 보내기 전에 쓴다.
+pub fn to_compatibility(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        if ('\u{1100}'..='\u{1112}').contains(&c)
+            && let Some(&v) = chars.peek()
+            && ('\u{1161}'..='\u{1175}').contains(&v)
+        {
+            chars.next();
+            let jong = chars
+                .next_if(|t| ('\u{11A8}'..='\u{11C2}').contains(t))
+                .map(compatibility_jamo);
+            if let Some(syllable) = compose(compatibility_jamo(c), compatibility_jamo(v), jong) {
+                out.push(syllable);
+                continue;
+            }
+        }
+        out.push(compatibility_jamo(c));
+    }
+    out
+}
+
+/// 완성형 음절을 첫가끝 자모로 풀어 쓴다(NFD와 같다). 그 밖의 글자는 그대로 둔다.
+pub fn to_conjoining(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() * 3);
+    for c in s.chars() {
+        match (c as u32).checked_sub(SYLLABLE_BASE) {
+            Some(index) if index < SYLLABLE_COUNT => {
+                let jong = index % 28;
+                out.extend(char::from_u32(0x1100 + index / 588));
+                out.extend(char::from_u32(0x1161 + index % 588 / 28));
+                if jong > 0 {
+                    out.extend(char::from_u32(0x11A7 + jong));
+                }
+            }
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// 자모로 시작하는 형태소인지 여부(`ㄴ다`, `ㅂ니다`, `ㄹ`)
+///
+/// 이런 형태소는 앞 음절의 받침으로 붙으므로 원문에 그대로 나타나지 않는다.
+pub fn is_jamo_fragment(text: &str) -> bool {
+    text.chars().next().is_some_and(is_jamo)
+}
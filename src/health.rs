@@ -0,0 +1,128 @@
+use std::time::{Duration, Instant};
+
+use tonic::codegen::http::uri::PathAndQuery;
+use tonic::transport::Channel;
+use tonic::{Code, Response, Status};
+
+use crate::balancer::EndpointPool;
+use crate::config::{BareunClientConfig, CallOptions, RpcMethod};
+use crate::error::{BareunError, Result, RpcStatus};
+
+/// `grpc.health.v1.HealthCheckRequest`
+#[derive(Clone, PartialEq, prost::Message)]
+struct HealthCheckRequest {
+    #[prost(string, tag = "1")]
+    service: String,
+}
+
+/// `grpc.health.v1.HealthCheckResponse`
+#[derive(Clone, PartialEq, prost::Message)]
+struct HealthCheckResponse {
+    #[prost(int32, tag = "1")]
+    status: i32,
+}
+
+const SERVING: i32 = 1;
+const HEALTH_CHECK: RpcMethod = RpcMethod::new("grpc.health.v1.Health/Check", false);
+
+async fn health_check(
+    channel: Channel,
+    request: tonic::Request<HealthCheckRequest>,
+) -> std::result::Result<Response<HealthCheckResponse>, Status> {
+    let mut grpc = tonic::client::Grpc::new(channel);
+    grpc.ready()
+        .await
+        .map_err(|e| Status::unavailable(e.to_string()))?;
+    let codec = tonic_prost::ProstCodec::default();
+    match grpc
+        .unary(
+            request,
+            PathAndQuery::from_static("/grpc.health.v1.Health/Check"),
+            codec,
+        )
+        .await
+    {
+        // health 서비스가 없는 서버라도 응답했다면 요청을 받을 수 있는 상태다.
+        Err(status) if status.code() == Code::Unimplemented => {
+            Ok(Response::new(HealthCheckResponse { status: SERVING }))
+        }
+        result => result,
+    }
+}
+
+/// 풀에서 고른 서버 하나가 요청을 받을 수 있는지 확인한다.
+///
+/// 표준 gRPC health check를 보내며, health 서비스를 제공하지 않는 서버는 응답만 하면
+/// 준비된 것으로 본다. 재시도하지 않는다. 카세트를 재생할 때는 항상 준비된 것으로 본다.
+pub(crate) async fn ready(
+    config: &BareunClientConfig,
+    pool: &EndpointPool,
+    options: &CallOptions,
+) -> Result<()> {
+    if config.replaying() {
+        return Ok(());
+    }
+    let request = HealthCheckRequest {
+        service: String::new(),
+    };
+    let res = config
+        .send(pool, &HEALTH_CHECK, request, options, health_check)
+        .await?;
+    if res.status == SERVING {
+        Ok(())
+    } else {
+        Err(BareunError::ServerUnavailable {
+            host: config.host.clone(),
+            port: config.port,
+            status: Box::new(RpcStatus::new(
+                Code::Unavailable,
+                HEALTH_CHECK.path,
+                "server is not serving",
+            )),
+        })
+    }
+}
+
+/// 서버가 준비될 때까지 `timeout` 동안 반복해서 확인한다.
+///
+/// `is_retryable`인 오류는 다시 시도하고, 그 밖의 오류(API 키 거부 등)는 바로 돌려준다.
+/// 끝내 준비되지 않으면 마지막 오류 메시지를 담은 `BareunError::Timeout`을 돌려준다.
+pub(crate) async fn wait_ready(
+    config: &BareunClientConfig,
+    pool: &EndpointPool,
+    timeout: Duration,
+) -> Result<()> {
+    let deadline = Instant::now() + timeout;
+    let mut backoff = Duration::from_millis(50);
+
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        let last_error = if remaining.is_zero() {
+            None
+        } else {
+            match ready(config, pool, &CallOptions::new().timeout(remaining)).await {
+                Ok(()) => return Ok(()),
+                Err(e) if e.is_retryable() => Some(e),
+                Err(e) => return Err(e),
+            }
+        };
+
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            let message = last_error
+                .map(|e| e.to_string())
+                .unwrap_or_else(|| "server did not become ready".to_string());
+            return Err(BareunError::Timeout {
+                timeout: Some(timeout),
+                status: Box::new(RpcStatus::new(
+                    Code::DeadlineExceeded,
+                    HEALTH_CHECK.path,
+                    message,
+                )),
+            });
+        }
+
+        tokio::time::sleep(backoff.min(remaining)).await;
+        backoff = (backoff * 2).min(Duration::from_secs(1));
+    }
+}
@@ -1,29 +1,85 @@
-use tonic::transport::{Certificate, Channel, ClientTlsConfig, Endpoint};
-use tonic::{Request, Status};
+use std::time::Duration;
 
+use tonic::transport::Channel;
+
+use crate::balancer::EndpointPool;
 use crate::bareun::language_service_client::LanguageServiceClient;
 use crate::bareun::{
     AnalyzeSyntaxListRequest, AnalyzeSyntaxListResponse, AnalyzeSyntaxRequest,
     AnalyzeSyntaxResponse, Document, EncodingType, TokenizeRequest, TokenizeResponse,
 };
-use crate::constants::{CA_BUNDLE, MAX_MESSAGE_LENGTH};
-use crate::error::{BareunError, Result};
+use crate::config::{BareunClientConfig, CallOptions, RpcMethod};
+use crate::error::Result;
+
+pub(crate) const ANALYZE_SYNTAX: RpcMethod =
+    RpcMethod::new("bareun.LanguageService/AnalyzeSyntax", true);
+pub(crate) const ANALYZE_SYNTAX_LIST: RpcMethod =
+    RpcMethod::new("bareun.LanguageService/AnalyzeSyntaxList", true);
+pub(crate) const TOKENIZE: RpcMethod = RpcMethod::new("bareun.LanguageService/Tokenize", true);
+
+pub(crate) fn analyze_syntax_request(
+    content: &str,
+    custom_dicts: &[String],
+    auto_split: bool,
+    auto_spacing: bool,
+    auto_jointing: bool,
+    encoding: EncodingType,
+) -> AnalyzeSyntaxRequest {
+    AnalyzeSyntaxRequest {
+        document: Some(Document {
+            content: content.to_string(),
+            language: "ko_KR".to_string(),
+        }),
+        encoding_type: encoding.into(),
+        auto_split_sentence: auto_split,
+        auto_spacing,
+        auto_jointing,
+        #[allow(deprecated)]
+        custom_domain: String::new(), // deprecated field
+        custom_dict_names: custom_dicts.to_vec(),
+    }
+}
 
-fn resolve_port(host: &str, port: Option<u16>) -> u16 {
-    if let Some(p) = port {
-        p
-    } else if host.to_lowercase().starts_with("api.bareun.ai") {
-        443
-    } else {
-        5656
+pub(crate) fn analyze_syntax_list_request(
+    content: &[String],
+    custom_dicts: &[String],
+    auto_spacing: bool,
+    auto_jointing: bool,
+    encoding: EncodingType,
+) -> AnalyzeSyntaxListRequest {
+    AnalyzeSyntaxListRequest {
+        sentences: content.to_vec(),
+        language: "ko_KR".to_string(),
+        encoding_type: encoding.into(),
+        auto_spacing,
+        auto_jointing,
+        #[allow(deprecated)]
+        custom_domain: String::new(), // deprecated field
+        custom_dict_names: custom_dicts.to_vec(),
     }
 }
 
+pub(crate) fn tokenize_request(
+    content: &str,
+    auto_split: bool,
+    encoding: EncodingType,
+) -> TokenizeRequest {
+    #[allow(deprecated)]
+    TokenizeRequest {
+        document: Some(Document {
+            content: content.to_string(),
+            language: "ko_KR".to_string(),
+        }),
+        encoding_type: encoding.into(),
+        auto_split_sentence: auto_split,
+        auto_spacing: false,
+    }
+}
+
+#[derive(Clone)]
 pub struct BareunLanguageServiceClient {
-    pub client: LanguageServiceClient<Channel>,
-    pub apikey: String,
-    pub host: String,
-    pub port: u16,
+    pool: EndpointPool,
+    pub config: BareunClientConfig,
 }
 
 impl BareunLanguageServiceClient {
@@ -34,71 +90,36 @@ impl BareunLanguageServiceClient {
     ///     host: Bareun 서버 호스트 주소
     ///     port: Bareun 서버 포트 번호
     pub async fn new(apikey: &str, host: &str, port: Option<u16>) -> Result<Self> {
-        let host = host.trim();
-        let host = if host.is_empty() {
-            "api.bareun.ai"
-        } else {
-            host
-        };
-
-        let port = resolve_port(host, port);
-        let channel = Self::create_channel(host, port).await?;
-        let client = LanguageServiceClient::new(channel)
-            .max_decoding_message_size(MAX_MESSAGE_LENGTH)
-            .max_encoding_message_size(MAX_MESSAGE_LENGTH);
-
-        Ok(BareunLanguageServiceClient {
-            client,
-            apikey: apikey.to_string(),
-            host: host.to_string(),
-            port,
-        })
-    }
-
-    async fn create_channel(host: &str, port: u16) -> Result<Channel> {
-        let uri = if host.to_lowercase().starts_with("api.bareun.ai") {
-            format!("https://{}:{}", host, port)
-        } else {
-            format!("http://{}:{}", host, port)
-        };
-
-        let endpoint = Endpoint::from_shared(uri)?;
-
-        let channel = if host.to_lowercase().starts_with("api.bareun.ai") {
-            let cert = Certificate::from_pem(CA_BUNDLE);
-            let tls = ClientTlsConfig::new().ca_certificate(cert);
-            endpoint.tls_config(tls)?.connect().await?
-        } else {
-            endpoint.connect().await?
-        };
-
-        Ok(channel)
-    }
-
-    fn handle_grpc_error(&self, e: Status) -> BareunError {
-        let code = e.code();
-        let details = e.message();
-        let server_message = if details.is_empty() {
-            "서버에서 추가 메시지를 제공하지 않았습니다.".to_string()
-        } else {
-            details.to_string()
-        };
-
-        match code {
-            tonic::Code::PermissionDenied => BareunError::PermissionDenied {
-                apikey: self.apikey.clone(),
-                message: server_message,
-            },
-            tonic::Code::Unavailable => BareunError::ServerUnavailable {
-                host: self.host.clone(),
-                port: self.port,
-                message: server_message,
-            },
-            tonic::Code::InvalidArgument => BareunError::InvalidArgument {
-                message: server_message,
-            },
-            _ => BareunError::GrpcError(server_message),
-        }
+        Self::from_config(BareunClientConfig::new(apikey, host, port)).await
+    }
+
+    /// 설정 객체로 클라이언트를 만든다.
+    pub async fn from_config(config: BareunClientConfig) -> Result<Self> {
+        let pool = config.connect().await?;
+        Ok(Self::from_pool(pool, config))
+    }
+
+    /// 서버에 연결하지 않고 클라이언트를 만든다. 연결 오류는 첫 요청에서 드러난다.
+    pub fn connect_lazy(config: BareunClientConfig) -> Result<Self> {
+        let pool = config.connect_lazy()?;
+        Ok(Self::from_pool(pool, config))
+    }
+
+    /// 이미 연결된 채널 하나를 사용하는 클라이언트를 만든다.
+    pub fn from_channel(channel: Channel, config: BareunClientConfig) -> Self {
+        let pool = EndpointPool::single(&config.host, config.port, channel);
+        Self::from_pool(pool, config)
+    }
+
+    /// 여러 서버의 채널 묶음을 사용하는 클라이언트를 만든다.
+    pub fn from_pool(pool: EndpointPool, config: BareunClientConfig) -> Self {
+        BareunLanguageServiceClient { pool, config }
+    }
+
+    fn tonic_client(&self, channel: Channel) -> LanguageServiceClient<Channel> {
+        LanguageServiceClient::new(channel)
+            .max_decoding_message_size(self.config.max_decoding_message_size)
+            .max_encoding_message_size(self.config.max_encoding_message_size)
     }
 
     /// 형태소 분석을 수행합니다.
@@ -110,93 +131,157 @@ impl BareunLanguageServiceClient {
     ///     auto_spacing: 띄어쓰기 보정 기능
     ///     auto_jointing: 붙여쓰기 보정 기능
     pub async fn analyze_syntax(
-        &mut self,
+        &self,
         content: &str,
         custom_dicts: &[String],
         auto_split: bool,
         auto_spacing: bool,
         auto_jointing: bool,
     ) -> Result<AnalyzeSyntaxResponse> {
-        let req = AnalyzeSyntaxRequest {
-            document: Some(Document {
-                content: content.to_string(),
-                language: "ko_KR".to_string(),
-            }),
-            encoding_type: EncodingType::Utf32.into(),
-            auto_split_sentence: auto_split,
+        self.analyze_syntax_with(
+            content,
+            custom_dicts,
+            auto_split,
             auto_spacing,
             auto_jointing,
-            #[allow(deprecated)]
-            custom_domain: String::new(), // deprecated field
-            custom_dict_names: custom_dicts.to_vec(),
-        };
+            &CallOptions::default(),
+        )
+        .await
+    }
 
-        let mut request = Request::new(req);
-        request
-            .metadata_mut()
-            .insert("api-key", self.apikey.parse().unwrap());
+    /// 호출 옵션(제한 시간 등)을 지정해 형태소 분석을 수행합니다.
+    pub async fn analyze_syntax_with(
+        &self,
+        content: &str,
+        custom_dicts: &[String],
+        auto_split: bool,
+        auto_spacing: bool,
+        auto_jointing: bool,
+        options: &CallOptions,
+    ) -> Result<AnalyzeSyntaxResponse> {
+        let req = analyze_syntax_request(
+            content,
+            custom_dicts,
+            auto_split,
+            auto_spacing,
+            auto_jointing,
+            self.config.encoding_for(options),
+        );
+        self.send_analyze_syntax(req, options).await
+    }
 
-        match self.client.analyze_syntax(request).await {
-            Ok(response) => Ok(response.into_inner()),
-            Err(e) => Err(self.handle_grpc_error(e)),
-        }
+    pub(crate) async fn send_analyze_syntax(
+        &self,
+        req: AnalyzeSyntaxRequest,
+        options: &CallOptions,
+    ) -> Result<AnalyzeSyntaxResponse> {
+        self.config
+            .call(
+                &self.pool,
+                &ANALYZE_SYNTAX,
+                req,
+                options,
+                |channel, request| {
+                    let mut client = self.tonic_client(channel);
+                    async move { client.analyze_syntax(request).await }
+                },
+            )
+            .await
     }
 
     /// 형태소 분석을 수행하되, 입력된 문장 단위가 일치하도록 반환됩니다.
     pub async fn analyze_syntax_list(
-        &mut self,
+        &self,
         content: &[String],
         custom_dicts: &[String],
         auto_spacing: bool,
         auto_jointing: bool,
     ) -> Result<AnalyzeSyntaxListResponse> {
-        let req = AnalyzeSyntaxListRequest {
-            sentences: content.to_vec(),
-            language: "ko_KR".to_string(),
-            encoding_type: EncodingType::Utf32.into(),
+        self.analyze_syntax_list_with(
+            content,
+            custom_dicts,
             auto_spacing,
             auto_jointing,
-            #[allow(deprecated)]
-            custom_domain: String::new(), // deprecated field
-            custom_dict_names: custom_dicts.to_vec(),
-        };
+            &CallOptions::default(),
+        )
+        .await
+    }
 
-        let mut request = Request::new(req);
-        request
-            .metadata_mut()
-            .insert("api-key", self.apikey.parse().unwrap());
+    /// 호출 옵션(제한 시간 등)을 지정해 문장 목록의 형태소 분석을 수행합니다.
+    pub async fn analyze_syntax_list_with(
+        &self,
+        content: &[String],
+        custom_dicts: &[String],
+        auto_spacing: bool,
+        auto_jointing: bool,
+        options: &CallOptions,
+    ) -> Result<AnalyzeSyntaxListResponse> {
+        let req = analyze_syntax_list_request(
+            content,
+            custom_dicts,
+            auto_spacing,
+            auto_jointing,
+            self.config.encoding_for(options),
+        );
+        self.send_analyze_syntax_list(req, options).await
+    }
 
-        match self.client.analyze_syntax_list(request).await {
-            Ok(response) => Ok(response.into_inner()),
-            Err(e) => Err(self.handle_grpc_error(e)),
-        }
+    pub(crate) async fn send_analyze_syntax_list(
+        &self,
+        req: AnalyzeSyntaxListRequest,
+        options: &CallOptions,
+    ) -> Result<AnalyzeSyntaxListResponse> {
+        self.config
+            .call(
+                &self.pool,
+                &ANALYZE_SYNTAX_LIST,
+                req,
+                options,
+                |channel, request| {
+                    let mut client = self.tonic_client(channel);
+                    async move { client.analyze_syntax_list(request).await }
+                },
+            )
+            .await
     }
 
     /// 토크나이즈를 수행합니다.
-    pub async fn tokenize(
-        &mut self,
+    pub async fn tokenize(&self, content: &str, auto_split: bool) -> Result<TokenizeResponse> {
+        self.tokenize_with(content, auto_split, &CallOptions::default())
+            .await
+    }
+
+    /// 호출 옵션(제한 시간 등)을 지정해 토크나이즈를 수행합니다.
+    pub async fn tokenize_with(
+        &self,
         content: &str,
         auto_split: bool,
+        options: &CallOptions,
     ) -> Result<TokenizeResponse> {
-        #[allow(deprecated)]
-        let req = TokenizeRequest {
-            document: Some(Document {
-                content: content.to_string(),
-                language: "ko_KR".to_string(),
-            }),
-            encoding_type: EncodingType::Utf32.into(),
-            auto_split_sentence: auto_split,
-            auto_spacing: false,
-        };
-
-        let mut request = Request::new(req);
-        request
-            .metadata_mut()
-            .insert("api-key", self.apikey.parse().unwrap());
-
-        match self.client.tokenize(request).await {
-            Ok(response) => Ok(response.into_inner()),
-            Err(e) => Err(self.handle_grpc_error(e)),
-        }
+        let req = tokenize_request(content, auto_split, self.config.encoding_for(options));
+        self.send_tokenize(req, options).await
+    }
+
+    pub(crate) async fn send_tokenize(
+        &self,
+        req: TokenizeRequest,
+        options: &CallOptions,
+    ) -> Result<TokenizeResponse> {
+        self.config
+            .call(&self.pool, &TOKENIZE, req, options, |channel, request| {
+                let mut client = self.tonic_client(channel);
+                async move { client.tokenize(request).await }
+            })
+            .await
+    }
+
+    /// 서버가 요청을 받을 수 있는지 한 번 확인한다.
+    pub async fn ready(&self) -> Result<()> {
+        crate::health::ready(&self.config, &self.pool, &CallOptions::default()).await
+    }
+
+    /// 서버가 준비될 때까지 최대 `timeout` 동안 기다린다.
+    pub async fn wait_ready(&self, timeout: Duration) -> Result<()> {
+        crate::health::wait_ready(&self.config, &self.pool, timeout).await
     }
 }
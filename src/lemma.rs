@@ -0,0 +1,118 @@
+use std::borrow::Cow;
+
+use crate::model::Tag;
+
+/// 어절의 기본형(사전형)을 만드는 방법
+///
+/// 기본값은 파생 접미사와 지정사를 모두 남긴다(`카톡했다` → `카톡하다`, `학생이다` → `학생이다`).
+/// 검색 색인처럼 어근만 필요하면 끈다.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let opts = LemmaOptions::new().keep_derivation(false).keep_copula(false);
+/// // 카톡했다 → 카톡, 학생이다 → 학생, 먹었다 → 먹다
+/// let lemmas = tagged.lemmas_with(&opts);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LemmaOptions {
+    /// 동사/형용사 파생 접미사(XSV, XSA)를 남길지 여부
+    pub keep_derivation: bool,
+    /// 긍정 지정사(VCP, `이다`)를 남길지 여부
+    pub keep_copula: bool,
+}
+
+impl Default for LemmaOptions {
+    fn default() -> Self {
+        LemmaOptions {
+            keep_derivation: true,
+            keep_copula: true,
+        }
+    }
+}
+
+impl LemmaOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn keep_derivation(mut self, v: bool) -> Self {
+        self.keep_derivation = v;
+        self
+    }
+
+    pub fn keep_copula(mut self, v: bool) -> Self {
+        self.keep_copula = v;
+        self
+    }
+
+    /// 이 태그의 형태소를 기본형에서 뺄지 여부
+    fn drops(&self, tag: Tag) -> bool {
+        match tag {
+            Tag::Xsv | Tag::Xsa => !self.keep_derivation,
+            Tag::Vcp => !self.keep_copula,
+            _ => false,
+        }
+    }
+}
+
+/// 어절의 형태소로 기본형을 만든다.
+///
+/// 첫 내용어(접두사 포함)부터 어간을 이루는 형태소(체언, 용언, 어근, 수식언, 접사, 지정사)를
+/// 이어 붙이고, 조사, 어미, 기호에서 멈춘다. 어간이 용언이나 용언 파생 접미사로 끝나면 `다`를
+/// 붙인다. 형태소는 서버가 이미 원형으로 돌려주므로(`갔다` → `가/VV+았/EP+다/EF`) 불규칙
+/// 활용은 따로 다루지 않는다.
+///
+/// Args:
+///     morphemes: 어절의 (형태소, 태그)
+///     options: 파생 접미사와 지정사를 남길지 여부
+///
+/// Returns:
+///     String: 기본형. 내용어가 없는 어절(조사, 기호만 있는 어절)은 빈 문자열
+pub fn lemmatize<'a>(
+    morphemes: impl IntoIterator<Item = (&'a str, Tag)>,
+    options: &LemmaOptions,
+) -> String {
+    let mut stem = String::new();
+    let mut last = None;
+    for (text, tag) in morphemes {
+        let part_of_stem =
+            tag.is_content() || matches!(tag, Tag::Xpn | Tag::Xsn | Tag::Xsv | Tag::Xsa | Tag::Vcp);
+        if last.is_none() && !part_of_stem {
+            // 어절 앞의 기호(따옴표, 괄호)는 건너뛴다.
+            if tag.is_symbol() {
+                continue;
+            }
+            return String::new();
+        }
+        if !part_of_stem || (last.is_some() && options.drops(tag)) {
+            break;
+        }
+        stem.push_str(text);
+        last = Some(tag);
+    }
+    match last {
+        Some(tag) if tag.is_predicate() || matches!(tag, Tag::Xsv | Tag::Xsa) => stem + "다",
+        _ => stem,
+    }
+}
+
+/// 서버가 준 기본형을 그대로 쓸 수 있으면 쓰고, 아니면 형태소로 만든다.
+///
+/// 서버의 기본형에는 옵션이 적용되지 않으므로, 옵션이 빼라는 형태소가 어절에 있으면 규칙을 쓴다.
+pub(crate) fn lemma_or_rules<'a>(
+    server: &'a str,
+    morphemes: impl IntoIterator<Item = (&'a str, Tag)> + Clone,
+    options: &LemmaOptions,
+) -> Cow<'a, str> {
+    if !server.is_empty()
+        && !morphemes
+            .clone()
+            .into_iter()
+            .any(|(_, tag)| options.drops(tag))
+    {
+        Cow::Borrowed(server)
+    } else {
+        Cow::Owned(lemmatize(morphemes, options))
+    }
+}
@@ -36,6 +36,12 @@
 //! CustomDict  
 //!     Custom dictionary for Korean.  
 //!     `use bareun_rs::bareun::CustomDict;`  
+//! hangul  
+//!     Hangul syllable and jamo utilities  
+//!     `use bareun_rs::hangul;`  
+//! model  
+//!     Owned analysis result types (`Document`, `Sentence`, `Eojeol`, `Morpheme`)  
+//!     `use bareun_rs::model::Document;`  
 //!
 //!
 //! Get bareun
@@ -43,24 +49,51 @@
 //! - Use docker, <https://hub.docker.com/r/bareunai/bareun>
 //! - Or visit <https://bareun.ai/>
 
+mod backend;
+mod balancer;
+mod cassette;
+mod config;
+mod conjugation;
 mod constants;
 mod corrector;
 mod custom_dict;
 mod custom_dict_client;
 mod error;
+pub mod hangul;
+mod health;
 mod lang_service_client;
+mod lemma;
+#[cfg(feature = "mock-server")]
+pub mod mock;
+pub mod model;
+mod offsets;
+mod retry;
 mod revision_service_client;
+mod session;
+mod spacing;
 mod tagger;
 mod tokenizer;
+mod view;
 
+pub use crate::backend::*;
+pub use crate::balancer::*;
+pub use crate::cassette::*;
+pub use crate::config::*;
+pub use crate::conjugation::*;
 pub use crate::corrector::*;
 pub use crate::custom_dict::*;
 pub use crate::custom_dict_client::*;
 pub use crate::error::*;
 pub use crate::lang_service_client::*;
+pub use crate::lemma::*;
+pub use crate::offsets::*;
+pub use crate::retry::*;
 pub use crate::revision_service_client::*;
+pub use crate::session::*;
+pub use crate::spacing::*;
 pub use crate::tagger::*;
 pub use crate::tokenizer::*;
+pub use crate::view::*;
 
 pub mod bareun {
     tonic::include_proto!("bareun");
@@ -0,0 +1,823 @@
+//! 테스트용 인-프로세스 Bareun 서버
+//!
+//! `mock-server` 기능을 켜면 사용할 수 있다. 실제 서버나 docker 이미지 없이
+//! `LanguageService`, `RevisionService`, `CustomDictionaryService`를 흉내 낸다.
+//!
+//! - 미리 등록한 응답(canned response)을 돌려주고, 등록하지 않은 문장은 어절 단위로 단순하게 분석한다.
+//! - 메서드별로 오류(`PermissionDenied`, `Unavailable` 등)와 지연을 주입할 수 있다.
+//! - 받은 요청을 메타데이터와 함께 기록한다.
+//! - 사용자 사전은 메모리에 저장한다.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use bareun_rs::Tagger;
+//! use bareun_rs::mock::{MockMethod, MockServer, analyze_syntax_response};
+//!
+//! let server = MockServer::start().await?;
+//! server.on_analyze_syntax(
+//!     "오늘은",
+//!     analyze_syntax_response("오늘은", &[("오늘은", &[("오늘", "NNG"), ("은", "JX")])]),
+//! );
+//! server.fail_times(MockMethod::AnalyzeSyntax, 1, tonic::Status::unavailable("restarting"));
+//!
+//! let tagger = Tagger::from_config(server.config("koba-TEST"), vec![]).await?;
+//! assert!(tagger.morphs("오늘은").await.is_err());
+//! assert_eq!(tagger.morphs("오늘은").await?, vec!["오늘", "은"]);
+//! assert_eq!(server.requests_for(MockMethod::AnalyzeSyntax).len(), 2);
+//! ```
+
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+use tokio::sync::oneshot;
+use tokio_stream::Stream;
+use tonic::metadata::MetadataMap;
+use tonic::transport::Server;
+use tonic::transport::server::TcpIncoming;
+use tonic::{Request, Response, Status};
+
+use crate::bareun::custom_dictionary_meta::DictMeta;
+use crate::bareun::custom_dictionary_service_server::{
+    CustomDictionaryService, CustomDictionaryServiceServer,
+};
+use crate::bareun::language_service_server::{LanguageService, LanguageServiceServer};
+use crate::bareun::morpheme::Tag;
+use crate::bareun::revision_service_server::{RevisionService, RevisionServiceServer};
+use crate::bareun::stream_correct_error_response::Res;
+use crate::bareun::{
+    AnalyzeSyntaxListRequest, AnalyzeSyntaxListResponse, AnalyzeSyntaxRequest,
+    AnalyzeSyntaxResponse, CheckConflictRequest, CheckConflictResponse, CorrectErrorRequest,
+    CorrectErrorResponse, CustomDictionary, CustomDictionaryMeta, DictSet,
+    GetCustomDictionaryListResponse, GetCustomDictionaryRequest, GetCustomDictionaryResponse,
+    Morpheme, RemoveCustomDictionariesRequest, RemoveCustomDictionariesResponse, Segment,
+    SegmentSentence, SegmentToken, Sentence, StreamCorrectErrorRequest, StreamCorrectErrorResponse,
+    StreamFirstCorrectError, TextSpan, Token, TokenizeRequest, TokenizeResponse,
+    UpdateCustomDictionaryRequest, UpdateCustomDictionaryResponse,
+};
+use crate::config::BareunClientConfig;
+
+/// 목 서버가 흉내 내는 RPC
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MockMethod {
+    AnalyzeSyntax,
+    AnalyzeSyntaxList,
+    Tokenize,
+    CorrectError,
+    StreamCorrectError,
+    GetCustomDictionaryList,
+    GetCustomDictionary,
+    UpdateCustomDictionary,
+    RemoveCustomDictionaries,
+    CheckConflict,
+}
+
+impl MockMethod {
+    /// gRPC 메서드 경로. 예: `bareun.LanguageService/AnalyzeSyntax`
+    pub fn path(&self) -> &'static str {
+        match self {
+            MockMethod::AnalyzeSyntax => "bareun.LanguageService/AnalyzeSyntax",
+            MockMethod::AnalyzeSyntaxList => "bareun.LanguageService/AnalyzeSyntaxList",
+            MockMethod::Tokenize => "bareun.LanguageService/Tokenize",
+            MockMethod::CorrectError => "bareun.RevisionService/CorrectError",
+            MockMethod::StreamCorrectError => "bareun.RevisionService/StreamCorrectError",
+            MockMethod::GetCustomDictionaryList => {
+                "bareun.CustomDictionaryService/GetCustomDictionaryList"
+            }
+            MockMethod::GetCustomDictionary => "bareun.CustomDictionaryService/GetCustomDictionary",
+            MockMethod::UpdateCustomDictionary => {
+                "bareun.CustomDictionaryService/UpdateCustomDictionary"
+            }
+            MockMethod::RemoveCustomDictionaries => {
+                "bareun.CustomDictionaryService/RemoveCustomDictionaries"
+            }
+            MockMethod::CheckConflict => "bareun.CustomDictionaryService/CheckConflict",
+        }
+    }
+}
+
+/// 목 서버가 받은 요청 메시지
+// 테스트에서 바로 패턴 매칭하도록 상자에 담지 않는다.
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug)]
+pub enum MockMessage {
+    AnalyzeSyntax(AnalyzeSyntaxRequest),
+    AnalyzeSyntaxList(AnalyzeSyntaxListRequest),
+    Tokenize(TokenizeRequest),
+    CorrectError(CorrectErrorRequest),
+    StreamCorrectError(StreamCorrectErrorRequest),
+    GetCustomDictionaryList,
+    GetCustomDictionary(GetCustomDictionaryRequest),
+    UpdateCustomDictionary(UpdateCustomDictionaryRequest),
+    RemoveCustomDictionaries(RemoveCustomDictionariesRequest),
+    CheckConflict(CheckConflictRequest),
+}
+
+/// 목 서버가 기록한 요청 하나
+#[derive(Clone, Debug)]
+pub struct RecordedRequest {
+    pub method: MockMethod,
+    /// `api-key`, `grpc-timeout` 등 요청에 실려 온 메타데이터
+    pub metadata: MetadataMap,
+    pub message: MockMessage,
+}
+
+impl RecordedRequest {
+    /// 요청에 실린 `api-key` 값
+    pub fn api_key(&self) -> Option<&str> {
+        self.metadata.get("api-key").and_then(|v| v.to_str().ok())
+    }
+
+    /// 요청에 실린 `grpc-timeout` 값. 예: `500m`
+    pub fn grpc_timeout(&self) -> Option<&str> {
+        self.metadata
+            .get("grpc-timeout")
+            .and_then(|v| v.to_str().ok())
+    }
+}
+
+/// 주입한 오류. `remaining`이 `None`이면 `reset`할 때까지 계속 실패한다.
+struct Fault {
+    status: Status,
+    remaining: Option<usize>,
+}
+
+#[derive(Default)]
+struct MockState {
+    api_key: Option<String>,
+    analyze: HashMap<String, AnalyzeSyntaxResponse>,
+    tokenize: HashMap<String, TokenizeResponse>,
+    correct: HashMap<String, CorrectErrorResponse>,
+    faults: HashMap<MockMethod, VecDeque<Fault>>,
+    delays: HashMap<MockMethod, Duration>,
+    requests: Vec<RecordedRequest>,
+    dicts: BTreeMap<String, CustomDictionary>,
+}
+
+#[derive(Clone, Default)]
+struct Shared(Arc<Mutex<MockState>>);
+
+impl Shared {
+    fn lock(&self) -> MutexGuard<'_, MockState> {
+        // 테스트가 패닉해도 다른 요청은 계속 처리한다.
+        self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// 요청을 기록하고, 설정된 지연과 오류를 적용한다.
+    async fn accept(
+        &self,
+        method: MockMethod,
+        metadata: &MetadataMap,
+        message: MockMessage,
+    ) -> Result<(), Status> {
+        let delay = {
+            let mut state = self.lock();
+            state.requests.push(RecordedRequest {
+                method,
+                metadata: metadata.clone(),
+                message,
+            });
+            state.delays.get(&method).copied()
+        };
+        if let Some(delay) = delay {
+            tokio::time::sleep(delay).await;
+        }
+
+        let mut state = self.lock();
+        if let Some(expected) = &state.api_key {
+            match metadata.get("api-key").and_then(|v| v.to_str().ok()) {
+                None => return Err(Status::unauthenticated("api-key is missing")),
+                Some(key) if key != expected => {
+                    return Err(Status::permission_denied("api-key is not valid"));
+                }
+                Some(_) => {}
+            }
+        }
+
+        let Some(faults) = state.faults.get_mut(&method) else {
+            return Ok(());
+        };
+        let Some(fault) = faults.front_mut() else {
+            return Ok(());
+        };
+        let status = fault.status.clone();
+        if let Some(remaining) = &mut fault.remaining {
+            *remaining -= 1;
+            if *remaining == 0 {
+                faults.pop_front();
+            }
+        }
+        Err(status)
+    }
+}
+
+/// 테스트용 Bareun 서버. 드롭하면 서버가 멈춘다.
+pub struct MockServer {
+    addr: SocketAddr,
+    shared: Shared,
+    shutdown: Option<oneshot::Sender<()>>,
+}
+
+impl MockServer {
+    /// `127.0.0.1`의 빈 포트에서 서버를 시작한다. Tokio 런타임 안에서 호출해야 한다.
+    pub async fn start() -> std::io::Result<Self> {
+        Self::start_on("127.0.0.1:0".parse().expect("valid socket address")).await
+    }
+
+    /// 지정한 주소에서 서버를 시작한다. 포트가 0이면 빈 포트를 고른다.
+    pub async fn start_on(addr: SocketAddr) -> std::io::Result<Self> {
+        let incoming = TcpIncoming::bind(addr)?;
+        let addr = incoming.local_addr()?;
+        let shared = Shared::default();
+        let (tx, rx) = oneshot::channel::<()>();
+
+        tokio::spawn(
+            Server::builder()
+                .add_service(LanguageServiceServer::new(MockLanguageService(
+                    shared.clone(),
+                )))
+                .add_service(RevisionServiceServer::new(MockRevisionService(
+                    shared.clone(),
+                )))
+                .add_service(CustomDictionaryServiceServer::new(
+                    MockCustomDictionaryService(shared.clone()),
+                ))
+                .serve_with_incoming_shutdown(incoming, async {
+                    let _ = rx.await;
+                }),
+        );
+
+        Ok(MockServer {
+            addr,
+            shared,
+            shutdown: Some(tx),
+        })
+    }
+
+    /// 서버가 받는 주소
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// 서버가 받는 포트
+    pub fn port(&self) -> u16 {
+        self.addr.port()
+    }
+
+    /// 이 서버에 연결하는 설정. 클라이언트 설정을 더 바꾸려면 `BareunClientConfig::builder`를 쓴다.
+    pub fn config(&self, apikey: &str) -> BareunClientConfig {
+        BareunClientConfig::new(apikey, &self.addr.ip().to_string(), Some(self.port()))
+    }
+
+    /// 지정한 API 키만 받는다. 다른 키는 `PERMISSION_DENIED`, 키가 없으면 `UNAUTHENTICATED`로 거절한다.
+    pub fn require_api_key(&self, apikey: &str) {
+        self.shared.lock().api_key = Some(apikey.to_string());
+    }
+
+    /// `content`를 분석해 달라는 `AnalyzeSyntax` 요청에 `response`를 돌려준다.
+    ///
+    /// `AnalyzeSyntaxList`는 문장마다 이 응답의 문장들을 사용한다.
+    pub fn on_analyze_syntax(&self, content: &str, response: AnalyzeSyntaxResponse) {
+        self.shared
+            .lock()
+            .analyze
+            .insert(content.to_string(), response);
+    }
+
+    /// `content`에 대한 `Tokenize` 요청에 `response`를 돌려준다.
+    pub fn on_tokenize(&self, content: &str, response: TokenizeResponse) {
+        self.shared
+            .lock()
+            .tokenize
+            .insert(content.to_string(), response);
+    }
+
+    /// `content`에 대한 `CorrectError`, `StreamCorrectError` 요청에 `response`를 돌려준다.
+    pub fn on_correct_error(&self, content: &str, response: CorrectErrorResponse) {
+        self.shared
+            .lock()
+            .correct
+            .insert(content.to_string(), response);
+    }
+
+    /// `reset`할 때까지 `method` 호출을 모두 `status`로 실패시킨다.
+    pub fn fail(&self, method: MockMethod, status: Status) {
+        self.push_fault(method, status, None);
+    }
+
+    /// 다음 `times`번의 `method` 호출을 `status`로 실패시킨다.
+    ///
+    /// 여러 번 부르면 주입한 순서대로 적용된다.
+    pub fn fail_times(&self, method: MockMethod, times: usize, status: Status) {
+        if times > 0 {
+            self.push_fault(method, status, Some(times));
+        }
+    }
+
+    fn push_fault(&self, method: MockMethod, status: Status, remaining: Option<usize>) {
+        self.shared
+            .lock()
+            .faults
+            .entry(method)
+            .or_default()
+            .push_back(Fault { status, remaining });
+    }
+
+    /// `method` 호출마다 `delay`만큼 기다린 뒤 응답한다.
+    pub fn delay(&self, method: MockMethod, delay: Duration) {
+        self.shared.lock().delays.insert(method, delay);
+    }
+
+    /// 주입한 오류와 지연, API 키 검사를 없앤다. 등록한 응답과 기록, 사용자 사전은 그대로 둔다.
+    pub fn reset(&self) {
+        let mut state = self.shared.lock();
+        state.faults.clear();
+        state.delays.clear();
+        state.api_key = None;
+    }
+
+    /// 지금까지 받은 요청 전체
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.shared.lock().requests.clone()
+    }
+
+    /// `method`로 받은 요청
+    pub fn requests_for(&self, method: MockMethod) -> Vec<RecordedRequest> {
+        self.shared
+            .lock()
+            .requests
+            .iter()
+            .filter(|r| r.method == method)
+            .cloned()
+            .collect()
+    }
+
+    /// 요청 기록을 지운다.
+    pub fn clear_requests(&self) {
+        self.shared.lock().requests.clear();
+    }
+
+    /// 서버에 저장된 사용자 사전
+    pub fn custom_dictionary(&self, domain: &str) -> Option<CustomDictionary> {
+        self.shared.lock().dicts.get(domain).cloned()
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        if let Some(tx) = self.shutdown.take() {
+            let _ = tx.send(());
+        }
+    }
+}
+
+/// `text`에서 `from` 이후 처음 나오는 `part`의 글자 단위 위치
+fn span(text: &str, part: &str, from: &mut usize) -> Option<TextSpan> {
+    let byte_from = text
+        .char_indices()
+        .nth(*from)
+        .map(|(i, _)| i)
+        .unwrap_or(text.len());
+    let begin = match text[byte_from..].find(part) {
+        Some(i) => *from + text[byte_from..byte_from + i].chars().count(),
+        None => *from,
+    };
+    let length = part.chars().count();
+    *from = begin + length;
+    Some(TextSpan {
+        content: part.to_string(),
+        begin_offset: begin as i32,
+        length: length as i32,
+    })
+}
+
+/// 형태 분석 응답을 만든다.
+///
+/// Args:
+///     text: 분석한 문장
+///     tokens: 어절마다 (어절, [(형태소, 품사 태그)]). 품사 태그는 `NNG`, `JX`처럼 쓴다.
+///             알 수 없는 태그는 `NA`가 된다.
+pub fn analyze_syntax_response(
+    text: &str,
+    tokens: &[(&str, &[(&str, &str)])],
+) -> AnalyzeSyntaxResponse {
+    let mut offset = 0;
+    let tokens: Vec<Token> = tokens
+        .iter()
+        .map(|(eojeol, morphs)| {
+            let token_text = span(text, eojeol, &mut offset);
+            let mut morph_offset = token_text.as_ref().map_or(0, |t| t.begin_offset as usize);
+            let morphemes: Vec<Morpheme> = morphs
+                .iter()
+                .map(|(m, tag)| Morpheme {
+                    text: span(text, m, &mut morph_offset),
+                    tag: Tag::from_str_name(tag).unwrap_or(Tag::Na) as i32,
+                    ..Default::default()
+                })
+                .collect();
+            Token {
+                text: token_text,
+                tagged: morphs
+                    .iter()
+                    .map(|(m, tag)| format!("{}/{}", m, tag))
+                    .collect::<Vec<_>>()
+                    .join("+"),
+                lemma: eojeol.to_string(),
+                morphemes,
+                ..Default::default()
+            }
+        })
+        .collect();
+    AnalyzeSyntaxResponse {
+        tokens_count: tokens.len() as i32,
+        sentences: vec![Sentence {
+            text: Some(TextSpan {
+                content: text.to_string(),
+                begin_offset: 0,
+                length: text.chars().count() as i32,
+            }),
+            tokens,
+            ..Default::default()
+        }],
+        language: "ko_KR".to_string(),
+    }
+}
+
+/// 토크나이즈 응답을 만든다.
+///
+/// Args:
+///     text: 분절한 문장
+///     tokens: 어절마다 (어절, [(분절, 힌트)]). 힌트는 `N`, `V`, `J`처럼 쓴다.
+pub fn tokenize_response(text: &str, tokens: &[(&str, &[(&str, &str)])]) -> TokenizeResponse {
+    let mut offset = 0;
+    let tokens: Vec<SegmentToken> = tokens
+        .iter()
+        .map(|(eojeol, segs)| {
+            let token_text = span(text, eojeol, &mut offset);
+            let mut seg_offset = token_text.as_ref().map_or(0, |t| t.begin_offset as usize);
+            SegmentToken {
+                text: token_text,
+                segments: segs
+                    .iter()
+                    .map(|(s, hint)| Segment {
+                        text: span(text, s, &mut seg_offset),
+                        hint: hint.to_string(),
+                    })
+                    .collect(),
+                tagged: segs
+                    .iter()
+                    .map(|(s, hint)| format!("{}/{}", s, hint))
+                    .collect::<Vec<_>>()
+                    .join("+"),
+            }
+        })
+        .collect();
+    TokenizeResponse {
+        tokens_count: tokens.len() as i32,
+        sentences: vec![SegmentSentence {
+            text: Some(TextSpan {
+                content: text.to_string(),
+                begin_offset: 0,
+                length: text.chars().count() as i32,
+            }),
+            tokens,
+        }],
+        language: "ko_KR".to_string(),
+    }
+}
+
+/// 등록되지 않은 문장은 줄마다 한 문장, 어절마다 형태소 하나(`NNG`)로 분석한다.
+fn fallback_analysis(content: &str) -> AnalyzeSyntaxResponse {
+    let mut res = AnalyzeSyntaxResponse {
+        language: "ko_KR".to_string(),
+        ..Default::default()
+    };
+    for line in content.lines().filter(|l| !l.trim().is_empty()) {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let tokens: Vec<(&str, Vec<(&str, &str)>)> =
+            words.iter().map(|w| (*w, vec![(*w, "NNG")])).collect();
+        let tokens: Vec<(&str, &[(&str, &str)])> =
+            tokens.iter().map(|(w, m)| (*w, m.as_slice())).collect();
+        let mut sentence = analyze_syntax_response(line, &tokens);
+        res.tokens_count += sentence.tokens_count;
+        res.sentences.append(&mut sentence.sentences);
+    }
+    res
+}
+
+fn fallback_tokenize(content: &str) -> TokenizeResponse {
+    let mut res = TokenizeResponse {
+        language: "ko_KR".to_string(),
+        ..Default::default()
+    };
+    for line in content.lines().filter(|l| !l.trim().is_empty()) {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let tokens: Vec<(&str, Vec<(&str, &str)>)> =
+            words.iter().map(|w| (*w, vec![(*w, "N")])).collect();
+        let tokens: Vec<(&str, &[(&str, &str)])> =
+            tokens.iter().map(|(w, m)| (*w, m.as_slice())).collect();
+        let mut sentence = tokenize_response(line, &tokens);
+        res.tokens_count += sentence.tokens_count;
+        res.sentences.append(&mut sentence.sentences);
+    }
+    res
+}
+
+/// 등록되지 않은 문장은 고칠 곳이 없는 것으로 본다.
+fn fallback_correction(content: &str) -> CorrectErrorResponse {
+    CorrectErrorResponse {
+        origin: content.to_string(),
+        revised: content.to_string(),
+        language: "ko_KR".to_string(),
+        tokens_count: content.split_whitespace().count() as i32,
+        ..Default::default()
+    }
+}
+
+struct MockLanguageService(Shared);
+
+impl MockLanguageService {
+    fn analyze(&self, content: &str) -> AnalyzeSyntaxResponse {
+        self.0
+            .lock()
+            .analyze
+            .get(content)
+            .cloned()
+            .unwrap_or_else(|| fallback_analysis(content))
+    }
+}
+
+#[tonic::async_trait]
+impl LanguageService for MockLanguageService {
+    async fn analyze_syntax(
+        &self,
+        request: Request<AnalyzeSyntaxRequest>,
+    ) -> Result<Response<AnalyzeSyntaxResponse>, Status> {
+        let (metadata, _, msg) = request.into_parts();
+        let content = msg.document.clone().unwrap_or_default().content;
+        self.0
+            .accept(
+                MockMethod::AnalyzeSyntax,
+                &metadata,
+                MockMessage::AnalyzeSyntax(msg),
+            )
+            .await?;
+        Ok(Response::new(self.analyze(&content)))
+    }
+
+    async fn analyze_syntax_list(
+        &self,
+        request: Request<AnalyzeSyntaxListRequest>,
+    ) -> Result<Response<AnalyzeSyntaxListResponse>, Status> {
+        let (metadata, _, msg) = request.into_parts();
+        let sentences = msg.sentences.clone();
+        self.0
+            .accept(
+                MockMethod::AnalyzeSyntaxList,
+                &metadata,
+                MockMessage::AnalyzeSyntaxList(msg),
+            )
+            .await?;
+
+        let mut res = AnalyzeSyntaxListResponse {
+            language: "ko_KR".to_string(),
+            ..Default::default()
+        };
+        for sentence in sentences {
+            let mut analyzed = self.analyze(&sentence);
+            res.tokens_count += analyzed.tokens_count;
+            res.sentences.append(&mut analyzed.sentences);
+        }
+        Ok(Response::new(res))
+    }
+
+    async fn tokenize(
+        &self,
+        request: Request<TokenizeRequest>,
+    ) -> Result<Response<TokenizeResponse>, Status> {
+        let (metadata, _, msg) = request.into_parts();
+        let content = msg.document.clone().unwrap_or_default().content;
+        self.0
+            .accept(MockMethod::Tokenize, &metadata, MockMessage::Tokenize(msg))
+            .await?;
+        let res = self
+            .0
+            .lock()
+            .tokenize
+            .get(&content)
+            .cloned()
+            .unwrap_or_else(|| fallback_tokenize(&content));
+        Ok(Response::new(res))
+    }
+}
+
+struct MockRevisionService(Shared);
+
+impl MockRevisionService {
+    fn correct(&self, content: &str) -> CorrectErrorResponse {
+        self.0
+            .lock()
+            .correct
+            .get(content)
+            .cloned()
+            .unwrap_or_else(|| fallback_correction(content))
+    }
+}
+
+type CorrectErrorStream =
+    Pin<Box<dyn Stream<Item = Result<StreamCorrectErrorResponse, Status>> + Send>>;
+
+#[tonic::async_trait]
+impl RevisionService for MockRevisionService {
+    async fn correct_error(
+        &self,
+        request: Request<CorrectErrorRequest>,
+    ) -> Result<Response<CorrectErrorResponse>, Status> {
+        let (metadata, _, msg) = request.into_parts();
+        let content = msg.document.clone().unwrap_or_default().content;
+        self.0
+            .accept(
+                MockMethod::CorrectError,
+                &metadata,
+                MockMessage::CorrectError(msg),
+            )
+            .await?;
+        Ok(Response::new(self.correct(&content)))
+    }
+
+    type StreamCorrectErrorStream = CorrectErrorStream;
+
+    async fn stream_correct_error(
+        &self,
+        request: Request<StreamCorrectErrorRequest>,
+    ) -> Result<Response<Self::StreamCorrectErrorStream>, Status> {
+        let (metadata, _, msg) = request.into_parts();
+        let content = msg.document.clone().unwrap_or_default().content;
+        let req_id = msg.req_id;
+        self.0
+            .accept(
+                MockMethod::StreamCorrectError,
+                &metadata,
+                MockMessage::StreamCorrectError(msg),
+            )
+            .await?;
+
+        let res = self.correct(&content);
+        let first = StreamFirstCorrectError {
+            origin: res.origin,
+            revised: res.revised,
+            revised_blocks: res.revised_blocks,
+            whitespace_cleanup_ranges: res.whitespace_cleanup_ranges,
+            revised_sentences: res.revised_sentences,
+            helps: res.helps,
+            language: res.language,
+            tokens_count: res.tokens_count,
+            req_id,
+            thinking_ids: vec![],
+        };
+        let stream = tokio_stream::iter(vec![Ok(StreamCorrectErrorResponse {
+            res: Some(Res::First(first)),
+        })]);
+        Ok(Response::new(Box::pin(stream)))
+    }
+}
+
+struct MockCustomDictionaryService(Shared);
+
+fn dict_meta(set: &Option<DictSet>) -> Option<DictMeta> {
+    set.as_ref().map(|s| DictMeta {
+        r#type: s.r#type,
+        name: s.name.clone(),
+        items_count: s.items.len() as i32,
+    })
+}
+
+#[tonic::async_trait]
+impl CustomDictionaryService for MockCustomDictionaryService {
+    async fn get_custom_dictionary_list(
+        &self,
+        request: Request<()>,
+    ) -> Result<Response<GetCustomDictionaryListResponse>, Status> {
+        self.0
+            .accept(
+                MockMethod::GetCustomDictionaryList,
+                request.metadata(),
+                MockMessage::GetCustomDictionaryList,
+            )
+            .await?;
+        let domain_dicts = self
+            .0
+            .lock()
+            .dicts
+            .values()
+            .map(|d| CustomDictionaryMeta {
+                domain_name: d.domain_name.clone(),
+                np_set: dict_meta(&d.np_set),
+                cp_set: dict_meta(&d.cp_set),
+                cp_caret_set: dict_meta(&d.cp_caret_set),
+                vv_set: dict_meta(&d.vv_set),
+                va_set: dict_meta(&d.va_set),
+            })
+            .collect();
+        Ok(Response::new(GetCustomDictionaryListResponse {
+            domain_dicts,
+        }))
+    }
+
+    async fn get_custom_dictionary(
+        &self,
+        request: Request<GetCustomDictionaryRequest>,
+    ) -> Result<Response<GetCustomDictionaryResponse>, Status> {
+        let (metadata, _, msg) = request.into_parts();
+        let domain_name = msg.domain_name.clone();
+        self.0
+            .accept(
+                MockMethod::GetCustomDictionary,
+                &metadata,
+                MockMessage::GetCustomDictionary(msg),
+            )
+            .await?;
+        let dict = self.0.lock().dicts.get(&domain_name).cloned();
+        Ok(Response::new(GetCustomDictionaryResponse {
+            domain_name,
+            dict,
+        }))
+    }
+
+    async fn update_custom_dictionary(
+        &self,
+        request: Request<UpdateCustomDictionaryRequest>,
+    ) -> Result<Response<UpdateCustomDictionaryResponse>, Status> {
+        let (metadata, _, msg) = request.into_parts();
+        let domain_name = msg.domain_name.clone();
+        let dict = msg.dict.clone();
+        self.0
+            .accept(
+                MockMethod::UpdateCustomDictionary,
+                &metadata,
+                MockMessage::UpdateCustomDictionary(msg),
+            )
+            .await?;
+        if domain_name.is_empty() {
+            return Err(Status::invalid_argument("domain_name is empty"));
+        }
+        let mut dict = dict.unwrap_or_default();
+        dict.domain_name = domain_name.clone();
+        self.0.lock().dicts.insert(domain_name.clone(), dict);
+        Ok(Response::new(UpdateCustomDictionaryResponse {
+            updated_domain_name: domain_name,
+        }))
+    }
+
+    async fn remove_custom_dictionaries(
+        &self,
+        request: Request<RemoveCustomDictionariesRequest>,
+    ) -> Result<Response<RemoveCustomDictionariesResponse>, Status> {
+        let (metadata, _, msg) = request.into_parts();
+        let (domain_names, all) = (msg.domain_names.clone(), msg.all);
+        self.0
+            .accept(
+                MockMethod::RemoveCustomDictionaries,
+                &metadata,
+                MockMessage::RemoveCustomDictionaries(msg),
+            )
+            .await?;
+
+        let mut state = self.0.lock();
+        let deleted_domain_names = if all {
+            std::mem::take(&mut state.dicts)
+                .into_keys()
+                .map(|k| (k, true))
+                .collect()
+        } else {
+            domain_names
+                .into_iter()
+                .map(|k| {
+                    let removed = state.dicts.remove(&k).is_some();
+                    (k, removed)
+                })
+                .collect()
+        };
+        Ok(Response::new(RemoveCustomDictionariesResponse {
+            deleted_domain_names,
+        }))
+    }
+
+    async fn check_conflict(
+        &self,
+        request: Request<CheckConflictRequest>,
+    ) -> Result<Response<CheckConflictResponse>, Status> {
+        let (metadata, _, msg) = request.into_parts();
+        self.0
+            .accept(
+                MockMethod::CheckConflict,
+                &metadata,
+                MockMessage::CheckConflict(msg),
+            )
+            .await?;
+        Ok(Response::new(CheckConflictResponse::default()))
+    }
+}
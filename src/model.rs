@@ -0,0 +1,882 @@
+//! 생성된 protobuf 타입과 분리된 분석 결과 모델
+//!
+//! `bareun` 모듈의 타입은 proto 정의가 바뀌면 함께 바뀐다. 이 모듈의 타입은 직접 작성한 것이라
+//! proto가 바뀌어도 모양이 유지된다. 분석 결과를 저장하거나 다른 서비스로 넘길 때 쓴다.
+//! `Tagged::document`로 만든다.
+//!
+//! # JSON 형식
+//!
+//! 모든 타입은 serde로 직렬화된다. 형식은 저장소의 `schema/document.schema.json`(JSON Schema)에
+//! 정리되어 있다. 필드를 빼거나 의미를 바꾸지 않고, 새 필드는 생략할 수 있는 형태로만 더한다.
+//!
+//! ```json
+//! {
+//!   "text": "나비가",
+//!   "language": "ko_KR",
+//!   "encoding": "utf32",
+//!   "sentences": [{
+//!     "text": "나비가",
+//!     "span": { "begin": 0, "length": 3 },
+//!     "eojeols": [{
+//!       "text": "나비가",
+//!       "span": { "begin": 0, "length": 3 },
+//!       "tagged": "나비/NNG+가/JKS",
+//!       "morphemes": [
+//!         { "text": "나비", "span": { "begin": 0, "length": 2 }, "tag": "NNG",
+//!           "probability": 0.98, "oov": "in_word_embedding" },
+//!         { "text": "가", "span": { "begin": 2, "length": 1 }, "tag": "JKS",
+//!           "probability": 0.99, "oov": "in_word_embedding" }
+//!       ]
+//!     }]
+//!   }]
+//! }
+//! ```
+//!
+//! `span`의 위치와 길이는 서버가 돌려준 값 그대로이고, 단위는 `encoding`에 적혀 있다.
+//! 기본값(`utf32`)에서는 문자 단위다.
+
+mod conllu;
+
+pub use conllu::Upos;
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::bareun;
+use crate::bareun::EncodingType;
+use crate::bareun::morpheme::{OutOfVocab, Tag as ProtoTag};
+use crate::error::{BareunError, Result};
+
+/// 분석한 문서 전체
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Document {
+    /// 분석을 요청한 원문
+    pub text: String,
+    /// 서버가 알려준 언어. 예: `ko_KR`
+    #[serde(default)]
+    pub language: String,
+    /// `span`이 쓰는 단위
+    #[serde(default)]
+    pub encoding: Encoding,
+    pub sentences: Vec<Sentence>,
+}
+
+/// 문장
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Sentence {
+    pub text: String,
+    pub span: Span,
+    /// 서버가 고쳐 쓴 문장. 고친 것이 없으면 없다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refined: Option<String>,
+    pub eojeols: Vec<Eojeol>,
+}
+
+/// 어절. 띄어쓰기로 나뉜 단위다.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Eojeol {
+    pub text: String,
+    pub span: Span,
+    /// 원형. 서버가 주지 않았으면 없다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lemma: Option<String>,
+    /// `형태소/태그+형태소/태그` 형식의 문자열
+    #[serde(default)]
+    pub tagged: String,
+    /// 서버가 고쳐 쓴 어절. 고친 것이 없으면 없다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub modified: Option<String>,
+    pub morphemes: Vec<Morpheme>,
+}
+
+/// 형태소
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Morpheme {
+    pub text: String,
+    pub span: Span,
+    pub tag: Tag,
+    /// 분석 결과의 확률. 서버가 주지 않으면 0이다.
+    #[serde(default)]
+    pub probability: f32,
+    /// 형태소를 어디에서 찾았는지
+    #[serde(default)]
+    pub oov: OovKind,
+    /// 형태소를 찾은 사용자 사전의 이름
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_dict: Option<String>,
+}
+
+/// 원문 안의 위치
+///
+/// 위치를 요청하지 않아(`Encoding::None`) 서버가 -1을 돌려주면 빈 범위(`Span::default()`)다.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Span {
+    pub begin: usize,
+    pub length: usize,
+}
+
+impl Span {
+    pub fn new(begin: usize, length: usize) -> Self {
+        Span { begin, length }
+    }
+
+    /// 서버가 돌려준 위치와 길이. 둘 중 하나라도 음수면 빈 범위다.
+    pub(crate) fn from_offsets(begin: i32, length: i32) -> Self {
+        match (usize::try_from(begin), usize::try_from(length)) {
+            (Ok(begin), Ok(length)) => Span::new(begin, length),
+            _ => Span::default(),
+        }
+    }
+
+    /// 끝 위치(포함하지 않음)
+    pub fn end(&self) -> usize {
+        self.begin + self.length
+    }
+}
+
+/// `Span`의 위치와 길이가 쓰는 단위
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Encoding {
+    /// 위치를 요청하지 않았다. 서버는 위치를 -1로 돌려준다.
+    None,
+    /// UTF-8 바이트
+    Utf8,
+    /// UTF-16 단위
+    Utf16,
+    /// 코드 포인트(문자)
+    #[default]
+    Utf32,
+}
+
+impl From<EncodingType> for Encoding {
+    fn from(encoding: EncodingType) -> Self {
+        match encoding {
+            EncodingType::None => Encoding::None,
+            EncodingType::Utf8 => Encoding::Utf8,
+            EncodingType::Utf16 => Encoding::Utf16,
+            EncodingType::Utf32 => Encoding::Utf32,
+        }
+    }
+}
+
+impl From<Encoding> for EncodingType {
+    fn from(encoding: Encoding) -> Self {
+        match encoding {
+            Encoding::None => EncodingType::None,
+            Encoding::Utf8 => EncodingType::Utf8,
+            Encoding::Utf16 => EncodingType::Utf16,
+            Encoding::Utf32 => EncodingType::Utf32,
+        }
+    }
+}
+
+/// 형태소를 찾은 곳
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OovKind {
+    /// 워드 임베딩에 포함된 단어
+    #[default]
+    InWordEmbedding,
+    /// 자동 추측한 미등록 단어
+    OutOfVocab,
+    /// 사용자 사전에 있는 단어
+    InCustomDict,
+    /// 기본 사전에 있는 단어
+    InBuiltinDict,
+    /// 우리말샘에 있는 단어
+    InUrimalsaem,
+    /// 위키 백과 사전에 있는 단어
+    InWikiDict,
+    /// 온 용어 사전에 있는 단어
+    InOnDict,
+}
+
+impl From<OutOfVocab> for OovKind {
+    fn from(v: OutOfVocab) -> Self {
+        match v {
+            OutOfVocab::InWordEmbedding => OovKind::InWordEmbedding,
+            OutOfVocab::OutOfVocab => OovKind::OutOfVocab,
+            OutOfVocab::InCustomDict => OovKind::InCustomDict,
+            OutOfVocab::InBuiltinDict => OovKind::InBuiltinDict,
+            OutOfVocab::InUrimalsaem => OovKind::InUrimalsaem,
+            OutOfVocab::InWikiDict => OovKind::InWikiDict,
+            OutOfVocab::InOnDict => OovKind::InOnDict,
+        }
+    }
+}
+
+/// 세종 품사 태그
+///
+/// JSON에는 `"NNG"`처럼 태그 이름으로 쓴다.
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Tag {
+    /// 알 수 없음
+    #[default]
+    Unk,
+    /// 일반 명사
+    Nng,
+    /// 고유 명사
+    Nnp,
+    /// 의존 명사
+    Nnb,
+    /// 대명사
+    Np,
+    /// 수사
+    Nr,
+    /// 명사 추정 범주
+    Nf,
+    /// 분석불능범주
+    Na,
+    /// 용언 추정 범주
+    Nv,
+    /// 동사
+    Vv,
+    /// 형용사
+    Va,
+    /// 보조 용언
+    Vx,
+    /// 긍정 지정사
+    Vcp,
+    /// 부정 지정사
+    Vcn,
+    /// 성상 관형사
+    Mma,
+    /// 지시 관형사
+    Mmd,
+    /// 수 관형사
+    Mmn,
+    /// 일반 부사
+    Mag,
+    /// 접속 부사
+    Maj,
+    /// 감탄사
+    Ic,
+    /// 주격 조사
+    Jks,
+    /// 보격 조사
+    Jkc,
+    /// 관형격 조사
+    Jkg,
+    /// 목적격 조사
+    Jko,
+    /// 부사격 조사
+    Jkb,
+    /// 호격 조사
+    Jkv,
+    /// 인용격 조사
+    Jkq,
+    /// 보조사
+    Jx,
+    /// 접속 조사
+    Jc,
+    /// 선어말 어미
+    Ep,
+    /// 종결 어미
+    Ef,
+    /// 연결 어미
+    Ec,
+    /// 명사형 전성 어미
+    Etn,
+    /// 관형형 전성 어미
+    Etm,
+    /// 체언 접두사
+    Xpn,
+    /// 명사 파생 접미사
+    Xsn,
+    /// 동사 파생 접미사
+    Xsv,
+    /// 형용사 파생 접미사
+    Xsa,
+    /// 어근
+    Xr,
+    /// 마침표, 물음표, 느낌표
+    Sf,
+    /// 쉼표, 가운뎃점, 콜론, 빗금
+    Sp,
+    /// 따옴표, 괄호표, 줄표
+    Ss,
+    /// 줄임표
+    Se,
+    /// 붙임표(물결, 숨김, 빠짐)
+    So,
+    /// 기타 기호(논리수학기호, 화폐기호)
+    Sw,
+    /// 외국어
+    Sl,
+    /// 한자
+    Sh,
+    /// 숫자
+    Sn,
+}
+
+impl Tag {
+    /// 모든 태그
+    pub const ALL: [Tag; 48] = [
+        Tag::Unk,
+        Tag::Nng,
+        Tag::Nnp,
+        Tag::Nnb,
+        Tag::Np,
+        Tag::Nr,
+        Tag::Nf,
+        Tag::Na,
+        Tag::Nv,
+        Tag::Vv,
+        Tag::Va,
+        Tag::Vx,
+        Tag::Vcp,
+        Tag::Vcn,
+        Tag::Mma,
+        Tag::Mmd,
+        Tag::Mmn,
+        Tag::Mag,
+        Tag::Maj,
+        Tag::Ic,
+        Tag::Jks,
+        Tag::Jkc,
+        Tag::Jkg,
+        Tag::Jko,
+        Tag::Jkb,
+        Tag::Jkv,
+        Tag::Jkq,
+        Tag::Jx,
+        Tag::Jc,
+        Tag::Ep,
+        Tag::Ef,
+        Tag::Ec,
+        Tag::Etn,
+        Tag::Etm,
+        Tag::Xpn,
+        Tag::Xsn,
+        Tag::Xsv,
+        Tag::Xsa,
+        Tag::Xr,
+        Tag::Sf,
+        Tag::Sp,
+        Tag::Ss,
+        Tag::Se,
+        Tag::So,
+        Tag::Sw,
+        Tag::Sl,
+        Tag::Sh,
+        Tag::Sn,
+    ];
+
+    /// `NNG` 형식의 태그 이름
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Tag::Unk => "UNK",
+            Tag::Nng => "NNG",
+            Tag::Nnp => "NNP",
+            Tag::Nnb => "NNB",
+            Tag::Np => "NP",
+            Tag::Nr => "NR",
+            Tag::Nf => "NF",
+            Tag::Na => "NA",
+            Tag::Nv => "NV",
+            Tag::Vv => "VV",
+            Tag::Va => "VA",
+            Tag::Vx => "VX",
+            Tag::Vcp => "VCP",
+            Tag::Vcn => "VCN",
+            Tag::Mma => "MMA",
+            Tag::Mmd => "MMD",
+            Tag::Mmn => "MMN",
+            Tag::Mag => "MAG",
+            Tag::Maj => "MAJ",
+            Tag::Ic => "IC",
+            Tag::Jks => "JKS",
+            Tag::Jkc => "JKC",
+            Tag::Jkg => "JKG",
+            Tag::Jko => "JKO",
+            Tag::Jkb => "JKB",
+            Tag::Jkv => "JKV",
+            Tag::Jkq => "JKQ",
+            Tag::Jx => "JX",
+            Tag::Jc => "JC",
+            Tag::Ep => "EP",
+            Tag::Ef => "EF",
+            Tag::Ec => "EC",
+            Tag::Etn => "ETN",
+            Tag::Etm => "ETM",
+            Tag::Xpn => "XPN",
+            Tag::Xsn => "XSN",
+            Tag::Xsv => "XSV",
+            Tag::Xsa => "XSA",
+            Tag::Xr => "XR",
+            Tag::Sf => "SF",
+            Tag::Sp => "SP",
+            Tag::Ss => "SS",
+            Tag::Se => "SE",
+            Tag::So => "SO",
+            Tag::Sw => "SW",
+            Tag::Sl => "SL",
+            Tag::Sh => "SH",
+            Tag::Sn => "SN",
+        }
+    }
+
+    /// 태그의 뜻. 예: `일반 명사`
+    pub fn description(&self) -> &'static str {
+        match self {
+            Tag::Unk => "알 수 없음",
+            Tag::Nng => "일반 명사",
+            Tag::Nnp => "고유 명사",
+            Tag::Nnb => "의존 명사",
+            Tag::Np => "대명사",
+            Tag::Nr => "수사",
+            Tag::Nf => "명사 추정 범주",
+            Tag::Na => "분석불능범주",
+            Tag::Nv => "용언 추정 범주",
+            Tag::Vv => "동사",
+            Tag::Va => "형용사",
+            Tag::Vx => "보조 용언",
+            Tag::Vcp => "긍정 지정사",
+            Tag::Vcn => "부정 지정사",
+            Tag::Mma => "성상 관형사",
+            Tag::Mmd => "지시 관형사",
+            Tag::Mmn => "수 관형사",
+            Tag::Mag => "일반 부사",
+            Tag::Maj => "접속 부사",
+            Tag::Ic => "감탄사",
+            Tag::Jks => "주격 조사",
+            Tag::Jkc => "보격 조사",
+            Tag::Jkg => "관형격 조사",
+            Tag::Jko => "목적격 조사",
+            Tag::Jkb => "부사격 조사",
+            Tag::Jkv => "호격 조사",
+            Tag::Jkq => "인용격 조사",
+            Tag::Jx => "보조사",
+            Tag::Jc => "접속 조사",
+            Tag::Ep => "선어말 어미",
+            Tag::Ef => "종결 어미",
+            Tag::Ec => "연결 어미",
+            Tag::Etn => "명사형 전성 어미",
+            Tag::Etm => "관형형 전성 어미",
+            Tag::Xpn => "체언 접두사",
+            Tag::Xsn => "명사 파생 접미사",
+            Tag::Xsv => "동사 파생 접미사",
+            Tag::Xsa => "형용사 파생 접미사",
+            Tag::Xr => "어근",
+            Tag::Sf => "마침표, 물음표, 느낌표",
+            Tag::Sp => "쉼표, 가운뎃점, 콜론, 빗금",
+            Tag::Ss => "따옴표, 괄호표, 줄표",
+            Tag::Se => "줄임표",
+            Tag::So => "붙임표(물결, 숨김, 빠짐)",
+            Tag::Sw => "기타 기호(논리수학기호, 화폐기호)",
+            Tag::Sl => "외국어",
+            Tag::Sh => "한자",
+            Tag::Sn => "숫자",
+        }
+    }
+
+    /// proto의 태그 값을 바꾼다. 알 수 없는 값은 `Tag::Unk`가 된다.
+    pub fn from_proto(value: i32) -> Self {
+        ProtoTag::try_from(value).map(Tag::from).unwrap_or(Tag::Unk)
+    }
+}
+
+/// 품사 태그의 큰 분류
+///
+/// 세종 품사 체계의 분류를 따른다. `hint`는 `Tokenized`의 `Segment.hint`와 같은 글자다.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TagCategory {
+    /// 체언: NNG, NNP, NNB, NP, NR
+    Substantive,
+    /// 용언: VV, VA, VX, VCP, VCN
+    Predicate,
+    /// 관형사: MMA, MMD, MMN
+    Prenoun,
+    /// 부사: MAG, MAJ
+    Adverb,
+    /// 감탄사: IC
+    Interjection,
+    /// 조사: JKS, JKC, JKG, JKO, JKB, JKV, JKQ, JX, JC
+    Particle,
+    /// 어미: EP, EF, EC, ETN, ETM
+    Ending,
+    /// 접사: XPN, XSN, XSV, XSA
+    Affix,
+    /// 어근: XR
+    Root,
+    /// 기호, 외국어, 한자, 숫자: SF, SP, SS, SE, SO, SW, SL, SH, SN
+    Symbol,
+    /// 분석할 수 없거나 추정한 것: NF, NV, NA, UNK
+    Unknown,
+}
+
+impl TagCategory {
+    /// `Segment.hint`에 쓰이는 글자. 예: 체언은 `'N'`
+    pub fn hint(&self) -> char {
+        match self {
+            TagCategory::Substantive => 'N',
+            TagCategory::Predicate => 'V',
+            TagCategory::Prenoun => 'M',
+            TagCategory::Adverb => 'A',
+            TagCategory::Interjection => 'I',
+            TagCategory::Particle => 'J',
+            TagCategory::Ending => 'E',
+            TagCategory::Affix => 'X',
+            TagCategory::Root => 'R',
+            TagCategory::Symbol => 'S',
+            TagCategory::Unknown => 'U',
+        }
+    }
+
+    /// 이 분류에 속한 태그
+    pub fn tags(self) -> impl Iterator<Item = Tag> {
+        Tag::ALL
+            .into_iter()
+            .filter(move |tag| tag.category() == self)
+    }
+}
+
+impl Tag {
+    /// 태그의 큰 분류
+    pub fn category(&self) -> TagCategory {
+        match self {
+            Tag::Nng | Tag::Nnp | Tag::Nnb | Tag::Np | Tag::Nr => TagCategory::Substantive,
+            Tag::Vv | Tag::Va | Tag::Vx | Tag::Vcp | Tag::Vcn => TagCategory::Predicate,
+            Tag::Mma | Tag::Mmd | Tag::Mmn => TagCategory::Prenoun,
+            Tag::Mag | Tag::Maj => TagCategory::Adverb,
+            Tag::Ic => TagCategory::Interjection,
+            Tag::Jks
+            | Tag::Jkc
+            | Tag::Jkg
+            | Tag::Jko
+            | Tag::Jkb
+            | Tag::Jkv
+            | Tag::Jkq
+            | Tag::Jx
+            | Tag::Jc => TagCategory::Particle,
+            Tag::Ep | Tag::Ef | Tag::Ec | Tag::Etn | Tag::Etm => TagCategory::Ending,
+            Tag::Xpn | Tag::Xsn | Tag::Xsv | Tag::Xsa => TagCategory::Affix,
+            Tag::Xr => TagCategory::Root,
+            Tag::Sf
+            | Tag::Sp
+            | Tag::Ss
+            | Tag::Se
+            | Tag::So
+            | Tag::Sw
+            | Tag::Sl
+            | Tag::Sh
+            | Tag::Sn => TagCategory::Symbol,
+            Tag::Nf | Tag::Nv | Tag::Na | Tag::Unk => TagCategory::Unknown,
+        }
+    }
+
+    /// 체언(명사, 대명사, 수사)
+    pub fn is_substantive(&self) -> bool {
+        self.category() == TagCategory::Substantive
+    }
+
+    /// 명사(일반, 고유, 의존)와 대명사. `Tagged::nouns`가 고르는 태그다.
+    pub fn is_noun(&self) -> bool {
+        matches!(self, Tag::Nng | Tag::Nnp | Tag::Nnb | Tag::Np)
+    }
+
+    /// 용언(동사, 형용사, 보조 용언, 지정사)
+    pub fn is_predicate(&self) -> bool {
+        self.category() == TagCategory::Predicate
+    }
+
+    /// 관형사와 부사
+    pub fn is_modifier(&self) -> bool {
+        matches!(self.category(), TagCategory::Prenoun | TagCategory::Adverb)
+    }
+
+    /// 조사
+    pub fn is_particle(&self) -> bool {
+        self.category() == TagCategory::Particle
+    }
+
+    /// 어미
+    pub fn is_ending(&self) -> bool {
+        self.category() == TagCategory::Ending
+    }
+
+    /// 접두사와 접미사
+    pub fn is_affix(&self) -> bool {
+        self.category() == TagCategory::Affix
+    }
+
+    /// 기호, 외국어, 한자, 숫자
+    pub fn is_symbol(&self) -> bool {
+        self.category() == TagCategory::Symbol
+    }
+
+    /// 실질적인 뜻을 가진 형태소(체언, 용언, 수식언, 독립언, 어근)
+    pub fn is_content(&self) -> bool {
+        matches!(
+            self.category(),
+            TagCategory::Substantive
+                | TagCategory::Predicate
+                | TagCategory::Prenoun
+                | TagCategory::Adverb
+                | TagCategory::Interjection
+                | TagCategory::Root
+        )
+    }
+}
+
+impl From<ProtoTag> for Tag {
+    fn from(tag: ProtoTag) -> Self {
+        match tag {
+            ProtoTag::Unk => Tag::Unk,
+            ProtoTag::Nng => Tag::Nng,
+            ProtoTag::Nnp => Tag::Nnp,
+            ProtoTag::Nnb => Tag::Nnb,
+            ProtoTag::Np => Tag::Np,
+            ProtoTag::Nr => Tag::Nr,
+            ProtoTag::Nf => Tag::Nf,
+            ProtoTag::Na => Tag::Na,
+            ProtoTag::Nv => Tag::Nv,
+            ProtoTag::Vv => Tag::Vv,
+            ProtoTag::Va => Tag::Va,
+            ProtoTag::Vx => Tag::Vx,
+            ProtoTag::Vcp => Tag::Vcp,
+            ProtoTag::Vcn => Tag::Vcn,
+            ProtoTag::Mma => Tag::Mma,
+            ProtoTag::Mmd => Tag::Mmd,
+            ProtoTag::Mmn => Tag::Mmn,
+            ProtoTag::Mag => Tag::Mag,
+            ProtoTag::Maj => Tag::Maj,
+            ProtoTag::Ic => Tag::Ic,
+            ProtoTag::Jks => Tag::Jks,
+            ProtoTag::Jkc => Tag::Jkc,
+            ProtoTag::Jkg => Tag::Jkg,
+            ProtoTag::Jko => Tag::Jko,
+            ProtoTag::Jkb => Tag::Jkb,
+            ProtoTag::Jkv => Tag::Jkv,
+            ProtoTag::Jkq => Tag::Jkq,
+            ProtoTag::Jx => Tag::Jx,
+            ProtoTag::Jc => Tag::Jc,
+            ProtoTag::Ep => Tag::Ep,
+            ProtoTag::Ef => Tag::Ef,
+            ProtoTag::Ec => Tag::Ec,
+            ProtoTag::Etn => Tag::Etn,
+            ProtoTag::Etm => Tag::Etm,
+            ProtoTag::Xpn => Tag::Xpn,
+            ProtoTag::Xsn => Tag::Xsn,
+            ProtoTag::Xsv => Tag::Xsv,
+            ProtoTag::Xsa => Tag::Xsa,
+            ProtoTag::Xr => Tag::Xr,
+            ProtoTag::Sf => Tag::Sf,
+            ProtoTag::Sp => Tag::Sp,
+            ProtoTag::Ss => Tag::Ss,
+            ProtoTag::Se => Tag::Se,
+            ProtoTag::So => Tag::So,
+            ProtoTag::Sw => Tag::Sw,
+            ProtoTag::Sl => Tag::Sl,
+            ProtoTag::Sh => Tag::Sh,
+            ProtoTag::Sn => Tag::Sn,
+        }
+    }
+}
+
+impl From<Tag> for ProtoTag {
+    fn from(tag: Tag) -> Self {
+        match tag {
+            Tag::Unk => ProtoTag::Unk,
+            Tag::Nng => ProtoTag::Nng,
+            Tag::Nnp => ProtoTag::Nnp,
+            Tag::Nnb => ProtoTag::Nnb,
+            Tag::Np => ProtoTag::Np,
+            Tag::Nr => ProtoTag::Nr,
+            Tag::Nf => ProtoTag::Nf,
+            Tag::Na => ProtoTag::Na,
+            Tag::Nv => ProtoTag::Nv,
+            Tag::Vv => ProtoTag::Vv,
+            Tag::Va => ProtoTag::Va,
+            Tag::Vx => ProtoTag::Vx,
+            Tag::Vcp => ProtoTag::Vcp,
+            Tag::Vcn => ProtoTag::Vcn,
+            Tag::Mma => ProtoTag::Mma,
+            Tag::Mmd => ProtoTag::Mmd,
+            Tag::Mmn => ProtoTag::Mmn,
+            Tag::Mag => ProtoTag::Mag,
+            Tag::Maj => ProtoTag::Maj,
+            Tag::Ic => ProtoTag::Ic,
+            Tag::Jks => ProtoTag::Jks,
+            Tag::Jkc => ProtoTag::Jkc,
+            Tag::Jkg => ProtoTag::Jkg,
+            Tag::Jko => ProtoTag::Jko,
+            Tag::Jkb => ProtoTag::Jkb,
+            Tag::Jkv => ProtoTag::Jkv,
+            Tag::Jkq => ProtoTag::Jkq,
+            Tag::Jx => ProtoTag::Jx,
+            Tag::Jc => ProtoTag::Jc,
+            Tag::Ep => ProtoTag::Ep,
+            Tag::Ef => ProtoTag::Ef,
+            Tag::Ec => ProtoTag::Ec,
+            Tag::Etn => ProtoTag::Etn,
+            Tag::Etm => ProtoTag::Etm,
+            Tag::Xpn => ProtoTag::Xpn,
+            Tag::Xsn => ProtoTag::Xsn,
+            Tag::Xsv => ProtoTag::Xsv,
+            Tag::Xsa => ProtoTag::Xsa,
+            Tag::Xr => ProtoTag::Xr,
+            Tag::Sf => ProtoTag::Sf,
+            Tag::Sp => ProtoTag::Sp,
+            Tag::Ss => ProtoTag::Ss,
+            Tag::Se => ProtoTag::Se,
+            Tag::So => ProtoTag::So,
+            Tag::Sw => ProtoTag::Sw,
+            Tag::Sl => ProtoTag::Sl,
+            Tag::Sh => ProtoTag::Sh,
+            Tag::Sn => ProtoTag::Sn,
+        }
+    }
+}
+
+impl fmt::Display for Tag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Tag {
+    type Err = BareunError;
+
+    /// `NNG` 같은 태그 이름을 읽는다. 대소문자는 가리지 않는다.
+    fn from_str(s: &str) -> Result<Self> {
+        Tag::ALL
+            .iter()
+            .find(|tag| tag.as_str().eq_ignore_ascii_case(s))
+            .copied()
+            .ok_or_else(|| BareunError::invalid_argument(format!("Unknown POS tag: {}", s)))
+    }
+}
+
+fn text_of(span: &Option<bareun::TextSpan>, what: &str) -> Result<(String, Span)> {
+    let span = span
+        .as_ref()
+        .ok_or_else(|| BareunError::MalformedResponse(format!("{} without text", what)))?;
+    Ok((
+        span.content.clone(),
+        Span::from_offsets(span.begin_offset, span.length),
+    ))
+}
+
+fn non_empty(s: &str) -> Option<String> {
+    (!s.is_empty()).then(|| s.to_string())
+}
+
+impl Document {
+    /// 형태소 분석 응답을 모델로 바꾼다. 위치는 UTF-32 단위로 본다.
+    ///
+    /// Args:
+    ///     text (&str): 분석을 요청한 원문
+    ///     res (&AnalyzeSyntaxResponse): 형태소 분석 응답
+    ///
+    /// Returns:
+    ///     Result<Document>: text가 없는 문장, 어절, 형태소가 있으면 `MalformedResponse`
+    pub fn from_response(text: &str, res: &bareun::AnalyzeSyntaxResponse) -> Result<Self> {
+        Ok(Document {
+            text: text.to_string(),
+            language: res.language.clone(),
+            encoding: Encoding::Utf32,
+            sentences: res
+                .sentences
+                .iter()
+                .map(Sentence::try_from)
+                .collect::<Result<_>>()?,
+        })
+    }
+
+    /// 모든 문장의 어절
+    pub fn eojeols(&self) -> impl Iterator<Item = &Eojeol> {
+        self.sentences.iter().flat_map(|s| &s.eojeols)
+    }
+
+    /// 모든 문장의 형태소
+    pub fn morphemes(&self) -> impl Iterator<Item = &Morpheme> {
+        self.eojeols().flat_map(|e| &e.morphemes)
+    }
+}
+
+impl Eojeol {
+    /// 형태소를 이어 표면형을 만든다(`crate::surface_form`).
+    ///
+    /// 형태소를 바꾼 뒤 어절을 다시 쓸 때 쓴다. `text`는 바꾸지 않는다.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// eojeol.morphemes[0].text = "돕".to_string(); // 먹었다 → 도왔다
+    /// eojeol.text = eojeol.surface_form();
+    /// ```
+    pub fn surface_form(&self) -> String {
+        crate::conjugation::surface_form(self.morphemes.iter().map(|m| (m.text.as_str(), m.tag)))
+    }
+}
+
+impl Morpheme {
+    /// 자모로 시작하는 형태소인지(`ㄴ다`, `ㅂ니다`). 앞 음절의 받침으로 붙어 있어 원문과 다르다.
+    pub fn is_jamo_fragment(&self) -> bool {
+        crate::hangul::is_jamo_fragment(&self.text)
+    }
+}
+
+impl TryFrom<&bareun::Sentence> for Sentence {
+    type Error = BareunError;
+
+    fn try_from(s: &bareun::Sentence) -> Result<Self> {
+        let (text, span) = text_of(&s.text, "sentence")?;
+        Ok(Sentence {
+            text,
+            span,
+            refined: non_empty(&s.refined),
+            eojeols: s
+                .tokens
+                .iter()
+                .map(Eojeol::try_from)
+                .collect::<Result<_>>()?,
+        })
+    }
+}
+
+impl TryFrom<&bareun::Token> for Eojeol {
+    type Error = BareunError;
+
+    fn try_from(t: &bareun::Token) -> Result<Self> {
+        let (text, span) = text_of(&t.text, "token")?;
+        Ok(Eojeol {
+            text,
+            span,
+            lemma: non_empty(&t.lemma),
+            tagged: t.tagged.clone(),
+            modified: non_empty(&t.modified),
+            morphemes: t
+                .morphemes
+                .iter()
+                .map(Morpheme::try_from)
+                .collect::<Result<_>>()?,
+        })
+    }
+}
+
+impl TryFrom<&bareun::Morpheme> for Morpheme {
+    type Error = BareunError;
+
+    fn try_from(m: &bareun::Morpheme) -> Result<Self> {
+        let (text, span) = text_of(&m.text, "morpheme")?;
+        Ok(Morpheme {
+            text,
+            span,
+            tag: Tag::from_proto(m.tag),
+            probability: m.probability,
+            oov: OutOfVocab::try_from(m.out_of_vocab)
+                .map(OovKind::from)
+                .unwrap_or_default(),
+            custom_dict: m.custom_dict_name.clone(),
+        })
+    }
+}
@@ -0,0 +1,437 @@
+//! Universal Dependencies 품사(UPOS)와 CoNLL-U 형식
+//!
+//! CoNLL-U는 어절 하나를 한 줄로 쓴다. 형태소는 한국어 UD 말뭉치처럼 LEMMA와 XPOS 열에 `+`로 이어
+//! 쓴다. 열은 탭으로 나눈다. 의존 구문 정보(HEAD, DEPREL)는 분석하지 않으므로 `_`로 둔다.
+//! 형태소에 든 `+`, `\`, `_`는 `\+`, `\\`, `\_`로 쓰고, 빈 형태소는 `_`로 쓴다.
+//!
+//! ```text
+//! # sent_id = 1
+//! # text = 나비가 난다
+//! 1  나비가  나비+가  NOUN  NNG+JKS  _  _  _  _  _
+//! 2  난다  날+ㄴ다  VERB  VV+EF  _  _  _  _  _
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use super::{Document, Encoding, Eojeol, Morpheme, OovKind, Sentence, Span, Tag};
+use crate::error::{BareunError, Result};
+
+/// Universal Dependencies 품사 태그
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Upos {
+    Adj,
+    Adp,
+    Adv,
+    Aux,
+    Cconj,
+    Det,
+    Intj,
+    Noun,
+    Num,
+    Part,
+    Pron,
+    Propn,
+    Punct,
+    Sconj,
+    Sym,
+    Verb,
+    X,
+}
+
+impl Upos {
+    pub const ALL: [Upos; 17] = [
+        Upos::Adj,
+        Upos::Adp,
+        Upos::Adv,
+        Upos::Aux,
+        Upos::Cconj,
+        Upos::Det,
+        Upos::Intj,
+        Upos::Noun,
+        Upos::Num,
+        Upos::Part,
+        Upos::Pron,
+        Upos::Propn,
+        Upos::Punct,
+        Upos::Sconj,
+        Upos::Sym,
+        Upos::Verb,
+        Upos::X,
+    ];
+
+    /// `NOUN` 형식의 태그 이름
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Upos::Adj => "ADJ",
+            Upos::Adp => "ADP",
+            Upos::Adv => "ADV",
+            Upos::Aux => "AUX",
+            Upos::Cconj => "CCONJ",
+            Upos::Det => "DET",
+            Upos::Intj => "INTJ",
+            Upos::Noun => "NOUN",
+            Upos::Num => "NUM",
+            Upos::Part => "PART",
+            Upos::Pron => "PRON",
+            Upos::Propn => "PROPN",
+            Upos::Punct => "PUNCT",
+            Upos::Sconj => "SCONJ",
+            Upos::Sym => "SYM",
+            Upos::Verb => "VERB",
+            Upos::X => "X",
+        }
+    }
+}
+
+impl fmt::Display for Upos {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Upos {
+    type Err = BareunError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Upos::ALL
+            .iter()
+            .find(|upos| upos.as_str().eq_ignore_ascii_case(s))
+            .copied()
+            .ok_or_else(|| BareunError::invalid_argument(format!("Unknown UPOS tag: {}", s)))
+    }
+}
+
+impl Tag {
+    /// 형태소 하나의 UPOS. 어절의 UPOS는 `Eojeol::upos`로 구한다.
+    pub fn upos(&self) -> Upos {
+        match self {
+            Tag::Nng | Tag::Nnb | Tag::Nf => Upos::Noun,
+            Tag::Nnp => Upos::Propn,
+            Tag::Np => Upos::Pron,
+            Tag::Nr | Tag::Sn => Upos::Num,
+            Tag::Vv => Upos::Verb,
+            Tag::Va | Tag::Vcn => Upos::Adj,
+            Tag::Vx | Tag::Vcp => Upos::Aux,
+            Tag::Mma | Tag::Mmd | Tag::Mmn => Upos::Det,
+            Tag::Mag => Upos::Adv,
+            Tag::Maj | Tag::Jc => Upos::Cconj,
+            Tag::Ic => Upos::Intj,
+            Tag::Jks
+            | Tag::Jkc
+            | Tag::Jkg
+            | Tag::Jko
+            | Tag::Jkb
+            | Tag::Jkv
+            | Tag::Jkq
+            | Tag::Jx => Upos::Adp,
+            Tag::Ep | Tag::Ef | Tag::Ec | Tag::Etn | Tag::Etm => Upos::Part,
+            Tag::Xpn | Tag::Xsn | Tag::Xsv | Tag::Xsa => Upos::Part,
+            Tag::Sf | Tag::Sp | Tag::Ss | Tag::Se | Tag::So => Upos::Punct,
+            Tag::Sw => Upos::Sym,
+            Tag::Xr | Tag::Sl | Tag::Sh | Tag::Nv | Tag::Na | Tag::Unk => Upos::X,
+        }
+    }
+}
+
+impl Eojeol {
+    /// 어절의 UPOS
+    ///
+    /// 동사/형용사 파생 접미사(XSV, XSA)가 있으면 VERB, ADJ이고, 그 밖에는 접두사를 건너뛴
+    /// 첫 형태소의 UPOS를 따른다.
+    pub fn upos(&self) -> Upos {
+        let tags = || self.morphemes.iter().map(|m| m.tag);
+        if tags().any(|tag| tag == Tag::Xsv) {
+            return Upos::Verb;
+        }
+        if tags().any(|tag| tag == Tag::Xsa) {
+            return Upos::Adj;
+        }
+        tags()
+            .find(|tag| *tag != Tag::Xpn)
+            .or_else(|| tags().next())
+            .map_or(Upos::X, |tag| tag.upos())
+    }
+}
+
+impl Document {
+    /// CoNLL-U 형식으로 쓴다.
+    ///
+    /// 문장마다 `# sent_id`(1부터)와 `# text` 주석을 달고, 어절마다 한 줄을 쓴다. 다음 어절과
+    /// 붙어 있으면 MISC 열에 `SpaceAfter=No`를 쓴다.
+    pub fn to_conllu(&self) -> String {
+        let mut out = String::new();
+        for (i, sentence) in self.sentences.iter().enumerate() {
+            out.push_str(&format!("# sent_id = {}\n", i + 1));
+            out.push_str(&format!("# text = {}\n", one_line(&sentence.text)));
+            for (j, eojeol) in sentence.eojeols.iter().enumerate() {
+                let joined = |f: &dyn Fn(&Morpheme) -> String| {
+                    if eojeol.morphemes.is_empty() {
+                        "_".to_string()
+                    } else {
+                        eojeol.morphemes.iter().map(f).collect::<Vec<_>>().join("+")
+                    }
+                };
+                let space_after = match sentence.eojeols.get(j + 1) {
+                    Some(next) if next.span.begin == eojeol.span.end() => "SpaceAfter=No",
+                    _ => "_",
+                };
+                out.push_str(&format!(
+                    "{}\t{}\t{}\t{}\t{}\t_\t_\t_\t_\t{}\n",
+                    j + 1,
+                    one_line(&eojeol.text),
+                    joined(&|m| escape(&m.text)),
+                    eojeol.upos(),
+                    joined(&|m| escape(m.tag.as_str())),
+                    space_after
+                ));
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    /// CoNLL-U 형식을 읽는다.
+    ///
+    /// LEMMA와 XPOS 열을 `\+`가 아닌 `+`로 나눠 형태소를 만든다. 문장 내용은 `# text` 주석을 쓰고, 없으면
+    /// 어절과 `SpaceAfter=No`로 만든다. 문장은 `\n`으로 이어 `text`를 만들고, 위치는 그 안의
+    /// 문자 단위다. 여러 어절을 묶는 줄(`1-2`)과 빈 노드(`1.1`)는 건너뛴다.
+    ///
+    /// Returns:
+    ///     Result<Document>: 열 수가 맞지 않거나 알 수 없는 XPOS가 있으면 `InvalidArgument` 오류
+    pub fn from_conllu(input: &str) -> Result<Document> {
+        let mut sentences = Vec::new();
+        let mut block = ConlluSentence::default();
+        for (n, line) in input.lines().enumerate() {
+            let line = line.trim_end_matches('\r');
+            if line.trim().is_empty() {
+                if !block.rows.is_empty() {
+                    sentences.push(std::mem::take(&mut block));
+                }
+                block = ConlluSentence::default();
+            } else if let Some(comment) = line.strip_prefix('#') {
+                if let Some(text) = comment.trim_start().strip_prefix("text =") {
+                    block.text = Some(text.trim().to_string());
+                }
+            } else {
+                block.rows.push(ConlluRow::parse(n + 1, line)?);
+            }
+        }
+        if !block.rows.is_empty() {
+            sentences.push(block);
+        }
+
+        let mut text = String::new();
+        let mut offset = 0;
+        let mut doc_sentences = Vec::new();
+        for block in sentences {
+            if !text.is_empty() {
+                text.push('\n');
+                offset += 1;
+            }
+            let sentence = block.into_sentence(offset)?;
+            offset += sentence.span.length;
+            text.push_str(&sentence.text);
+            doc_sentences.push(sentence);
+        }
+        Ok(Document {
+            text,
+            language: String::new(),
+            encoding: Encoding::Utf32,
+            sentences: doc_sentences,
+        })
+    }
+}
+
+/// 탭과 줄바꿈은 CoNLL-U 열을 깨뜨리므로 공백으로 바꾼다.
+fn one_line(s: &str) -> String {
+    s.replace(['\t', '\n', '\r'], " ")
+}
+
+/// LEMMA, XPOS 열에 쓸 형태소 하나. 구분자 `+`와 빈 값 `_`를 이스케이프한다.
+fn escape(s: &str) -> String {
+    match one_line(s).as_str() {
+        "" => "_".to_string(),
+        "_" => "\\_".to_string(),
+        s => s.replace('\\', "\\\\").replace('+', "\\+"),
+    }
+}
+
+/// `escape`로 쓴 형태소들을 나눈다. 열 전체가 `_`이면 빈 목록이다.
+fn split_escaped(col: &str) -> Vec<String> {
+    if col == "_" {
+        return Vec::new();
+    }
+    let mut parts = Vec::new();
+    let (mut part, mut raw) = (String::new(), String::new());
+    let mut chars = col.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                raw.push(c);
+                if let Some(next) = chars.next() {
+                    raw.push(next);
+                    part.push(next);
+                }
+            }
+            '+' => {
+                parts.push(if raw == "_" { String::new() } else { part });
+                (part, raw) = (String::new(), String::new());
+            }
+            c => {
+                raw.push(c);
+                part.push(c);
+            }
+        }
+    }
+    parts.push(if raw == "_" { String::new() } else { part });
+    parts
+}
+
+#[derive(Default)]
+struct ConlluSentence {
+    text: Option<String>,
+    rows: Vec<ConlluRow>,
+}
+
+struct ConlluRow {
+    form: String,
+    morphemes: Vec<(String, Tag)>,
+    space_after: bool,
+}
+
+impl ConlluRow {
+    /// 어절 줄을 읽는다. 여러 어절을 묶는 줄과 빈 노드는 건너뛰도록 `form`을 비운다.
+    fn parse(line_no: usize, line: &str) -> Result<ConlluRow> {
+        let err = |msg: String| {
+            BareunError::invalid_argument(format!("CoNLL-U line {}: {}", line_no, msg))
+        };
+        let cols: Vec<&str> = line.split('\t').collect();
+        if cols.len() != 10 {
+            return Err(err(format!("expected 10 columns, found {}", cols.len())));
+        }
+        let space_after = !cols[9].split('|').any(|v| v == "SpaceAfter=No");
+        if cols[0].contains(['-', '.']) {
+            return Ok(ConlluRow {
+                form: String::new(),
+                morphemes: Vec::new(),
+                space_after,
+            });
+        }
+        let tags = split_escaped(cols[4]);
+        let mut lemmas = split_escaped(cols[2]);
+        // 빈 형태소 하나는 열 전체가 `_`로 쓰인다.
+        if lemmas.is_empty() && tags.len() == 1 {
+            lemmas.push(String::new());
+        }
+        if lemmas.len() != tags.len() {
+            return Err(err(format!(
+                "LEMMA has {} morphemes but XPOS has {}",
+                lemmas.len(),
+                tags.len()
+            )));
+        }
+        let morphemes = lemmas
+            .into_iter()
+            .zip(tags)
+            .map(|(lemma, tag)| Ok((lemma, tag.parse::<Tag>().map_err(|e| err(e.to_string()))?)))
+            .collect::<Result<_>>()?;
+        Ok(ConlluRow {
+            form: cols[1].to_string(),
+            morphemes,
+            space_after,
+        })
+    }
+}
+
+impl ConlluSentence {
+    /// `offset`은 문서 안에서 문장이 시작하는 문자 위치다.
+    fn into_sentence(self, offset: usize) -> Result<Sentence> {
+        let rows: Vec<ConlluRow> = self
+            .rows
+            .into_iter()
+            .filter(|r| !r.form.is_empty())
+            .collect();
+        let text = self.text.unwrap_or_else(|| {
+            let mut text = String::new();
+            for (i, row) in rows.iter().enumerate() {
+                text.push_str(&row.form);
+                if row.space_after && i + 1 < rows.len() {
+                    text.push(' ');
+                }
+            }
+            text
+        });
+
+        let mut from = 0;
+        let mut eojeols = Vec::with_capacity(rows.len());
+        for row in rows {
+            let begin = find_chars(&text, &row.form, from).ok_or_else(|| {
+                BareunError::invalid_argument(format!(
+                    "CoNLL-U token '{}' is not found in sentence '{}'",
+                    row.form, text
+                ))
+            })?;
+            let length = row.form.chars().count();
+            from = begin + length;
+            let span = Span::new(offset + begin, length);
+
+            let mut morph_from = 0;
+            let morphemes = row
+                .morphemes
+                .iter()
+                .map(|(m, tag)| {
+                    let m_span = match find_chars(&row.form, m, morph_from) {
+                        Some(b) => {
+                            morph_from = b + m.chars().count();
+                            Span::new(span.begin + b, m.chars().count())
+                        }
+                        // 원형으로 바뀐 형태소(ㄴ다 등)는 어절 전체를 가리킨다.
+                        None => span,
+                    };
+                    Morpheme {
+                        text: m.clone(),
+                        span: m_span,
+                        tag: *tag,
+                        probability: 0.0,
+                        oov: OovKind::default(),
+                        custom_dict: None,
+                    }
+                })
+                .collect::<Vec<_>>();
+            let tagged = row
+                .morphemes
+                .iter()
+                .map(|(m, tag)| format!("{}/{}", m, tag))
+                .collect::<Vec<_>>()
+                .join("+");
+            eojeols.push(Eojeol {
+                text: row.form,
+                span,
+                lemma: None,
+                tagged,
+                modified: None,
+                morphemes,
+            });
+        }
+
+        let length = text.chars().count();
+        Ok(Sentence {
+            text,
+            span: Span::new(offset, length),
+            refined: None,
+            eojeols,
+        })
+    }
+}
+
+/// `text`의 `from`번째 문자부터 `part`를 찾아 문자 위치를 돌려준다.
+fn find_chars(text: &str, part: &str, from: usize) -> Option<usize> {
+    let byte_from = text.char_indices().nth(from).map_or(text.len(), |(i, _)| i);
+    text[byte_from..]
+        .find(part)
+        .map(|i| from + text[byte_from..byte_from + i].chars().count())
+}
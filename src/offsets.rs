@@ -0,0 +1,128 @@
+use std::ops::Range;
+
+use crate::bareun::EncodingType;
+use crate::error::{BareunError, Result};
+use crate::model::Span;
+
+/// 원문 안의 범위를 코드 포인트, UTF-8 바이트, UTF-16 단위로 나타낸 것
+///
+/// 하이라이트처럼 원문을 다른 인코딩으로 다루는 곳에 위치를 넘길 때 쓴다.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TextRange {
+    /// 코드 포인트(문자) 단위
+    pub chars: Range<usize>,
+    /// UTF-8 바이트 단위. `&str`을 자를 때 쓴다.
+    pub utf8: Range<usize>,
+    /// UTF-16 단위. JavaScript, Java 문자열의 위치와 같다.
+    pub utf16: Range<usize>,
+}
+
+/// 서버가 돌려준 위치를 코드 포인트, UTF-8, UTF-16 위치로 바꾸는 표
+///
+/// 서버는 요청한 인코딩(기본값 UTF-32)의 단위로 위치를 돌려준다. 표를 한 번 만들어 두면
+/// 위치마다 원문을 다시 훑지 않고 바꿀 수 있다. `Tagged::offset_index`로 만든다.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let index = tagged.offset_index();
+/// for m in tagged.morphemes() {
+///     let range = m.range(&index)?;
+///     println!("{} {:?} {:?}", m.text(), range.utf8, range.utf16);
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct OffsetIndex {
+    /// i번째 문자가 시작하는 UTF-8 위치. 마지막 값은 원문의 바이트 길이다.
+    utf8: Vec<usize>,
+    /// i번째 문자가 시작하는 UTF-16 위치. 마지막 값은 원문의 UTF-16 길이다.
+    utf16: Vec<usize>,
+    /// `range`에 넘기는 위치의 단위
+    encoding: EncodingType,
+}
+
+impl OffsetIndex {
+    /// 위치가 UTF-32(코드 포인트) 단위인 표를 만든다.
+    pub fn new(text: &str) -> Self {
+        Self::with_encoding(text, EncodingType::Utf32)
+    }
+
+    /// 위치가 `encoding` 단위인 표를 만든다.
+    pub fn with_encoding(text: &str, encoding: EncodingType) -> Self {
+        let n = text.chars().count();
+        let mut utf8 = Vec::with_capacity(n + 1);
+        let mut utf16 = Vec::with_capacity(n + 1);
+        let mut u16_pos = 0;
+        for (i, c) in text.char_indices() {
+            utf8.push(i);
+            utf16.push(u16_pos);
+            u16_pos += c.len_utf16();
+        }
+        utf8.push(text.len());
+        utf16.push(u16_pos);
+        OffsetIndex {
+            utf8,
+            utf16,
+            encoding,
+        }
+    }
+
+    /// `range`에 넘기는 위치의 단위
+    pub fn encoding(&self) -> EncodingType {
+        self.encoding
+    }
+
+    /// 원문의 문자 수
+    pub fn char_len(&self) -> usize {
+        self.utf8.len().saturating_sub(1)
+    }
+
+    /// `encoding` 단위의 `span`을 세 가지 단위의 범위로 바꾼다.
+    ///
+    /// Returns:
+    ///     Result<TextRange>: `span`이 원문을 벗어나거나 문자 경계가 아니면, 또는 위치를
+    ///                        요청하지 않았으면(`EncodingType::None`) `InvalidArgument` 오류
+    pub fn range(&self, span: Span) -> Result<TextRange> {
+        let (begin, end) = match self.encoding {
+            EncodingType::Utf32 => {
+                if span.end() > self.char_len() {
+                    return Err(BareunError::invalid_argument(format!(
+                        "Span {}..{} is out of range for text of {} characters",
+                        span.begin,
+                        span.end(),
+                        self.char_len()
+                    )));
+                }
+                (span.begin, span.end())
+            }
+            EncodingType::Utf8 => (
+                self.char_at(&self.utf8, span.begin)?,
+                self.char_at(&self.utf8, span.end())?,
+            ),
+            EncodingType::Utf16 => (
+                self.char_at(&self.utf16, span.begin)?,
+                self.char_at(&self.utf16, span.end())?,
+            ),
+            EncodingType::None => {
+                return Err(BareunError::invalid_argument(
+                    "Offsets are not available for EncodingType::None",
+                ));
+            }
+        };
+        Ok(TextRange {
+            chars: begin..end,
+            utf8: self.utf8[begin]..self.utf8[end],
+            utf16: self.utf16[begin]..self.utf16[end],
+        })
+    }
+
+    /// `table` 단위의 위치가 가리키는 문자의 순서
+    fn char_at(&self, table: &[usize], offset: usize) -> Result<usize> {
+        table.binary_search(&offset).map_err(|_| {
+            BareunError::invalid_argument(format!(
+                "Offset {} ({:?}) is out of range or not on a character boundary",
+                offset, self.encoding
+            ))
+        })
+    }
+}
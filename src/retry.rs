@@ -0,0 +1,174 @@
+use std::collections::hash_map::RandomState;
+use std::future::Future;
+use std::hash::{BuildHasher, Hasher};
+use std::time::{Duration, Instant};
+
+use tonic::{Code, Status};
+
+/// 일시적인 gRPC 오류에 대한 재시도 정책
+///
+/// 같은 요청을 다시 보내도 결과가 달라지지 않는 호출(`analyze_syntax`,
+/// `analyze_syntax_list`, `tokenize`, `correct_error`, 사용자 사전 `get_list`/`get`/`check_conflict`)에만
+/// 적용된다. 사전 갱신·삭제와 스트리밍 교정은 재시도하지 않는다.
+///
+/// n번째 재시도 전에는 `initial_backoff * multiplier^(n-1)`(최대 `max_backoff`)만큼 기다리며,
+/// 여러 클라이언트가 동시에 재시도하지 않도록 `jitter` 비율만큼 대기 시간을 무작위로 줄인다.
+///
+/// 기본값(8회, 200ms부터 두 배씩, 최대 5초)은 재시도 사이에 모두 약 16초를 기다린다.
+/// 서버 컨테이너가 다시 뜨는 동안 버틸 수 있는 정도이며, 더 오래 걸리는 환경이라면
+/// `max_attempts`나 `max_backoff`를 늘린다. `deadline`을 정하면 대기뿐 아니라 진행 중인
+/// 시도의 grpc-timeout도 남은 시간으로 줄어든다.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use std::time::Duration;
+/// use bareun_rs::{BareunClientConfig, RetryPolicy};
+///
+/// let config = BareunClientConfig::builder("YOUR_API_KEY")
+///     .host("localhost")
+///     .retry_policy(
+///         RetryPolicy::default()
+///             .max_attempts(5)
+///             .deadline(Duration::from_secs(30)),
+///     )
+///     .build();
+/// ```
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    /// 첫 시도를 포함한 최대 시도 횟수
+    pub max_attempts: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    pub multiplier: f64,
+    /// 0.0 ~ 1.0. 대기 시간에서 무작위로 줄일 수 있는 최대 비율
+    pub jitter: f64,
+    /// 재시도할 gRPC 상태 코드
+    pub retryable_codes: Vec<Code>,
+    /// 첫 시도부터 잰 전체 재시도 제한 시간. 다음 대기가 이 시간을 넘기면 재시도하지 않으며,
+    /// 각 시도의 제한 시간도 남은 시간을 넘지 않는다.
+    pub deadline: Option<Duration>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 8,
+            initial_backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(5),
+            multiplier: 2.0,
+            jitter: 0.2,
+            retryable_codes: vec![Code::Unavailable],
+            deadline: None,
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 재시도하지 않는 정책
+    pub fn disabled() -> Self {
+        Self::default().max_attempts(1)
+    }
+
+    pub fn max_attempts(mut self, v: u32) -> Self {
+        self.max_attempts = v.max(1);
+        self
+    }
+
+    pub fn initial_backoff(mut self, v: Duration) -> Self {
+        self.initial_backoff = v;
+        self
+    }
+
+    pub fn max_backoff(mut self, v: Duration) -> Self {
+        self.max_backoff = v;
+        self
+    }
+
+    pub fn multiplier(mut self, v: f64) -> Self {
+        self.multiplier = v.max(1.0);
+        self
+    }
+
+    pub fn jitter(mut self, v: f64) -> Self {
+        self.jitter = v.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn retryable_codes(mut self, codes: Vec<Code>) -> Self {
+        self.retryable_codes = codes;
+        self
+    }
+
+    pub fn deadline(mut self, v: Duration) -> Self {
+        self.deadline = Some(v);
+        self
+    }
+
+    pub fn is_retryable(&self, code: Code) -> bool {
+        self.retryable_codes.contains(&code)
+    }
+
+    /// `attempt`번째 재시도(1부터 시작) 전의 대기 시간
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exp = self.multiplier.powf(f64::from(attempt.saturating_sub(1)));
+        let base = (self.initial_backoff.as_secs_f64() * exp).min(self.max_backoff.as_secs_f64());
+        Duration::from_secs_f64(base * (1.0 - self.jitter * random_unit()))
+    }
+}
+
+/// 0.0 이상 1.0 미만의 난수
+fn random_unit() -> f64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u128(
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos(),
+    );
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// 정책에 따라 `call`을 반복 호출한다. 정책이 없으면 한 번만 호출한다.
+///
+/// `call`은 정책의 `deadline`까지 남은 시간을 받는다. 제한 시간이 없으면 `None`
+pub(crate) async fn with_retry<T, F, Fut>(
+    policy: Option<&RetryPolicy>,
+    mut call: F,
+) -> std::result::Result<T, Status>
+where
+    F: FnMut(Option<Duration>) -> Fut,
+    Fut: Future<Output = std::result::Result<T, Status>>,
+{
+    let Some(policy) = policy else {
+        return call(None).await;
+    };
+
+    let started = Instant::now();
+    let remaining = || policy.deadline.map(|d| d.saturating_sub(started.elapsed()));
+    let mut attempt = 1;
+    loop {
+        let status = match call(remaining()).await {
+            Ok(v) => return Ok(v),
+            Err(status) => status,
+        };
+
+        if attempt >= policy.max_attempts || !policy.is_retryable(status.code()) {
+            return Err(status);
+        }
+
+        let wait = policy.backoff(attempt);
+        if let Some(deadline) = policy.deadline
+            && started.elapsed() + wait > deadline
+        {
+            return Err(status);
+        }
+
+        tokio::time::sleep(wait).await;
+        attempt += 1;
+    }
+}
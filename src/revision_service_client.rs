@@ -1,30 +1,26 @@
-use tonic::transport::{Certificate, Channel, ClientTlsConfig, Endpoint};
-use tonic::{Request, Status};
+use std::time::Duration;
 
+use tonic::Streaming;
+use tonic::transport::Channel;
+
+use crate::balancer::EndpointPool;
 use crate::bareun::revision_service_client::RevisionServiceClient;
 use crate::bareun::{
     CorrectErrorRequest, CorrectErrorResponse, StreamCorrectErrorRequest,
     StreamCorrectErrorResponse,
 };
-use crate::constants::{CA_BUNDLE, MAX_MESSAGE_LENGTH};
-use crate::error::{BareunError, Result};
-use tonic::Streaming;
+use crate::config::{BareunClientConfig, CallOptions, RpcMethod};
+use crate::error::Result;
 
-fn resolve_port(host: &str, port: Option<u16>) -> u16 {
-    if let Some(p) = port {
-        p
-    } else if host.to_lowercase().starts_with("api.bareun.ai") {
-        443
-    } else {
-        5656
-    }
-}
+pub(crate) const CORRECT_ERROR: RpcMethod =
+    RpcMethod::new("bareun.RevisionService/CorrectError", true);
+pub(crate) const STREAM_CORRECT_ERROR: RpcMethod =
+    RpcMethod::new("bareun.RevisionService/StreamCorrectError", false);
 
+#[derive(Clone)]
 pub struct BareunRevisionServiceClient {
-    pub client: RevisionServiceClient<Channel>,
-    pub apikey: String,
-    pub host: String,
-    pub port: u16,
+    pool: EndpointPool,
+    pub config: BareunClientConfig,
 }
 
 impl BareunRevisionServiceClient {
@@ -35,102 +31,127 @@ impl BareunRevisionServiceClient {
     ///     host: gRPC 서버 주소
     ///     port: gRPC 서버 포트
     pub async fn new(apikey: &str, host: &str, port: Option<u16>) -> Result<Self> {
-        let host = host.trim();
-        let host = if host.is_empty() {
-            "api.bareun.ai"
-        } else {
-            host
-        };
-
-        let port = resolve_port(host, port);
-        let channel = Self::create_channel(host, port).await?;
-        let client = RevisionServiceClient::new(channel)
-            .max_decoding_message_size(MAX_MESSAGE_LENGTH)
-            .max_encoding_message_size(MAX_MESSAGE_LENGTH);
-
-        Ok(BareunRevisionServiceClient {
-            client,
-            apikey: apikey.to_string(),
-            host: host.to_string(),
-            port,
-        })
+        Self::from_config(BareunClientConfig::new(apikey, host, port)).await
     }
 
-    async fn create_channel(host: &str, port: u16) -> Result<Channel> {
-        let uri = if host.to_lowercase().starts_with("api.bareun.ai") {
-            format!("https://{}:{}", host, port)
-        } else {
-            format!("http://{}:{}", host, port)
-        };
-
-        let endpoint = Endpoint::from_shared(uri)?;
+    /// 설정 객체로 클라이언트를 만든다.
+    pub async fn from_config(config: BareunClientConfig) -> Result<Self> {
+        let pool = config.connect().await?;
+        Ok(Self::from_pool(pool, config))
+    }
 
-        let channel = if host.to_lowercase().starts_with("api.bareun.ai") {
-            let cert = Certificate::from_pem(CA_BUNDLE);
-            let tls = ClientTlsConfig::new().ca_certificate(cert);
-            endpoint.tls_config(tls)?.connect().await?
-        } else {
-            endpoint.connect().await?
-        };
+    /// 서버에 연결하지 않고 클라이언트를 만든다. 연결 오류는 첫 요청에서 드러난다.
+    pub fn connect_lazy(config: BareunClientConfig) -> Result<Self> {
+        let pool = config.connect_lazy()?;
+        Ok(Self::from_pool(pool, config))
+    }
 
-        Ok(channel)
+    /// 이미 연결된 채널 하나를 사용하는 클라이언트를 만든다.
+    pub fn from_channel(channel: Channel, config: BareunClientConfig) -> Self {
+        let pool = EndpointPool::single(&config.host, config.port, channel);
+        Self::from_pool(pool, config)
     }
 
-    fn handle_grpc_error(&self, e: Status) -> BareunError {
-        let code = e.code();
-        let details = e.message();
-        let server_message = if details.is_empty() {
-            "서버에서 추가 메시지를 제공하지 않았습니다.".to_string()
-        } else {
-            details.to_string()
-        };
+    /// 여러 서버의 채널 묶음을 사용하는 클라이언트를 만든다.
+    pub fn from_pool(pool: EndpointPool, config: BareunClientConfig) -> Self {
+        BareunRevisionServiceClient { pool, config }
+    }
 
-        match code {
-            tonic::Code::PermissionDenied => BareunError::PermissionDenied {
-                apikey: self.apikey.clone(),
-                message: server_message,
-            },
-            tonic::Code::Unavailable => BareunError::ServerUnavailable {
-                host: self.host.clone(),
-                port: self.port,
-                message: server_message,
-            },
-            tonic::Code::InvalidArgument => BareunError::InvalidArgument {
-                message: server_message,
-            },
-            _ => BareunError::GrpcError(server_message),
-        }
+    fn tonic_client(&self, channel: Channel) -> RevisionServiceClient<Channel> {
+        RevisionServiceClient::new(channel)
+            .max_decoding_message_size(self.config.max_decoding_message_size)
+            .max_encoding_message_size(self.config.max_encoding_message_size)
     }
 
     /// 맞춤법 교정을 위한 gRPC 호출
     pub async fn correct_error(
-        &mut self,
+        &self,
         request: CorrectErrorRequest,
     ) -> Result<CorrectErrorResponse> {
-        let mut req = Request::new(request);
-        req.metadata_mut()
-            .insert("api-key", self.apikey.parse().unwrap());
+        self.correct_error_with(request, &CallOptions::default())
+            .await
+    }
 
-        match self.client.correct_error(req).await {
-            Ok(response) => Ok(response.into_inner()),
-            Err(e) => Err(self.handle_grpc_error(e)),
-        }
+    /// 호출 옵션(제한 시간 등)을 지정해 맞춤법 교정을 요청한다.
+    pub async fn correct_error_with(
+        &self,
+        request: CorrectErrorRequest,
+        options: &CallOptions,
+    ) -> Result<CorrectErrorResponse> {
+        self.config
+            .call(
+                &self.pool,
+                &CORRECT_ERROR,
+                request,
+                options,
+                |channel, req| {
+                    let mut client = self.tonic_client(channel);
+                    async move { client.correct_error(req).await }
+                },
+            )
+            .await
     }
 
     /// 스트리밍 맞춤법 교정을 위한 gRPC 호출
     ///
     /// 서버에서 첫 번째 응답과 이후의 thinking revision 업데이트를 스트림으로 받는다.
     pub async fn stream_correct_error(
-        &mut self,
+        &self,
         request: StreamCorrectErrorRequest,
     ) -> Result<Streaming<StreamCorrectErrorResponse>> {
-        let mut req = Request::new(request);
-        req.metadata_mut()
-            .insert("api-key", self.apikey.parse().unwrap());
+        self.stream_correct_error_with(request, &CallOptions::default())
+            .await
+    }
 
-        match self.client.stream_correct_error(req).await {
-            Ok(response) => Ok(response.into_inner()),
-            Err(e) => Err(self.handle_grpc_error(e)),
+    /// 호출 옵션(제한 시간 등)을 지정해 스트리밍 교정을 요청한다.
+    ///
+    /// 제한 시간은 스트림 전체에 적용되며, 만료되면 서버가 스트림을 `DEADLINE_EXCEEDED`로 끝낸다.
+    pub async fn stream_correct_error_with(
+        &self,
+        request: StreamCorrectErrorRequest,
+        options: &CallOptions,
+    ) -> Result<Streaming<StreamCorrectErrorResponse>> {
+        self.stream_correct_error_picked(request, options)
+            .await
+            .map(|(stream, _)| stream)
+    }
+
+    /// `stream_correct_error_with`와 같지만 스트림을 보낸 서버의 (주소, 포트)도 돌려준다.
+    pub(crate) async fn stream_correct_error_picked(
+        &self,
+        request: StreamCorrectErrorRequest,
+        options: &CallOptions,
+    ) -> Result<(Streaming<StreamCorrectErrorResponse>, (String, u16))> {
+        let send = |request| {
+            self.config.send_picked(
+                &self.pool,
+                &STREAM_CORRECT_ERROR,
+                request,
+                options,
+                |channel, req| {
+                    let mut client = self.tonic_client(channel);
+                    async move { client.stream_correct_error(req).await }
+                },
+            )
+        };
+        match &self.config.cassette {
+            Some(cassette) => {
+                let live = send(request.clone());
+                cassette
+                    .stream(&self.config, &STREAM_CORRECT_ERROR, &request, options, live)
+                    .await
+            }
+            None => send(request).await,
         }
     }
+
+    /// 서버가 요청을 받을 수 있는지 한 번 확인한다.
+    pub async fn ready(&self) -> Result<()> {
+        crate::health::ready(&self.config, &self.pool, &CallOptions::default()).await
+    }
+
+    /// 서버가 준비될 때까지 최대 `timeout` 동안 기다린다.
+    pub async fn wait_ready(&self, timeout: Duration) -> Result<()> {
+        crate::health::wait_ready(&self.config, &self.pool, timeout).await
+    }
 }
@@ -0,0 +1,133 @@
+use std::time::Duration;
+
+use tonic::transport::Channel;
+
+use crate::balancer::EndpointPool;
+use crate::config::{BareunClientConfig, CallOptions};
+use crate::corrector::Corrector;
+use crate::custom_dict::CustomDict;
+use crate::custom_dict_client::CustomDictionaryServiceClient;
+use crate::error::{BareunError, Result};
+use crate::lang_service_client::BareunLanguageServiceClient;
+use crate::revision_service_client::BareunRevisionServiceClient;
+use crate::tagger::Tagger;
+use crate::tokenizer::Tokenizer;
+
+/// 하나의 gRPC 연결을 여러 객체가 함께 쓰도록 해주는 세션
+///
+/// gRPC 채널은 HTTP/2 위에서 여러 요청을 동시에 처리하므로, 세션에서 꺼낸
+/// `Tagger`, `Tokenizer`, `Corrector`, `CustomDict`는 새로 연결하지 않고
+/// 같은 채널을 사용한다. 서버를 여러 개 설정했다면 모든 객체가 같은 부하 분산 상태를
+/// 공유한다. 세션의 복제 비용은 작다.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use bareun_rs::{BareunClientConfig, BareunSession};
+///
+/// let session = BareunSession::connect(BareunClientConfig::new("YOUR_API_KEY", "localhost", None)).await?;
+///
+/// let mut tagger = session.tagger();
+/// let mut corrector = session.corrector();
+/// let dict = session.custom_dict("my")?;
+/// ```
+#[derive(Clone)]
+pub struct BareunSession {
+    config: BareunClientConfig,
+    pool: EndpointPool,
+}
+
+impl BareunSession {
+    /// 서버에 연결한 세션을 만든다.
+    ///
+    /// Args:
+    ///     apikey: Bareun API 키
+    ///     host: Bareun 서버 호스트 주소
+    ///     port: Bareun 서버 포트 번호
+    pub async fn new(apikey: &str, host: &str, port: Option<u16>) -> Result<Self> {
+        Self::connect(BareunClientConfig::new(apikey, host, port)).await
+    }
+
+    /// 설정 객체로 서버에 연결한 세션을 만든다.
+    pub async fn connect(config: BareunClientConfig) -> Result<Self> {
+        if config.apikey.is_empty() {
+            return Err(BareunError::MissingApiKey);
+        }
+
+        let pool = config.connect().await?;
+        Ok(BareunSession { config, pool })
+    }
+
+    /// 서버에 연결하지 않고 세션을 만든다.
+    ///
+    /// 서버가 아직 떠 있지 않아도 성공하며, 연결 오류는 첫 요청에서 드러난다.
+    /// 시작 시점에 서버를 기다려야 한다면 `wait_ready`를 사용한다. Tokio 런타임 안에서 호출해야 한다.
+    pub fn connect_lazy(config: BareunClientConfig) -> Result<Self> {
+        if config.apikey.is_empty() {
+            return Err(BareunError::MissingApiKey);
+        }
+
+        let pool = config.connect_lazy()?;
+        Ok(BareunSession { config, pool })
+    }
+
+    /// 서버가 요청을 받을 수 있는지 한 번 확인한다.
+    pub async fn ready(&self) -> Result<()> {
+        crate::health::ready(&self.config, &self.pool, &CallOptions::default()).await
+    }
+
+    /// 서버가 준비될 때까지 최대 `timeout` 동안 기다린다.
+    ///
+    /// 기다리는 동안 연결 실패는 다시 시도하며, 끝내 준비되지 않으면 `BareunError::Timeout`을 돌려준다.
+    pub async fn wait_ready(&self, timeout: Duration) -> Result<()> {
+        crate::health::wait_ready(&self.config, &self.pool, timeout).await
+    }
+
+    pub fn config(&self) -> &BareunClientConfig {
+        &self.config
+    }
+
+    /// 세션이 사용하는 (첫 번째 서버의) gRPC 채널
+    pub fn channel(&self) -> Channel {
+        self.pool.primary_channel()
+    }
+
+    /// 세션이 사용하는 서버별 채널 묶음
+    pub fn pool(&self) -> &EndpointPool {
+        &self.pool
+    }
+
+    pub fn language_client(&self) -> BareunLanguageServiceClient {
+        BareunLanguageServiceClient::from_pool(self.pool.clone(), self.config.clone())
+    }
+
+    pub fn revision_client(&self) -> BareunRevisionServiceClient {
+        BareunRevisionServiceClient::from_pool(self.pool.clone(), self.config.clone())
+    }
+
+    pub fn custom_dict_client(&self) -> CustomDictionaryServiceClient {
+        CustomDictionaryServiceClient::from_pool(self.pool.clone(), self.config.clone())
+    }
+
+    /// 세션의 연결을 사용하는 Tagger. 커스텀 사전은 `set_custom_dicts`로 지정한다.
+    pub fn tagger(&self) -> Tagger {
+        Tagger::from_session(self, vec![])
+    }
+
+    pub fn tokenizer(&self) -> Tokenizer {
+        Tokenizer::from_session(self)
+    }
+
+    pub fn corrector(&self) -> Corrector {
+        Corrector::from_session(self)
+    }
+
+    /// 세션의 연결을 사용하는 사용자 사전
+    ///
+    /// # Errors
+    ///
+    /// 사용자 사전의 이름이 비어 있으면 `BareunError::InvalidCustomDictName`을 돌려줍니다.
+    pub fn custom_dict(&self, name: &str) -> Result<CustomDict> {
+        CustomDict::with_client(name, self.custom_dict_client())
+    }
+}
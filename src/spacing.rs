@@ -0,0 +1,86 @@
+use crate::offsets::TextRange;
+
+/// 띄어쓰기 수정의 종류
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SpacingEditKind {
+    /// 공백을 넣는다.
+    Insert,
+    /// 공백을 지운다.
+    Remove,
+    /// 공백을 다른 공백으로 바꾼다(`\n` → ` `, 탭 → ` `).
+    Replace,
+}
+
+impl SpacingEditKind {
+    /// 원문의 글자를 차지하는 수정인지 여부. `Insert`만 빈 범위다.
+    fn consumes(self) -> bool {
+        self != SpacingEditKind::Insert
+    }
+}
+
+/// 자동 띄어쓰기/붙여쓰기가 원문에 한 수정 하나
+///
+/// `Tagged::spacing_edits`가 만든다. 위치는 `Tagged::phrase` 안의 범위이고, 넣기는 빈 범위다.
+/// 수정을 뒤에서부터 적용하면 `Tagged::refined_text`와 같은 문자열이 된다.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SpacingEdit {
+    pub kind: SpacingEditKind,
+    /// `phrase` 안의 위치. `Insert`이면 넣을 자리의 빈 범위다.
+    pub range: TextRange,
+    /// 넣은 공백, 지운 공백, 또는 바꾼 뒤의 공백
+    pub text: String,
+}
+
+/// 공백만 다른 두 문자열을 비교해 (종류, 시작 문자 위치, 끝 문자 위치, 공백)을 돌려준다.
+///
+/// 위치는 `original`의 문자 단위다. 공백이 아닌 글자가 다르면 `None`
+pub(crate) fn diff_spacing(
+    original: &str,
+    refined: &str,
+) -> Option<Vec<(SpacingEditKind, usize, usize, String)>> {
+    let mut edits: Vec<(SpacingEditKind, usize, usize, String)> = Vec::new();
+    let mut push = |kind: SpacingEditKind, at: usize, c: char| match edits.last_mut() {
+        // 이어지는 수정은 하나로 묶는다.
+        Some((k, _, end, text)) if *k == kind && *end == at => {
+            if kind.consumes() {
+                *end += 1;
+            }
+            text.push(c);
+        }
+        _ => {
+            let end = if kind.consumes() { at + 1 } else { at };
+            edits.push((kind, at, end, c.to_string()));
+        }
+    };
+
+    let mut orig = original.chars().peekable();
+    let mut refd = refined.chars().peekable();
+    let mut i = 0;
+    loop {
+        match (orig.peek().copied(), refd.peek().copied()) {
+            (None, None) => break,
+            (Some(o), Some(r)) if o == r => {
+                orig.next();
+                refd.next();
+                i += 1;
+            }
+            (Some(o), Some(r)) if o.is_whitespace() && r.is_whitespace() => {
+                push(SpacingEditKind::Replace, i, r);
+                orig.next();
+                refd.next();
+                i += 1;
+            }
+            (Some(o), _) if o.is_whitespace() => {
+                push(SpacingEditKind::Remove, i, o);
+                orig.next();
+                i += 1;
+            }
+            (_, Some(r)) if r.is_whitespace() => {
+                push(SpacingEditKind::Insert, i, r);
+                refd.next();
+            }
+            _ => return None,
+        }
+    }
+    Some(edits)
+}
@@ -1,19 +1,48 @@
 use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::sync::Arc;
+use std::time::Duration;
 
-use crate::bareun::morpheme::{OutOfVocab, Tag};
-use crate::bareun::{AnalyzeSyntaxResponse, Morpheme, Sentence};
+use crate::backend::LanguageBackend;
+use crate::bareun::morpheme::OutOfVocab;
+use crate::bareun::{AnalyzeSyntaxResponse, EncodingType, Morpheme, Sentence};
+use crate::config::{BareunClientConfig, CallOptions};
 use crate::custom_dict::CustomDict;
 use crate::error::{BareunError, Result};
-use crate::lang_service_client::BareunLanguageServiceClient;
+use crate::lang_service_client::{analyze_syntax_list_request, analyze_syntax_request};
+use crate::lemma::LemmaOptions;
+use crate::model::{Document, Span, Tag, TagCategory};
+use crate::offsets::{OffsetIndex, TextRange};
+use crate::session::BareunSession;
+use crate::spacing::{SpacingEdit, diff_spacing};
+use crate::view::{EojeolView, MorphemeView, SentenceView};
 
 pub struct Tagged {
     pub phrase: String,
     pub r: AnalyzeSyntaxResponse,
+    /// 응답의 위치(`begin_offset`, `length`)가 쓰는 단위
+    pub encoding: EncodingType,
 }
 
 impl Tagged {
+    /// 위치가 UTF-32(문자) 단위인 분석 결과를 만듭니다.
     pub fn new(phrase: String, res: AnalyzeSyntaxResponse) -> Self {
-        Tagged { phrase, r: res }
+        Tagged {
+            phrase,
+            r: res,
+            encoding: EncodingType::Utf32,
+        }
+    }
+
+    /// 응답의 위치가 쓰는 단위를 지정합니다.
+    pub fn with_encoding(mut self, encoding: EncodingType) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
+    /// 응답의 위치가 쓰는 단위
+    pub fn encoding(&self) -> EncodingType {
+        self.encoding
     }
 
     pub fn phrase(&self) -> &str {
@@ -24,12 +53,268 @@ impl Tagged {
         &self.r
     }
 
+    /// 문장 목록을 복사해서 돌려줍니다. 복사하지 않으려면 `sentences_iter`를 쓰세요.
     pub fn sentences(&self) -> Vec<Sentence> {
         self.r.sentences.to_vec()
     }
 
-    fn _pos(m: &Morpheme, join: bool, detail: bool) -> String {
-        if join {
+    /// 문장을 복사하지 않고 차례로 돌려줍니다.
+    pub fn sentences_iter(&self) -> impl Iterator<Item = SentenceView<'_>> {
+        self.r
+            .sentences
+            .iter()
+            .enumerate()
+            .map(|(index, sentence)| SentenceView::new(index, sentence))
+    }
+
+    /// 모든 문장의 어절을 복사하지 않고 차례로 돌려줍니다.
+    ///
+    /// 어절마다 원문 위치(`span`), 형태소, 서버의 `tagged` 문자열, 기본형, 자동 띄어쓰기/붙여쓰기로
+    /// 바뀐 내용(`modified`)을 볼 수 있습니다.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// for e in tagged.eojeols().filter(|e| e.is_modified()) {
+    ///     println!("{} -> {} ({})", e.text(), e.modified().unwrap(), e.tagged());
+    /// }
+    /// ```
+    pub fn eojeols(&self) -> impl Iterator<Item = EojeolView<'_>> {
+        self.sentences_iter().flat_map(|s| s.eojeols())
+    }
+
+    /// 모든 문장의 형태소를 복사하지 않고 차례로 돌려줍니다.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let verbs = tagged.morphemes().filter(|m| m.tag() == Tag::Vv).count();
+    /// ```
+    pub fn morphemes(&self) -> impl Iterator<Item = MorphemeView<'_>> {
+        self.eojeols().flat_map(|e| e.morphemes())
+    }
+
+    /// 분석 결과를 proto 타입과 분리된 `model::Document`로 바꿉니다.
+    ///
+    /// # Errors
+    ///
+    /// 서버 응답에 text가 없는 문장, 어절, 형태소가 있으면 `BareunError::MalformedResponse`를 돌려줍니다.
+    pub fn document(&self) -> Result<Document> {
+        Ok(Document {
+            encoding: self.encoding.into(),
+            ..Document::from_response(&self.phrase, &self.r)?
+        })
+    }
+
+    /// 응답의 위치(`encoding` 단위)를 코드 포인트, UTF-8, UTF-16 위치로 바꾸는 표를 만듭니다.
+    pub fn offset_index(&self) -> OffsetIndex {
+        OffsetIndex::with_encoding(&self.phrase, self.encoding)
+    }
+
+    /// `phrase`에서 `range`에 해당하는 부분을 잘라 돌려줍니다.
+    ///
+    /// # Errors
+    ///
+    /// `range`가 `phrase`를 벗어나거나 문자 경계가 아니면 `BareunError::InvalidArgument`를 돌려줍니다.
+    pub fn slice(&self, range: &TextRange) -> Result<&str> {
+        self.phrase.get(range.utf8.clone()).ok_or_else(|| {
+            BareunError::invalid_argument(format!(
+                "Byte range {:?} is not a valid slice of the phrase",
+                range.utf8
+            ))
+        })
+    }
+
+    /// 서버가 돌려준 위치가 입력과 맞는지 확인합니다.
+    ///
+    /// 문장과 어절은 위치로 자른 `phrase`가 내용과 같아야 하고, 형태소는 어절 안에 있어야 합니다.
+    /// 형태소의 내용은 원형으로 바뀌어 있을 수 있으므로(`ㄴ다` 등) 비교하지 않습니다.
+    ///
+    /// # Errors
+    ///
+    /// 맞지 않는 위치가 있으면 `BareunError::MalformedResponse`를 돌려줍니다.
+    pub fn validate_offsets(&self) -> Result<()> {
+        let index = self.offset_index();
+        let range = |span: Span, what: &dyn Fn() -> String| {
+            index
+                .range(span)
+                .map_err(|e| BareunError::MalformedResponse(format!("{}: {}", what(), e)))
+        };
+        for s in self.sentences_iter() {
+            let what = || format!("sentence {}", s.index());
+            self.check_content(&range(s.span(), &what)?, s.text(), &what)?;
+            for e in s.eojeols() {
+                let what = || format!("token {} of sentence {}", e.index(), s.index());
+                let outer = range(e.span(), &what)?;
+                self.check_content(&outer, e.text(), &what)?;
+                for m in e.morphemes() {
+                    let what = || format!("morpheme {} of {}", m.index(), what());
+                    let inner = range(m.span(), &what)?;
+                    if inner.chars.start < outer.chars.start || inner.chars.end > outer.chars.end {
+                        return Err(BareunError::MalformedResponse(format!(
+                            "{} at {:?} is outside its token at {:?}",
+                            what(),
+                            inner.chars,
+                            outer.chars
+                        )));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn check_content(
+        &self,
+        range: &TextRange,
+        content: &str,
+        what: &dyn Fn() -> String,
+    ) -> Result<()> {
+        let found = &self.phrase[range.utf8.clone()];
+        if found != content {
+            return Err(BareunError::MalformedResponse(format!(
+                "{} at {:?} is '{}' in the input but '{}' in the response",
+                what(),
+                range.chars,
+                found,
+                content
+            )));
+        }
+        Ok(())
+    }
+
+    /// 자동 띄어쓰기/붙여쓰기를 반영한 전체 문장을 돌려줍니다.
+    ///
+    /// 문장마다 서버의 `refined`를 쓰고, 비어 있으면 어절의 `modified`로 바꾼 원문을 씁니다.
+    /// 문장 사이의 내용(줄바꿈 등)은 `phrase` 그대로 둡니다. `auto_spacing`, `auto_jointing`을 켜고
+    /// 분석해야 바뀐 내용이 있습니다.
+    ///
+    /// # Errors
+    ///
+    /// 문장이나 어절의 위치가 `phrase`와 맞지 않으면 `BareunError::MalformedResponse`를 돌려줍니다.
+    pub fn refined_text(&self) -> Result<String> {
+        let index = self.offset_index();
+        let mut out = String::with_capacity(self.phrase.len());
+        let mut pos = 0;
+        for s in self.sentences_iter() {
+            let range =
+                self.response_range(&index, s.span(), || format!("sentence {}", s.index()))?;
+            out.push_str(self.phrase_between(pos, range.utf8.start)?);
+            out.push_str(&self.refined_sentence(&index, s, &range)?);
+            pos = range.utf8.end;
+        }
+        out.push_str(self.phrase_between(pos, self.phrase.len())?);
+        Ok(out)
+    }
+
+    /// 자동 띄어쓰기/붙여쓰기가 넣거나 지운 공백을 `phrase` 안의 위치와 함께 돌려줍니다.
+    ///
+    /// `Corrector`를 쓰지 않고 띄어쓰기만 고칠 때 씁니다.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let tagged = tagger.tag("나비가난다", false, true, true).await?;
+    /// for edit in tagged.spacing_edits()? {
+    ///     println!("{:?} {:?} at {:?}", edit.kind, edit.text, edit.range.chars);
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// 위치가 `phrase`와 맞지 않거나, 바뀐 문장이 공백 밖의 글자도 다르면
+    /// `BareunError::MalformedResponse`를 돌려줍니다.
+    pub fn spacing_edits(&self) -> Result<Vec<SpacingEdit>> {
+        let index = self.offset_index();
+        let chars = OffsetIndex::new(&self.phrase);
+        let mut edits = Vec::new();
+        for s in self.sentences_iter() {
+            let range =
+                self.response_range(&index, s.span(), || format!("sentence {}", s.index()))?;
+            let refined = self.refined_sentence(&index, s, &range)?;
+            let diff =
+                diff_spacing(&self.phrase[range.utf8.clone()], &refined).ok_or_else(|| {
+                    BareunError::MalformedResponse(format!(
+                        "refined text of sentence {} differs from the input beyond spacing: '{}'",
+                        s.index(),
+                        refined
+                    ))
+                })?;
+            for (kind, begin, end, text) in diff {
+                let span = Span::new(range.chars.start + begin, end - begin);
+                edits.push(SpacingEdit {
+                    kind,
+                    range: chars.range(span)?,
+                    text,
+                });
+            }
+        }
+        Ok(edits)
+    }
+
+    /// 문장 하나의 띄어쓰기를 고친 내용. `range`는 문장의 위치입니다.
+    fn refined_sentence(
+        &self,
+        index: &OffsetIndex,
+        s: SentenceView<'_>,
+        range: &TextRange,
+    ) -> Result<String> {
+        if !s.raw().refined.is_empty() {
+            return Ok(s.raw().refined.clone());
+        }
+        let mut out = String::new();
+        let mut pos = range.utf8.start;
+        for e in s.eojeols() {
+            if let Some(modified) = e.modified() {
+                let token = self.response_range(index, e.span(), || {
+                    format!("token {} of sentence {}", e.index(), s.index())
+                })?;
+                out.push_str(self.phrase_between(pos, token.utf8.start)?);
+                out.push_str(modified);
+                pos = token.utf8.end;
+            }
+        }
+        out.push_str(self.phrase_between(pos, range.utf8.end)?);
+        Ok(out)
+    }
+
+    fn response_range(
+        &self,
+        index: &OffsetIndex,
+        span: Span,
+        what: impl Fn() -> String,
+    ) -> Result<TextRange> {
+        index
+            .range(span)
+            .map_err(|e| BareunError::MalformedResponse(format!("{}: {}", what(), e)))
+    }
+
+    /// `phrase`의 바이트 `begin..end`. 응답의 위치가 겹치거나 거꾸로면 오류
+    fn phrase_between(&self, begin: usize, end: usize) -> Result<&str> {
+        self.phrase.get(begin..end).ok_or_else(|| {
+            BareunError::MalformedResponse(format!(
+                "Response offsets overlap or go backwards at byte {} of the phrase",
+                begin
+            ))
+        })
+    }
+
+    /// 분석 결과를 CoNLL-U 형식으로 씁니다. 어절 하나가 한 줄입니다(`model::Document::to_conllu`).
+    ///
+    /// # Errors
+    ///
+    /// 서버 응답에 text가 없는 문장, 어절, 형태소가 있으면 `BareunError::MalformedResponse`를 돌려줍니다.
+    pub fn to_conllu(&self) -> Result<String> {
+        Ok(self.document()?.to_conllu())
+    }
+
+    fn _pos(m: &Morpheme, join: bool, detail: bool) -> Result<String> {
+        let content = &m
+            .text
+            .as_ref()
+            .ok_or_else(|| BareunError::MalformedResponse("morpheme without text".to_string()))?
+            .content;
+        Ok(if join {
             if detail {
                 let p = if m.probability > 0.0 {
                     format!(":{:5.3}", m.probability)
@@ -41,42 +326,33 @@ impl Tagged {
                 } else {
                     String::new()
                 };
-                format!(
-                    "{}/{}{}{}",
-                    m.text.as_ref().expect("text is empty").content,
-                    m.tag().as_str_name(),
-                    p,
-                    oov
-                )
+                format!("{}/{}{}{}", content, m.tag().as_str_name(), p, oov)
             } else {
-                format!(
-                    "{}/{}",
-                    m.text.as_ref().expect("text is empty").content,
-                    m.tag().as_str_name()
-                )
+                format!("{}/{}", content, m.tag().as_str_name())
             }
         } else {
             if detail {
                 format!(
                     "{}\t{}\t{}\t{}",
-                    m.text.as_ref().expect("text is empty").content,
+                    content,
                     m.tag().as_str_name(),
                     m.out_of_vocab().as_str_name(),
                     m.probability
                 )
             } else {
-                format!(
-                    "{}\t{}",
-                    m.text.as_ref().expect("text is empty").content,
-                    m.tag().as_str_name()
-                )
+                format!("{}\t{}", content, m.tag().as_str_name())
             }
-        }
+        })
     }
 
-    pub fn pos(&self, flatten: bool, join: bool, detail: bool) -> Vec<Vec<String>> {
+    /// 형태소와 품사 태그의 목록을 만듭니다.
+    ///
+    /// # Errors
+    ///
+    /// 서버 응답에 text가 없는 형태소가 있으면 `BareunError::MalformedResponse`를 돌려줍니다.
+    pub fn pos(&self, flatten: bool, join: bool, detail: bool) -> Result<Vec<Vec<String>>> {
         if flatten {
-            vec![
+            Ok(vec![
                 self.r
                     .sentences
                     .iter()
@@ -88,8 +364,8 @@ impl Tagged {
                                 .map(|m| Tagged::_pos(m, join, detail))
                         })
                     })
-                    .collect(),
-            ]
+                    .collect::<Result<_>>()?,
+            ])
         } else {
             self.r
                 .sentences
@@ -115,13 +391,7 @@ impl Tagged {
     ///
     /// 분석된 모든 형태소의 벡터
     pub fn morphs(&self) -> Vec<String> {
-        self.r
-            .sentences
-            .iter()
-            .flat_map(|s| &s.tokens)
-            .flat_map(|token| &token.morphemes)
-            .filter_map(|m| m.text.as_ref().map(|t| t.content.clone()))
-            .collect()
+        self.filter_by(|_| true)
     }
 
     /// 명사를 추출합니다.
@@ -130,19 +400,7 @@ impl Tagged {
     ///
     /// 분석된 모든 명사(고유명사, 일반명사, 대명사, 의존명사)의 벡터
     pub fn nouns(&self) -> Vec<String> {
-        self.r
-            .sentences
-            .iter()
-            .flat_map(|s| &s.tokens)
-            .flat_map(|token| &token.morphemes)
-            .filter(|m| {
-                m.tag == Tag::Nnp as i32
-                    || m.tag == Tag::Nng as i32
-                    || m.tag == Tag::Np as i32
-                    || m.tag == Tag::Nnb as i32
-            })
-            .filter_map(|m| m.text.as_ref().map(|t| t.content.clone()))
-            .collect()
+        self.filter_by(|tag| tag.is_noun())
     }
 
     /// 동사를 추출합니다.
@@ -151,13 +409,98 @@ impl Tagged {
     ///
     /// 분석된 모든 동사의 벡터
     pub fn verbs(&self) -> Vec<String> {
-        self.r
-            .sentences
-            .iter()
-            .flat_map(|s| &s.tokens)
-            .flat_map(|token| &token.morphemes)
-            .filter(|m| m.tag == Tag::Vv as i32)
-            .filter_map(|m| m.text.as_ref().map(|t| t.content.clone()))
+        self.filter_by(|tag| tag == Tag::Vv)
+    }
+
+    /// 용언(동사, 형용사, 보조 용언, 지정사)을 추출합니다.
+    pub fn predicates(&self) -> Vec<String> {
+        self.filter_by(|tag| tag.is_predicate())
+    }
+
+    /// 체언(명사, 대명사, 수사)을 추출합니다.
+    pub fn substantives(&self) -> Vec<String> {
+        self.filter_by(|tag| tag.is_substantive())
+    }
+
+    /// 형용사를 추출합니다.
+    pub fn adjectives(&self) -> Vec<String> {
+        self.filter_by(|tag| tag == Tag::Va)
+    }
+
+    /// 부사를 추출합니다.
+    pub fn adverbs(&self) -> Vec<String> {
+        self.filter_by(|tag| tag.category() == TagCategory::Adverb)
+    }
+
+    /// 관형사를 추출합니다.
+    pub fn prenouns(&self) -> Vec<String> {
+        self.filter_by(|tag| tag.category() == TagCategory::Prenoun)
+    }
+
+    /// 조사를 추출합니다.
+    pub fn postpositions(&self) -> Vec<String> {
+        self.filter_by(|tag| tag.is_particle())
+    }
+
+    /// 감탄사를 추출합니다.
+    pub fn interjections(&self) -> Vec<String> {
+        self.filter_by(|tag| tag == Tag::Ic)
+    }
+
+    /// 어미를 추출합니다.
+    pub fn endings(&self) -> Vec<String> {
+        self.filter_by(|tag| tag.is_ending())
+    }
+
+    /// 접사를 추출합니다.
+    pub fn affixes(&self) -> Vec<String> {
+        self.filter_by(|tag| tag.is_affix())
+    }
+
+    /// 기호, 외국어, 한자, 숫자를 추출합니다.
+    pub fn symbols(&self) -> Vec<String> {
+        self.filter_by(|tag| tag.is_symbol())
+    }
+
+    /// 태그가 `keep`을 만족하는 형태소를 추출합니다. text가 없는 형태소는 건너뜁니다.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let content_words = tagged.filter_by(|tag| tag.is_substantive() || tag == Tag::Va);
+    /// ```
+    pub fn filter_by(&self, keep: impl Fn(Tag) -> bool) -> Vec<String> {
+        self.morphemes()
+            .filter(|m| m.raw().text.is_some() && keep(m.tag()))
+            .map(|m| m.text().to_string())
+            .collect()
+    }
+
+    /// 어절마다 기본형(사전형)을 추출합니다. `먹었다` → `먹다`, `갔다` → `가다`
+    ///
+    /// 서버가 준 기본형을 쓰고, 비어 있으면 형태소로 만듭니다. 내용어가 없는 어절(기호 등)은
+    /// 건너뜁니다.
+    ///
+    /// # Returns
+    ///
+    /// 분석된 모든 어절의 기본형 벡터
+    pub fn lemmas(&self) -> Vec<String> {
+        self.lemmas_with(&LemmaOptions::default())
+    }
+
+    /// `options`에 따라 파생 접미사(XSV, XSA)나 지정사(VCP)를 뺀 기본형을 추출합니다.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// // 카톡했다 → 카톡, 학생이다 → 학생
+    /// let opts = LemmaOptions::new().keep_derivation(false).keep_copula(false);
+    /// let lemmas = tagged.lemmas_with(&opts);
+    /// ```
+    pub fn lemmas_with(&self, options: &LemmaOptions) -> Vec<String> {
+        self.eojeols()
+            .map(|e| e.lemma_with(options).into_owned())
+            .filter(|lemma| !lemma.is_empty())
             .collect()
     }
 
@@ -176,13 +519,137 @@ impl Tagged {
     }
 }
 
+/// 형태소 분석 요청 옵션
+///
+/// `Tagger`에 기본값을 지정해 두고(`set_options`), 호출마다 `*_with_options`로 바꿀 수 있다.
+/// 기본값은 기존 `pos`/`morphs`/`nouns`/`verbs`와 같다(띄어쓰기 보정만 사용).
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use std::time::Duration;
+/// use bareun_rs::TagOptions;
+///
+/// let opts = TagOptions::new()
+///     .auto_spacing(false)
+///     .custom_dicts(vec!["my".to_string()])
+///     .timeout(Duration::from_secs(2));
+/// let nouns = tagger.nouns_with_options("나비 허리에 새파란 초생달이 시리다.", &opts).await?;
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TagOptions {
+    /// 문장 자동 분리 여부
+    pub auto_split: bool,
+    /// 띄어쓰기 보정 여부
+    pub auto_spacing: bool,
+    /// 붙여쓰기 보정 여부
+    pub auto_jointing: bool,
+    /// 사용할 사용자 사전 이름. `None`이면 `Tagger`에 지정한 사전을 쓴다.
+    pub custom_dicts: Option<Vec<String>>,
+    /// `custom_dicts`(또는 `Tagger`의 사전) 뒤에 덧붙일 사용자 사전 이름
+    pub extra_custom_dicts: Vec<String>,
+    /// 요청 전에 사용자 사전이 서버에 있는지 확인할지 여부. 확인할 때마다 사전 목록을 조회한다.
+    pub validate_custom_dicts: bool,
+    /// 이 호출의 제한 시간. `None`이면 클라이언트 설정을 따른다.
+    pub timeout: Option<Duration>,
+    /// 서버가 돌려줄 위치의 단위. `None`이면 `Tagger`의 설정을 따른다.
+    pub encoding: Option<EncodingType>,
+}
+
+impl Default for TagOptions {
+    fn default() -> Self {
+        TagOptions {
+            auto_split: false,
+            auto_spacing: true,
+            auto_jointing: false,
+            custom_dicts: None,
+            extra_custom_dicts: Vec::new(),
+            validate_custom_dicts: false,
+            timeout: None,
+            encoding: None,
+        }
+    }
+}
+
+impl TagOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn auto_split(mut self, v: bool) -> Self {
+        self.auto_split = v;
+        self
+    }
+
+    pub fn auto_spacing(mut self, v: bool) -> Self {
+        self.auto_spacing = v;
+        self
+    }
+
+    pub fn auto_jointing(mut self, v: bool) -> Self {
+        self.auto_jointing = v;
+        self
+    }
+
+    /// `Tagger`의 사용자 사전 대신 쓸 사전 목록
+    pub fn custom_dicts(mut self, custom_dicts: Vec<String>) -> Self {
+        self.custom_dicts = Some(custom_dicts);
+        self
+    }
+
+    /// `Tagger`의 사용자 사전 뒤에 덧붙일 사전 목록
+    pub fn add_custom_dicts(mut self, custom_dicts: Vec<String>) -> Self {
+        self.extra_custom_dicts.extend(custom_dicts);
+        self
+    }
+
+    pub fn validate_custom_dicts(mut self, v: bool) -> Self {
+        self.validate_custom_dicts = v;
+        self
+    }
+
+    /// 실제로 요청에 담을 사용자 사전 목록을 만든다.
+    ///
+    /// `custom_dicts`가 있으면 그것을, 없으면 `default`를 쓰고 뒤에 `extra_custom_dicts`를 붙인다.
+    /// 순서는 유지하며, 같은 이름이 여러 번 나오면 처음 것만 남긴다.
+    pub fn resolve_custom_dicts(&self, default: &[String]) -> Vec<String> {
+        let base = self.custom_dicts.as_deref().unwrap_or(default);
+        let mut names: Vec<String> = Vec::with_capacity(base.len() + self.extra_custom_dicts.len());
+        for name in base.iter().chain(&self.extra_custom_dicts) {
+            if !names.contains(name) {
+                names.push(name.clone());
+            }
+        }
+        names
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn encoding(mut self, encoding: EncodingType) -> Self {
+        self.encoding = Some(encoding);
+        self
+    }
+
+    fn call_options(&self) -> CallOptions {
+        CallOptions {
+            timeout: self.timeout,
+            encoding: self.encoding,
+        }
+    }
+}
+
+#[derive(Clone)]
 pub struct Tagger {
-    client: BareunLanguageServiceClient,
+    /// 백엔드를 직접 지정했으면 `None`
+    session: Option<BareunSession>,
+    backend: Arc<dyn LanguageBackend>,
     custom_dicts: Vec<String>,
+    options: TagOptions,
+    encoding: EncodingType,
     internal_custom_dicts: HashMap<String, CustomDict>,
-    apikey: String,
-    host: String,
-    port: i32,
 }
 
 impl Tagger {
@@ -192,132 +659,349 @@ impl Tagger {
         port: Option<u16>,
         custom_dicts: Vec<String>,
     ) -> Result<Self> {
-        if apikey.is_empty() {
-            return Err(BareunError::MissingApiKey);
-        }
+        Self::from_config(BareunClientConfig::new(apikey, host, port), custom_dicts).await
+    }
 
-        let client = BareunLanguageServiceClient::new(apikey, host, port).await?;
+    /// 설정 객체로 Tagger를 만든다.
+    pub async fn from_config(
+        config: BareunClientConfig,
+        custom_dicts: Vec<String>,
+    ) -> Result<Self> {
+        let session = BareunSession::connect(config).await?;
+        Ok(Self::from_session(&session, custom_dicts))
+    }
 
-        // Store connection info for CustomDict creation
-        let host_str = if host.trim().is_empty() {
-            "api.bareun.ai"
-        } else {
-            host.trim()
-        };
-        let port_i32 = port.unwrap_or(if host_str == "api.bareun.ai" {
-            443
-        } else {
-            5656
-        }) as i32;
+    /// 서버에 연결하지 않고 Tagger를 만든다. 연결 오류는 첫 요청에서 드러난다.
+    pub fn connect_lazy(config: BareunClientConfig, custom_dicts: Vec<String>) -> Result<Self> {
+        let session = BareunSession::connect_lazy(config)?;
+        Ok(Self::from_session(&session, custom_dicts))
+    }
+
+    /// 서버가 요청을 받을 수 있는지 한 번 확인한다.
+    pub async fn ready(&self) -> Result<()> {
+        self.backend.ready().await
+    }
+
+    /// 서버가 준비될 때까지 최대 `timeout` 동안 기다린다.
+    pub async fn wait_ready(&self, timeout: Duration) -> Result<()> {
+        self.backend.wait_ready(timeout).await
+    }
 
-        Ok(Tagger {
-            client,
+    /// 세션의 연결을 함께 사용하는 Tagger를 만든다.
+    pub fn from_session(session: &BareunSession, custom_dicts: Vec<String>) -> Self {
+        Tagger {
+            session: Some(session.clone()),
+            backend: Arc::new(session.language_client()),
             custom_dicts,
+            options: TagOptions::default(),
+            encoding: session.config().encoding,
             internal_custom_dicts: HashMap::new(),
-            apikey: apikey.to_string(),
-            host: host_str.to_string(),
-            port: port_i32,
-        })
+        }
+    }
+
+    /// 지정한 백엔드로 요청을 보내는 Tagger를 만든다.
+    ///
+    /// 서버 연결이 없으므로 `custom_dict`로 만든 사용자 사전은 서버에 연결되어 있지 않다.
+    pub fn with_backend(
+        backend: impl LanguageBackend + 'static,
+        custom_dicts: Vec<String>,
+    ) -> Self {
+        Self::with_shared_backend(Arc::new(backend), custom_dicts)
+    }
+
+    /// 다른 객체와 함께 쓰는 백엔드로 Tagger를 만든다.
+    pub fn with_shared_backend(
+        backend: Arc<dyn LanguageBackend>,
+        custom_dicts: Vec<String>,
+    ) -> Self {
+        Tagger {
+            session: None,
+            backend,
+            custom_dicts,
+            options: TagOptions::default(),
+            encoding: EncodingType::Utf32,
+            internal_custom_dicts: HashMap::new(),
+        }
+    }
+
+    /// 요청을 보내는 백엔드
+    pub fn backend(&self) -> &Arc<dyn LanguageBackend> {
+        &self.backend
+    }
+
+    /// 서버가 돌려줄 위치의 기본 단위를 바꾼다. 처음 값은 클라이언트 설정(`BareunClientConfig::encoding`),
+    /// 백엔드를 직접 지정했으면 `EncodingType::Utf32`다.
+    pub fn set_encoding(&mut self, encoding: EncodingType) {
+        self.encoding = encoding;
+    }
+
+    /// 서버가 돌려줄 위치의 기본 단위
+    pub fn encoding(&self) -> EncodingType {
+        self.encoding
     }
 
     pub fn set_custom_dicts(&mut self, custom_dicts: Vec<String>) {
         self.custom_dicts = custom_dicts;
     }
 
-    pub fn custom_dict(&mut self, name: &str) -> &mut CustomDict {
-        if name.is_empty() {
-            panic!("invalid name for custom dict");
-        }
+    /// `pos`, `morphs`, `nouns`, `verbs`가 사용하는 기본 옵션을 바꾼다.
+    pub fn set_options(&mut self, options: TagOptions) {
+        self.options = options;
+    }
 
-        let apikey = self.apikey.clone();
-        let host = self.host.clone();
-        let port = self.port;
+    pub fn options(&self) -> &TagOptions {
+        &self.options
+    }
 
-        self.internal_custom_dicts
-            .entry(name.to_string())
-            .or_insert_with(|| {
-                let mut cd = CustomDict::new(name);
-                cd.set_connection(&apikey, &host, port);
-                cd
-            })
+    /// 이름으로 사용자 사전을 가져옵니다. 처음 부르면 세션의 연결을 쓰는 사전을 새로 만듭니다.
+    ///
+    /// # Errors
+    ///
+    /// 이름이 비어 있으면 `BareunError::InvalidCustomDictName`을 돌려줍니다.
+    pub fn custom_dict(&mut self, name: &str) -> Result<&mut CustomDict> {
+        match self.internal_custom_dicts.entry(name.to_string()) {
+            Entry::Occupied(e) => Ok(e.into_mut()),
+            Entry::Vacant(e) => {
+                let dict = match &self.session {
+                    Some(session) => session.custom_dict(name)?,
+                    None => CustomDict::new(name)?,
+                };
+                Ok(e.insert(dict))
+            }
+        }
     }
 
     pub async fn tag(
-        &mut self,
+        &self,
         phrase: &str,
         auto_split: bool,
         auto_spacing: bool,
         auto_jointing: bool,
     ) -> Result<Tagged> {
+        self.tag_with(
+            phrase,
+            auto_split,
+            auto_spacing,
+            auto_jointing,
+            &CallOptions::default(),
+        )
+        .await
+    }
+
+    /// 호출 옵션(제한 시간 등)을 지정해 형태소 분석을 수행한다.
+    ///
+    /// Args:
+    ///     phrase: 분석할 문장
+    ///     options: 이 호출에만 적용할 옵션. 지정하지 않은 값은 클라이언트 설정을 따른다.
+    pub async fn tag_with(
+        &self,
+        phrase: &str,
+        auto_split: bool,
+        auto_spacing: bool,
+        auto_jointing: bool,
+        options: &CallOptions,
+    ) -> Result<Tagged> {
+        let opts = TagOptions {
+            auto_split,
+            auto_spacing,
+            auto_jointing,
+            timeout: options.timeout,
+            encoding: options.encoding,
+            ..TagOptions::default()
+        };
+        self.tag_with_options(phrase, &opts).await
+    }
+
+    /// 옵션을 지정해 형태소 분석을 수행한다.
+    ///
+    /// Args:
+    ///     phrase: 분석할 문장
+    ///     opts: 이 호출에만 적용할 옵션. `Tagger`의 기본 옵션은 쓰지 않는다.
+    pub async fn tag_with_options(&self, phrase: &str, opts: &TagOptions) -> Result<Tagged> {
+        let encoding = opts.encoding.unwrap_or(self.encoding);
         if phrase.is_empty() {
             eprintln!("OOPS, no sentences.");
-            return Ok(Tagged::new(
-                "".to_string(),
-                AnalyzeSyntaxResponse::default(),
-            ));
+            return Ok(
+                Tagged::new("".to_string(), AnalyzeSyntaxResponse::default())
+                    .with_encoding(encoding),
+            );
         }
 
+        let req = analyze_syntax_request(
+            phrase,
+            &self.custom_dicts_for(opts).await?,
+            opts.auto_split,
+            opts.auto_spacing,
+            opts.auto_jointing,
+            encoding,
+        );
         let res = self
-            .client
-            .analyze_syntax(
-                phrase,
-                &self.custom_dicts,
-                auto_split,
-                auto_spacing,
-                auto_jointing,
-            )
+            .backend
+            .analyze_syntax(req, &opts.call_options())
             .await?;
 
-        Ok(Tagged::new(phrase.to_string(), res))
+        Ok(Tagged::new(phrase.to_string(), res).with_encoding(encoding))
+    }
+
+    /// 요청에 담을 사용자 사전 목록. `validate_custom_dicts`면 서버에 있는지 확인한다.
+    async fn custom_dicts_for(&self, opts: &TagOptions) -> Result<Vec<String>> {
+        let names = opts.resolve_custom_dicts(&self.custom_dicts);
+        if opts.validate_custom_dicts {
+            self.check_custom_dicts_with(&names, &opts.call_options())
+                .await?;
+        }
+        Ok(names)
+    }
+
+    /// 사용자 사전이 모두 서버에 있는지 확인한다.
+    ///
+    /// # Errors
+    ///
+    /// 없는 사전이 있으면 `BareunError::UnknownCustomDicts`를 돌려준다. `with_backend`로 만들어
+    /// 서버 연결이 없으면 `BareunError::InvalidArgument`를 돌려준다.
+    pub async fn check_custom_dicts(&self, names: &[String]) -> Result<()> {
+        self.check_custom_dicts_with(names, &CallOptions::default())
+            .await
+    }
+
+    /// 호출 옵션(제한 시간 등)을 지정해 사용자 사전이 모두 서버에 있는지 확인한다.
+    pub async fn check_custom_dicts_with(
+        &self,
+        names: &[String],
+        options: &CallOptions,
+    ) -> Result<()> {
+        match &self.session {
+            Some(session) => {
+                session
+                    .custom_dict_client()
+                    .check_exists_with(names, options)
+                    .await
+            }
+            None if names.is_empty() => Ok(()),
+            None => Err(BareunError::invalid_argument(
+                "custom dictionaries can only be checked with a server connection",
+            )),
+        }
     }
 
     pub async fn tags(
-        &mut self,
+        &self,
         phrase: &[String],
         auto_split: bool,
         auto_spacing: bool,
         auto_jointing: bool,
     ) -> Result<Tagged> {
+        self.tags_with(
+            phrase,
+            auto_split,
+            auto_spacing,
+            auto_jointing,
+            &CallOptions::default(),
+        )
+        .await
+    }
+
+    /// 호출 옵션을 지정해 여러 문장을 한 번에 분석한다.
+    pub async fn tags_with(
+        &self,
+        phrase: &[String],
+        auto_split: bool,
+        auto_spacing: bool,
+        auto_jointing: bool,
+        options: &CallOptions,
+    ) -> Result<Tagged> {
+        let opts = TagOptions {
+            auto_split,
+            auto_spacing,
+            auto_jointing,
+            timeout: options.timeout,
+            encoding: options.encoding,
+            ..TagOptions::default()
+        };
+        self.tags_with_options(phrase, &opts).await
+    }
+
+    /// 옵션을 지정해 여러 문장을 한 번에 분석한다.
+    pub async fn tags_with_options(&self, phrase: &[String], opts: &TagOptions) -> Result<Tagged> {
+        let encoding = opts.encoding.unwrap_or(self.encoding);
         if phrase.is_empty() {
             eprintln!("OOPS, no sentences.");
-            return Ok(Tagged::new(
-                "".to_string(),
-                AnalyzeSyntaxResponse::default(),
-            ));
+            return Ok(
+                Tagged::new("".to_string(), AnalyzeSyntaxResponse::default())
+                    .with_encoding(encoding),
+            );
         }
 
         let p = phrase.join("\n");
+        let req = analyze_syntax_request(
+            &p,
+            &self.custom_dicts_for(opts).await?,
+            opts.auto_split,
+            opts.auto_spacing,
+            opts.auto_jointing,
+            encoding,
+        );
         let res = self
-            .client
-            .analyze_syntax(
-                &p,
-                &self.custom_dicts,
-                auto_split,
-                auto_spacing,
-                auto_jointing,
-            )
+            .backend
+            .analyze_syntax(req, &opts.call_options())
             .await?;
 
-        Ok(Tagged::new(p, res))
+        Ok(Tagged::new(p, res).with_encoding(encoding))
     }
 
     pub async fn taglist(
-        &mut self,
+        &self,
+        phrase: &[String],
+        auto_spacing: bool,
+        auto_jointing: bool,
+    ) -> Result<Tagged> {
+        self.taglist_with(phrase, auto_spacing, auto_jointing, &CallOptions::default())
+            .await
+    }
+
+    /// 호출 옵션을 지정해 문장 목록을 입력 단위 그대로 분석한다.
+    pub async fn taglist_with(
+        &self,
         phrase: &[String],
         auto_spacing: bool,
         auto_jointing: bool,
+        options: &CallOptions,
+    ) -> Result<Tagged> {
+        let opts = TagOptions {
+            auto_spacing,
+            auto_jointing,
+            timeout: options.timeout,
+            encoding: options.encoding,
+            ..TagOptions::default()
+        };
+        self.taglist_with_options(phrase, &opts).await
+    }
+
+    /// 옵션을 지정해 문장 목록을 입력 단위 그대로 분석한다. `auto_split`은 쓰지 않는다.
+    pub async fn taglist_with_options(
+        &self,
+        phrase: &[String],
+        opts: &TagOptions,
     ) -> Result<Tagged> {
+        let encoding = opts.encoding.unwrap_or(self.encoding);
         if phrase.is_empty() {
             eprintln!("OOPS, no sentences.");
-            return Ok(Tagged::new(
-                "".to_string(),
-                AnalyzeSyntaxResponse::default(),
-            ));
+            return Ok(
+                Tagged::new("".to_string(), AnalyzeSyntaxResponse::default())
+                    .with_encoding(encoding),
+            );
         }
 
+        let req = analyze_syntax_list_request(
+            phrase,
+            &self.custom_dicts_for(opts).await?,
+            opts.auto_spacing,
+            opts.auto_jointing,
+            encoding,
+        );
         let res = self
-            .client
-            .analyze_syntax_list(phrase, &self.custom_dicts, auto_spacing, auto_jointing)
+            .backend
+            .analyze_syntax_list(req, &opts.call_options())
             .await?;
 
         Ok(Tagged::new(
@@ -327,20 +1011,34 @@ impl Tagger {
                 language: res.language,
                 tokens_count: res.tokens_count,
             },
-        ))
+        )
+        .with_encoding(encoding))
     }
 
+    /// `Tagger`의 기본 옵션(`set_options`)으로 분석해 형태소와 품사 태그의 목록을 만듭니다.
     pub async fn pos(
-        &mut self,
+        &self,
+        phrase: &str,
+        flatten: bool,
+        join: bool,
+        detail: bool,
+    ) -> Result<Vec<Vec<String>>> {
+        self.pos_with_options(phrase, flatten, join, detail, &self.options)
+            .await
+    }
+
+    /// 옵션을 지정해 분석하고 형태소와 품사 태그의 목록을 만듭니다.
+    pub async fn pos_with_options(
+        &self,
         phrase: &str,
         flatten: bool,
         join: bool,
         detail: bool,
+        opts: &TagOptions,
     ) -> Result<Vec<Vec<String>>> {
-        Ok(self
-            .tag(phrase, false, true, false)
+        self.tag_with_options(phrase, opts)
             .await?
-            .pos(flatten, join, detail))
+            .pos(flatten, join, detail)
     }
 
     /// 문장을 분석하여 형태소를 추출합니다.
@@ -352,8 +1050,17 @@ impl Tagger {
     /// # Returns
     ///
     /// 분석된 모든 형태소의 벡터
-    pub async fn morphs(&mut self, phrase: &str) -> Result<Vec<String>> {
-        Ok(self.tag(phrase, false, true, false).await?.morphs())
+    pub async fn morphs(&self, phrase: &str) -> Result<Vec<String>> {
+        self.morphs_with_options(phrase, &self.options).await
+    }
+
+    /// 옵션을 지정해 문장을 분석하고 형태소를 추출합니다.
+    pub async fn morphs_with_options(
+        &self,
+        phrase: &str,
+        opts: &TagOptions,
+    ) -> Result<Vec<String>> {
+        Ok(self.tag_with_options(phrase, opts).await?.morphs())
     }
 
     /// 문장을 분석하여 명사를 추출합니다.
@@ -365,8 +1072,13 @@ impl Tagger {
     /// # Returns
     ///
     /// 분석된 모든 명사의 벡터
-    pub async fn nouns(&mut self, phrase: &str) -> Result<Vec<String>> {
-        Ok(self.tag(phrase, false, true, false).await?.nouns())
+    pub async fn nouns(&self, phrase: &str) -> Result<Vec<String>> {
+        self.nouns_with_options(phrase, &self.options).await
+    }
+
+    /// 옵션을 지정해 문장을 분석하고 명사를 추출합니다.
+    pub async fn nouns_with_options(&self, phrase: &str, opts: &TagOptions) -> Result<Vec<String>> {
+        Ok(self.tag_with_options(phrase, opts).await?.nouns())
     }
 
     /// 문장을 분석하여 동사를 추출합니다.
@@ -378,7 +1090,12 @@ impl Tagger {
     /// # Returns
     ///
     /// 분석된 모든 동사의 벡터
-    pub async fn verbs(&mut self, phrase: &str) -> Result<Vec<String>> {
-        Ok(self.tag(phrase, false, true, false).await?.verbs())
+    pub async fn verbs(&self, phrase: &str) -> Result<Vec<String>> {
+        self.verbs_with_options(phrase, &self.options).await
+    }
+
+    /// 옵션을 지정해 문장을 분석하고 동사를 추출합니다.
+    pub async fn verbs_with_options(&self, phrase: &str, opts: &TagOptions) -> Result<Vec<String>> {
+        Ok(self.tag_with_options(phrase, opts).await?.verbs())
     }
 }
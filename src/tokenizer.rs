@@ -1,6 +1,12 @@
-use crate::bareun::{Segment, SegmentSentence, TokenizeResponse};
-use crate::error::Result;
-use crate::lang_service_client::BareunLanguageServiceClient;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::backend::LanguageBackend;
+use crate::bareun::{EncodingType, Segment, SegmentSentence, TokenizeResponse};
+use crate::config::{BareunClientConfig, CallOptions};
+use crate::error::{BareunError, Result};
+use crate::lang_service_client::tokenize_request;
+use crate::session::BareunSession;
 pub enum SegResult {
     Flat(Vec<String>),
     Nested(Vec<Vec<String>>),
@@ -12,6 +18,8 @@ pub struct Tokenized {
     */
     pub phrase: String,
     pub r: TokenizeResponse,
+    /// 응답의 위치가 쓰는 단위
+    pub encoding: EncodingType,
 }
 impl Tokenized {
     /**
@@ -20,7 +28,24 @@ impl Tokenized {
     :param res:
     */
     pub fn new(phrase: String, res: TokenizeResponse) -> Self {
-        Tokenized { phrase, r: res }
+        Tokenized {
+            phrase,
+            r: res,
+            encoding: EncodingType::Utf32,
+        }
+    }
+    /**
+    Set the encoding the offsets of the response are in.
+    */
+    pub fn with_encoding(mut self, encoding: EncodingType) -> Self {
+        self.encoding = encoding;
+        self
+    }
+    /**
+    The encoding the offsets of the response are in.
+    */
+    pub fn encoding(&self) -> EncodingType {
+        self.encoding
     }
     /**
     Get the original phrase that was tokenized.
@@ -41,9 +66,14 @@ impl Tokenized {
         self.r.sentences.to_vec()
     }
 
-    fn _segment(m: &Segment, join: bool, detail: bool) -> String {
-        let content = m.text.clone().unwrap().content;
-        if join {
+    fn _segment(m: &Segment, join: bool, detail: bool) -> Result<String> {
+        let content = m
+            .text
+            .as_ref()
+            .ok_or_else(|| BareunError::MalformedResponse("segment without text".to_string()))?
+            .content
+            .clone();
+        Ok(if join {
             format!("{}/{}", content, m.hint)
         } else {
             if detail {
@@ -51,16 +81,17 @@ impl Tokenized {
             } else {
                 content
             }
-        }
+        })
     }
     /**
     분절의 결과를 튜플 형태로 반환한다.
     :param flatten : If False, returns original morphs.
     :param join    : If True, returns joined sets of morph and tag.
     :param detail  : if True, returns everything of morph result
+    :raises BareunError::MalformedResponse: 서버 응답에 text가 없는 segment가 있는 경우
     */
-    pub fn seg(&self, flatten: bool, join: bool, detail: bool) -> SegResult {
-        if flatten {
+    pub fn seg(&self, flatten: bool, join: bool, detail: bool) -> Result<SegResult> {
+        Ok(if flatten {
             SegResult::Flat(
                 self.r
                     .sentences
@@ -73,7 +104,7 @@ impl Tokenized {
                                 .map(|m| Tokenized::_segment(m, join, detail))
                         })
                     })
-                    .collect(),
+                    .collect::<Result<_>>()?,
             )
         } else {
             SegResult::Nested(
@@ -92,9 +123,9 @@ impl Tokenized {
                             })
                             .collect()
                     })
-                    .collect(),
+                    .collect::<Result<_>>()?,
             )
-        }
+        })
     }
     /**문장의 모든 segment들을 반환한다. */
     pub fn segments(&self) -> Vec<String> {
@@ -237,7 +268,7 @@ impl Tokenized {
 ///
 /// # Examples
 ///
-/// ```rust
+/// ```rust,ignore
 /// use bareun_rs::Tokenizer;
 ///
 /// #[tokio::main]
@@ -254,18 +285,71 @@ impl Tokenized {
 ///     Ok(())
 /// }
 /// ```
+#[derive(Clone)]
 pub struct Tokenizer {
-    pub client: BareunLanguageServiceClient,
+    backend: Arc<dyn LanguageBackend>,
+    encoding: EncodingType,
 }
 impl Tokenizer {
     pub async fn new(apikey: &str, host: &str, port: Option<u16>) -> Result<Self> {
-        if apikey.is_empty() {
-            return Err(crate::error::BareunError::MissingApiKey);
+        Self::from_config(BareunClientConfig::new(apikey, host, port)).await
+    }
+
+    /// 설정 객체로 Tokenizer를 만든다.
+    pub async fn from_config(config: BareunClientConfig) -> Result<Self> {
+        let session = BareunSession::connect(config).await?;
+        Ok(Self::from_session(&session))
+    }
+
+    /// 서버에 연결하지 않고 Tokenizer를 만든다. 연결 오류는 첫 요청에서 드러난다.
+    pub fn connect_lazy(config: BareunClientConfig) -> Result<Self> {
+        let session = BareunSession::connect_lazy(config)?;
+        Ok(Self::from_session(&session))
+    }
+
+    /// 서버가 요청을 받을 수 있는지 한 번 확인한다.
+    pub async fn ready(&self) -> Result<()> {
+        self.backend.ready().await
+    }
+
+    /// 서버가 준비될 때까지 최대 `timeout` 동안 기다린다.
+    pub async fn wait_ready(&self, timeout: Duration) -> Result<()> {
+        self.backend.wait_ready(timeout).await
+    }
+
+    /// 세션의 연결을 함께 사용하는 Tokenizer를 만든다.
+    pub fn from_session(session: &BareunSession) -> Self {
+        let mut tokenizer = Self::with_backend(session.language_client());
+        tokenizer.encoding = session.config().encoding;
+        tokenizer
+    }
+
+    /// 지정한 백엔드로 요청을 보내는 Tokenizer를 만든다.
+    pub fn with_backend(backend: impl LanguageBackend + 'static) -> Self {
+        Self::with_shared_backend(Arc::new(backend))
+    }
+
+    /// 다른 객체와 함께 쓰는 백엔드로 Tokenizer를 만든다.
+    pub fn with_shared_backend(backend: Arc<dyn LanguageBackend>) -> Self {
+        Tokenizer {
+            backend,
+            encoding: EncodingType::Utf32,
         }
+    }
 
-        let client = BareunLanguageServiceClient::new(apikey, host, port).await?;
+    /// 요청을 보내는 백엔드
+    pub fn backend(&self) -> &Arc<dyn LanguageBackend> {
+        &self.backend
+    }
+
+    /// 서버가 돌려줄 위치의 기본 단위를 바꾼다. 호출마다 `CallOptions::encoding`으로 바꿀 수도 있다.
+    pub fn set_encoding(&mut self, encoding: EncodingType) {
+        self.encoding = encoding;
+    }
 
-        Ok(Tokenizer { client })
+    /// 서버가 돌려줄 위치의 기본 단위
+    pub fn encoding(&self) -> EncodingType {
+        self.encoding
     }
 
     /// 토크나이즈 요청을 수행한다.
@@ -274,35 +358,64 @@ impl Tokenizer {
     ///     phrase: 분석할 문장
     ///     auto_split: proto상 deprecated. 서버는 '\n' 기준 문장 분리를 기본으로 수행하므로
     ///                 이 값은 무시된다. 호환성을 위해 시그니처는 유지한다.
-    pub async fn tokenize(&mut self, phrase: &str, auto_split: bool) -> Result<Tokenized> {
+    pub async fn tokenize(&self, phrase: &str, auto_split: bool) -> Result<Tokenized> {
+        self.tokenize_with(phrase, auto_split, &CallOptions::default())
+            .await
+    }
+
+    /// 호출 옵션(제한 시간 등)을 지정해 토크나이즈를 수행한다.
+    pub async fn tokenize_with(
+        &self,
+        phrase: &str,
+        auto_split: bool,
+        options: &CallOptions,
+    ) -> Result<Tokenized> {
+        let encoding = options.encoding.unwrap_or(self.encoding);
         if phrase.is_empty() {
             eprintln!("OOPS, no sentences.");
-            return Ok(Tokenized::new(
-                String::default(),
-                TokenizeResponse::default(),
-            ));
+            return Ok(
+                Tokenized::new(String::default(), TokenizeResponse::default())
+                    .with_encoding(encoding),
+            );
         }
 
-        let res = self.client.tokenize(phrase, auto_split).await?;
-        Ok(Tokenized::new(phrase.to_string(), res))
+        let res = self
+            .backend
+            .tokenize(tokenize_request(phrase, auto_split, encoding), options)
+            .await?;
+        Ok(Tokenized::new(phrase.to_string(), res).with_encoding(encoding))
     }
     /**
     tag string array.
     :param phrase: array of string
     :return: Tagged result instance
     */
-    pub async fn tokenize_list(&mut self, phrase: &[String]) -> Result<Tokenized> {
+    pub async fn tokenize_list(&self, phrase: &[String]) -> Result<Tokenized> {
+        self.tokenize_list_with(phrase, &CallOptions::default())
+            .await
+    }
+
+    /// 호출 옵션(제한 시간 등)을 지정해 문장 목록을 토크나이즈한다.
+    pub async fn tokenize_list_with(
+        &self,
+        phrase: &[String],
+        options: &CallOptions,
+    ) -> Result<Tokenized> {
+        let encoding = options.encoding.unwrap_or(self.encoding);
         if phrase.is_empty() {
             eprintln!("OOPS, no sentences.");
-            return Ok(Tokenized::new(
-                String::default(),
-                TokenizeResponse::default(),
-            ));
+            return Ok(
+                Tokenized::new(String::default(), TokenizeResponse::default())
+                    .with_encoding(encoding),
+            );
         }
 
         let p = phrase.join("\n");
-        let res = self.client.tokenize(&p, false).await?;
-        Ok(Tokenized::new(p, res))
+        let res = self
+            .backend
+            .tokenize(tokenize_request(&p, false, encoding), options)
+            .await?;
+        Ok(Tokenized::new(p, res).with_encoding(encoding))
     }
     /**
     분절 하기,
@@ -312,30 +425,29 @@ impl Tokenizer {
     :param detail  : if True, returns every things of morph result
     */
     pub async fn seg(
-        &mut self,
+        &self,
         phrase: &str,
         flatten: bool,
         join: bool,
         detail: bool,
     ) -> Result<SegResult> {
-        Ok(self
-            .tokenize(phrase, false)
+        self.tokenize(phrase, false)
             .await?
-            .seg(flatten, join, detail))
+            .seg(flatten, join, detail)
     }
 
     /**문장을 분절하여 어절 내부의 기본 단위로 만들어 낸다.*/
-    pub async fn segments(&mut self, phrase: &str) -> Result<Vec<String>> {
+    pub async fn segments(&self, phrase: &str) -> Result<Vec<String>> {
         Ok(self.tokenize(phrase, false).await?.segments())
     }
 
     /**문장을 분절하여 어절 내부의 기본 단위로 만들어 내고 체언을 뽑아낸다.*/
-    pub async fn nouns(&mut self, phrase: &str) -> Result<Vec<String>> {
+    pub async fn nouns(&self, phrase: &str) -> Result<Vec<String>> {
         Ok(self.tokenize(phrase, false).await?.nouns())
     }
 
     /**문장을 분절하여 어절 내부의 기본 단위로 만들어 내고 용언을 뽑아낸다.*/
-    pub async fn verbs(&mut self, phrase: &str) -> Result<Vec<String>> {
+    pub async fn verbs(&self, phrase: &str) -> Result<Vec<String>> {
         Ok(self.tokenize(phrase, false).await?.verbs())
     }
 }
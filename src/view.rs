@@ -0,0 +1,274 @@
+use std::borrow::Cow;
+
+use crate::bareun::morpheme::OutOfVocab;
+use crate::bareun::{Morpheme, Sentence, TextSpan, Token};
+use crate::conjugation::surface_form;
+use crate::error::Result;
+use crate::hangul;
+use crate::lemma::{LemmaOptions, lemma_or_rules};
+use crate::model::{OovKind, Span, Tag};
+use crate::offsets::{OffsetIndex, TextRange};
+
+fn content(text: &Option<TextSpan>) -> &str {
+    text.as_ref().map_or("", |t| t.content.as_str())
+}
+
+fn span(text: &Option<TextSpan>) -> Span {
+    text.as_ref().map_or(Span::default(), |t| {
+        Span::from_offsets(t.begin_offset, t.length)
+    })
+}
+
+/// 분석 결과의 문장을 복사하지 않고 빌려 보는 뷰
+///
+/// `Tagged::sentences_iter`가 만든다. 문장의 순서(`index`)를 함께 가지고 있다.
+#[derive(Clone, Copy, Debug)]
+pub struct SentenceView<'a> {
+    index: usize,
+    sentence: &'a Sentence,
+}
+
+impl<'a> SentenceView<'a> {
+    pub(crate) fn new(index: usize, sentence: &'a Sentence) -> Self {
+        SentenceView { index, sentence }
+    }
+
+    /// 응답 안에서 문장의 순서
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// proto 문장
+    pub fn raw(&self) -> &'a Sentence {
+        self.sentence
+    }
+
+    /// 문장 내용. 서버가 text를 주지 않았으면 빈 문자열이다.
+    pub fn text(&self) -> &'a str {
+        content(&self.sentence.text)
+    }
+
+    /// 위치. 서버가 위치를 주지 않았으면(-1) 빈 범위다.
+    pub fn span(&self) -> Span {
+        span(&self.sentence.text)
+    }
+
+    /// 원문에서의 위치를 코드 포인트, UTF-8, UTF-16 단위로 돌려준다.
+    pub fn range(&self, index: &OffsetIndex) -> Result<TextRange> {
+        index.range(self.span())
+    }
+
+    /// 문장의 어절
+    pub fn eojeols(self) -> impl Iterator<Item = EojeolView<'a>> + 'a {
+        let sentence_index = self.index;
+        self.sentence
+            .tokens
+            .iter()
+            .enumerate()
+            .map(move |(index, token)| EojeolView::new(sentence_index, index, token))
+    }
+
+    /// 문장의 형태소
+    pub fn morphemes(self) -> impl Iterator<Item = MorphemeView<'a>> + 'a {
+        self.eojeols().flat_map(|e| e.morphemes())
+    }
+}
+
+/// 분석 결과의 어절(토큰)을 복사하지 않고 빌려 보는 뷰
+#[derive(Clone, Copy, Debug)]
+pub struct EojeolView<'a> {
+    sentence_index: usize,
+    index: usize,
+    token: &'a Token,
+}
+
+impl<'a> EojeolView<'a> {
+    pub(crate) fn new(sentence_index: usize, index: usize, token: &'a Token) -> Self {
+        EojeolView {
+            sentence_index,
+            index,
+            token,
+        }
+    }
+
+    /// 어절이 속한 문장의 순서
+    pub fn sentence_index(&self) -> usize {
+        self.sentence_index
+    }
+
+    /// 문장 안에서 어절의 순서
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// proto 토큰
+    pub fn raw(&self) -> &'a Token {
+        self.token
+    }
+
+    /// 어절 내용. 서버가 text를 주지 않았으면 빈 문자열이다.
+    pub fn text(&self) -> &'a str {
+        content(&self.token.text)
+    }
+
+    /// 위치. 서버가 위치를 주지 않았으면(-1) 빈 범위다.
+    pub fn span(&self) -> Span {
+        span(&self.token.text)
+    }
+
+    /// 원문에서의 위치를 코드 포인트, UTF-8, UTF-16 단위로 돌려준다.
+    pub fn range(&self, index: &OffsetIndex) -> Result<TextRange> {
+        index.range(self.span())
+    }
+
+    /// `형태소/태그+형태소/태그` 형식의 문자열
+    pub fn tagged(&self) -> &'a str {
+        &self.token.tagged
+    }
+
+    /// 자동 띄어쓰기, 붙여쓰기로 바뀐 어절 내용. 바뀌지 않았으면 `None`
+    pub fn modified(&self) -> Option<&'a str> {
+        Some(self.token.modified.as_str()).filter(|m| !m.is_empty())
+    }
+
+    /// 서버가 어절 내용을 바꿨는지 여부
+    pub fn is_modified(&self) -> bool {
+        self.modified().is_some()
+    }
+
+    /// 어절의 기본형(`먹었다` → `먹다`)
+    ///
+    /// 서버가 준 기본형을 돌려주고, 비어 있으면 형태소로 만든다(`lemmatize`).
+    /// 서버의 값 그대로는 `raw().lemma`에 있다.
+    pub fn lemma(&self) -> Cow<'a, str> {
+        self.lemma_with(&LemmaOptions::default())
+    }
+
+    /// `options`에 따라 파생 접미사나 지정사를 뺀 기본형
+    ///
+    /// 옵션이 빼라는 형태소가 어절에 있으면 서버의 기본형 대신 형태소로 만든다.
+    pub fn lemma_with(&self, options: &LemmaOptions) -> Cow<'a, str> {
+        let token = self.token;
+        let morphemes = token
+            .morphemes
+            .iter()
+            .map(|m| (content(&m.text), Tag::from_proto(m.tag)));
+        lemma_or_rules(&token.lemma, morphemes, options)
+    }
+
+    /// 형태소를 이어 만든 표면형. 서버의 형태소가 맞다면 `text()`와 같다.
+    pub fn surface_form(&self) -> String {
+        surface_form(self.morphemes().map(|m| (m.text(), m.tag())))
+    }
+
+    /// 어절의 형태소
+    pub fn morphemes(self) -> impl Iterator<Item = MorphemeView<'a>> + 'a {
+        let (sentence_index, token_index) = (self.sentence_index, self.index);
+        self.token
+            .morphemes
+            .iter()
+            .enumerate()
+            .map(move |(index, morpheme)| {
+                MorphemeView::new(sentence_index, token_index, index, morpheme)
+            })
+    }
+}
+
+/// 분석 결과의 형태소를 복사하지 않고 빌려 보는 뷰
+///
+/// 태그는 `model::Tag`로 돌려준다.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use bareun_rs::model::Tag;
+///
+/// let tagged = tagger.tag("나비가 난다", false, true, false).await?;
+/// let nouns: Vec<&str> = tagged
+///     .morphemes()
+///     .filter(|m| m.tag() == Tag::Nng)
+///     .map(|m| m.text())
+///     .collect();
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct MorphemeView<'a> {
+    sentence_index: usize,
+    token_index: usize,
+    index: usize,
+    morpheme: &'a Morpheme,
+}
+
+impl<'a> MorphemeView<'a> {
+    pub(crate) fn new(
+        sentence_index: usize,
+        token_index: usize,
+        index: usize,
+        morpheme: &'a Morpheme,
+    ) -> Self {
+        MorphemeView {
+            sentence_index,
+            token_index,
+            index,
+            morpheme,
+        }
+    }
+
+    /// 형태소가 속한 문장의 순서
+    pub fn sentence_index(&self) -> usize {
+        self.sentence_index
+    }
+
+    /// 형태소가 속한 어절의 문장 안 순서
+    pub fn token_index(&self) -> usize {
+        self.token_index
+    }
+
+    /// 어절 안에서 형태소의 순서
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// proto 형태소
+    pub fn raw(&self) -> &'a Morpheme {
+        self.morpheme
+    }
+
+    /// 형태소 내용. 서버가 text를 주지 않았으면 빈 문자열이다.
+    pub fn text(&self) -> &'a str {
+        content(&self.morpheme.text)
+    }
+
+    /// 위치. 서버가 위치를 주지 않았으면(-1) 빈 범위다.
+    pub fn span(&self) -> Span {
+        span(&self.morpheme.text)
+    }
+
+    /// 원문에서의 위치를 코드 포인트, UTF-8, UTF-16 단위로 돌려준다.
+    pub fn range(&self, index: &OffsetIndex) -> Result<TextRange> {
+        index.range(self.span())
+    }
+
+    pub fn tag(&self) -> Tag {
+        Tag::from_proto(self.morpheme.tag)
+    }
+
+    /// 자모로 시작하는 형태소인지(`ㄴ다`, `ㅂ니다`). 앞 음절의 받침으로 붙어 있어 원문과 다르다.
+    pub fn is_jamo_fragment(&self) -> bool {
+        hangul::is_jamo_fragment(self.text())
+    }
+
+    pub fn probability(&self) -> f32 {
+        self.morpheme.probability
+    }
+
+    pub fn oov(&self) -> OovKind {
+        OutOfVocab::try_from(self.morpheme.out_of_vocab)
+            .map(OovKind::from)
+            .unwrap_or_default()
+    }
+
+    /// 형태소를 찾은 사용자 사전의 이름
+    pub fn custom_dict(&self) -> Option<&'a str> {
+        self.morpheme.custom_dict_name.as_deref()
+    }
+}
@@ -0,0 +1,44 @@
+//! 여러 테스트 파일이 함께 쓰는 분석 결과 픽스처
+#![allow(dead_code)]
+
+use bareun_rs::Tagged;
+use bareun_rs::bareun::AnalyzeSyntaxResponse;
+use bareun_rs::mock::analyze_syntax_response;
+
+/// (어절, [(형태소, 품사 태그)])
+pub type Eojeol<'a> = (&'a str, &'a [(&'a str, &'a str)]);
+
+/// 한 문장짜리 분석 결과를 만든다.
+pub fn tagged(phrase: &str, eojeols: &[Eojeol]) -> Tagged {
+    Tagged::new(phrase.to_string(), analyze_syntax_response(phrase, eojeols))
+}
+
+/// 여러 문장의 분석 결과를 만든다. `phrase`를 줄바꿈으로 나눈 문장마다 `sentences`의 어절을 쓰며,
+/// 위치는 `phrase` 전체 기준으로 옮긴다.
+pub fn tagged_sentences(phrase: &str, sentences: &[&[Eojeol]]) -> Tagged {
+    let mut offset = 0;
+    let res = AnalyzeSyntaxResponse {
+        sentences: phrase
+            .split('\n')
+            .zip(sentences)
+            .map(|(text, eojeols)| {
+                let mut sentence = analyze_syntax_response(text, eojeols).sentences.remove(0);
+                let spans = sentence
+                    .text
+                    .iter_mut()
+                    .chain(sentence.tokens.iter_mut().flat_map(|t| {
+                        t.text
+                            .iter_mut()
+                            .chain(t.morphemes.iter_mut().flat_map(|m| m.text.iter_mut()))
+                    }));
+                for span in spans {
+                    span.begin_offset += offset;
+                }
+                offset += text.chars().count() as i32 + 1;
+                sentence
+            })
+            .collect(),
+        ..Default::default()
+    };
+    Tagged::new(phrase.to_string(), res)
+}
@@ -0,0 +1,258 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use bareun_rs::bareun::stream_correct_error_response::Res;
+    use bareun_rs::bareun::{
+        AnalyzeSyntaxListRequest, AnalyzeSyntaxListResponse, AnalyzeSyntaxRequest,
+        AnalyzeSyntaxResponse, CorrectErrorRequest, CorrectErrorResponse,
+        StreamCorrectErrorRequest, StreamCorrectErrorResponse, StreamFirstCorrectError,
+        TokenizeRequest, TokenizeResponse,
+    };
+    use bareun_rs::mock::{MockMethod, MockServer, analyze_syntax_response, tokenize_response};
+    use bareun_rs::{
+        BackendFuture, BareunError, BareunSession, CallOptions, Corrector, LanguageBackend,
+        RevisionBackend, RevisionStream, StreamRevisionEvent, Tagger, Tokenizer,
+        stream_from_messages,
+    };
+    use tokio_stream::StreamExt;
+    use tonic::Status;
+
+    const API_KEY: &str = "koba-ABCDEFG-1234567-LMNOPQR-7654321";
+
+    /// 받은 요청을 세고 고정된 응답을 돌려주는 가짜 백엔드
+    #[derive(Default)]
+    struct FakeLanguage {
+        calls: AtomicUsize,
+    }
+
+    impl LanguageBackend for FakeLanguage {
+        fn analyze_syntax<'a>(
+            &'a self,
+            request: AnalyzeSyntaxRequest,
+            _options: &'a CallOptions,
+        ) -> BackendFuture<'a, AnalyzeSyntaxResponse> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Box::pin(async move {
+                assert_eq!(request.custom_dict_names, vec!["my"]);
+                let content = request.document.unwrap().content;
+                Ok(analyze_syntax_response(
+                    &content,
+                    &[("나비가", &[("나비", "NNG"), ("가", "JKS")])],
+                ))
+            })
+        }
+
+        fn analyze_syntax_list<'a>(
+            &'a self,
+            request: AnalyzeSyntaxListRequest,
+            _options: &'a CallOptions,
+        ) -> BackendFuture<'a, AnalyzeSyntaxListResponse> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Box::pin(async move {
+                let res = analyze_syntax_response(&request.sentences.join("\n"), &[]);
+                Ok(AnalyzeSyntaxListResponse {
+                    sentences: res.sentences,
+                    ..Default::default()
+                })
+            })
+        }
+
+        fn tokenize<'a>(
+            &'a self,
+            request: TokenizeRequest,
+            _options: &'a CallOptions,
+        ) -> BackendFuture<'a, TokenizeResponse> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Box::pin(async move {
+                let content = request.document.unwrap().content;
+                Ok(tokenize_response(
+                    &content,
+                    &[("나비가", &[("나비", "N"), ("가", "J")])],
+                ))
+            })
+        }
+    }
+
+    struct FakeRevision;
+
+    impl RevisionBackend for FakeRevision {
+        fn correct_error<'a>(
+            &'a self,
+            request: CorrectErrorRequest,
+            _options: &'a CallOptions,
+        ) -> BackendFuture<'a, CorrectErrorResponse> {
+            Box::pin(async move {
+                let origin = request.document.unwrap().content;
+                Ok(CorrectErrorResponse {
+                    revised: origin.replace("됬", "됐"),
+                    origin,
+                    ..Default::default()
+                })
+            })
+        }
+
+        fn stream_correct_error<'a>(
+            &'a self,
+            request: StreamCorrectErrorRequest,
+            _options: &'a CallOptions,
+        ) -> BackendFuture<'a, RevisionStream> {
+            Box::pin(async move {
+                let first = StreamCorrectErrorResponse {
+                    res: Some(Res::First(StreamFirstCorrectError {
+                        req_id: request.req_id,
+                        revised: "됐다".to_string(),
+                        ..Default::default()
+                    })),
+                };
+                Ok(stream_from_messages(
+                    vec![first],
+                    Some(Status::internal("broken")),
+                ))
+            })
+        }
+    }
+
+    /// 같은 문장의 분석 결과를 기억하는 데코레이터
+    struct Caching<B> {
+        inner: B,
+        hits: AtomicUsize,
+        cache: std::sync::Mutex<std::collections::HashMap<String, AnalyzeSyntaxResponse>>,
+    }
+
+    impl<B: LanguageBackend> LanguageBackend for Caching<B> {
+        fn analyze_syntax<'a>(
+            &'a self,
+            request: AnalyzeSyntaxRequest,
+            options: &'a CallOptions,
+        ) -> BackendFuture<'a, AnalyzeSyntaxResponse> {
+            Box::pin(async move {
+                let key = request.document.as_ref().unwrap().content.clone();
+                if let Some(res) = self.cache.lock().unwrap().get(&key) {
+                    self.hits.fetch_add(1, Ordering::SeqCst);
+                    return Ok(res.clone());
+                }
+                let res = self.inner.analyze_syntax(request, options).await?;
+                self.cache.lock().unwrap().insert(key, res.clone());
+                Ok(res)
+            })
+        }
+
+        fn analyze_syntax_list<'a>(
+            &'a self,
+            request: AnalyzeSyntaxListRequest,
+            options: &'a CallOptions,
+        ) -> BackendFuture<'a, AnalyzeSyntaxListResponse> {
+            self.inner.analyze_syntax_list(request, options)
+        }
+
+        fn tokenize<'a>(
+            &'a self,
+            request: TokenizeRequest,
+            options: &'a CallOptions,
+        ) -> BackendFuture<'a, TokenizeResponse> {
+            self.inner.tokenize(request, options)
+        }
+
+        fn ready(&self) -> BackendFuture<'_, ()> {
+            self.inner.ready()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_tagger_with_fake_backend() {
+        let backend = Arc::new(FakeLanguage::default());
+        let mut tagger = Tagger::with_shared_backend(backend.clone(), vec!["my".to_string()]);
+        tagger.ready().await.unwrap();
+
+        assert_eq!(tagger.morphs("나비가").await.unwrap(), vec!["나비", "가"]);
+        assert_eq!(
+            tagger
+                .taglist(&["하나".to_string(), "둘".to_string()], true, false)
+                .await
+                .unwrap()
+                .phrase(),
+            "하나\n둘"
+        );
+        assert_eq!(backend.calls.load(Ordering::SeqCst), 2);
+
+        // 서버 연결이 없으면 사용자 사전도 연결되지 않은 채로 만든다.
+        let dict = tagger.custom_dict("my").unwrap();
+        assert_eq!(dict.domain, "my");
+        assert!(dict.update().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_tokenizer_with_fake_backend() {
+        let tokenizer = Tokenizer::with_backend(FakeLanguage::default());
+        assert_eq!(
+            tokenizer.segments("나비가").await.unwrap(),
+            vec!["나비", "가"]
+        );
+        assert_eq!(tokenizer.nouns("나비가").await.unwrap(), vec!["나비"]);
+    }
+
+    #[tokio::test]
+    async fn test_corrector_with_fake_backend() {
+        let corrector = Corrector::with_backend(FakeRevision);
+        let res = corrector.correct_error("됬다", &[], None).await.unwrap();
+        assert_eq!(res.revised, "됐다");
+
+        let mut events = Vec::new();
+        let e = corrector
+            .stream_correct_error_with("됬다", &[], None, 3, |event| {
+                events.push(event);
+                true
+            })
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            events.as_slice(),
+            [StreamRevisionEvent::First(first)] if first.req_id == 3
+        ));
+        assert!(matches!(e, BareunError::GrpcError(ref s) if s.message == "broken"));
+        assert_eq!(
+            e.method(),
+            Some("bareun.RevisionService/StreamCorrectError")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stream_from_messages_maps_status() {
+        let mut stream = stream_from_messages(Vec::new(), Some(Status::unavailable("down")));
+        let e = stream.next().await.unwrap().unwrap_err();
+        assert!(matches!(e, BareunError::ServerUnavailable { .. }));
+        assert!(e.is_retryable());
+
+        let mut stream = stream_from_messages(Vec::new(), Some(Status::permission_denied("no")));
+        let e = stream.next().await.unwrap().unwrap_err();
+        assert!(matches!(e, BareunError::PermissionDenied { ref apikey, .. } if apikey == "****"));
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_decorating_grpc_backend() {
+        let server = MockServer::start().await.unwrap();
+        server.on_analyze_syntax(
+            "나비가",
+            analyze_syntax_response("나비가", &[("나비가", &[("나비", "NNG"), ("가", "JKS")])]),
+        );
+        let session = BareunSession::connect(server.config(API_KEY))
+            .await
+            .unwrap();
+        let caching = Arc::new(Caching {
+            inner: session.language_client(),
+            hits: AtomicUsize::new(0),
+            cache: Default::default(),
+        });
+        let tagger = Tagger::with_shared_backend(caching.clone(), vec![]);
+        tagger.ready().await.unwrap();
+
+        for _ in 0..3 {
+            assert_eq!(tagger.morphs("나비가").await.unwrap(), vec!["나비", "가"]);
+        }
+        assert_eq!(caching.hits.load(Ordering::SeqCst), 2);
+        assert_eq!(server.requests_for(MockMethod::AnalyzeSyntax).len(), 1);
+    }
+}
@@ -0,0 +1,295 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use bareun_rs::bareun::language_service_server::{LanguageService, LanguageServiceServer};
+    use bareun_rs::bareun::revision_service_server::{RevisionService, RevisionServiceServer};
+    use bareun_rs::bareun::{
+        AnalyzeSyntaxListRequest, AnalyzeSyntaxListResponse, AnalyzeSyntaxRequest,
+        AnalyzeSyntaxResponse, CorrectErrorRequest, CorrectErrorResponse,
+        StreamCorrectErrorRequest, StreamCorrectErrorResponse, TokenizeRequest, TokenizeResponse,
+    };
+    use bareun_rs::{
+        BalanceStrategy, BareunClientConfig, BareunError, BareunSession, Corrector, LoadBalancing,
+        RetryPolicy, Tagger,
+    };
+    use tokio_stream::Stream;
+    use tonic::transport::Server;
+    use tonic::transport::server::TcpIncoming;
+    use tonic::{Request, Response, Status};
+
+    /// 받은 문장을 기록하는 서버. `down`이면 모든 요청에 Unavailable을 돌려주고,
+    /// "slow"라는 문장은 300ms 뒤에 응답한다. 스트리밍 교정은 항상 Unavailable로 끝난다.
+    #[derive(Clone, Default)]
+    struct Backend {
+        seen: Arc<Mutex<Vec<String>>>,
+        down: Arc<AtomicBool>,
+    }
+
+    impl Backend {
+        async fn handle(&self, content: String) -> Result<(), Status> {
+            self.seen.lock().unwrap().push(content.clone());
+            if self.down.load(Ordering::SeqCst) {
+                return Err(Status::unavailable("container stopped"));
+            }
+            if content == "slow" {
+                tokio::time::sleep(Duration::from_millis(300)).await;
+            }
+            Ok(())
+        }
+
+        fn count(&self) -> usize {
+            self.seen.lock().unwrap().len()
+        }
+    }
+
+    #[tonic::async_trait]
+    impl LanguageService for Backend {
+        async fn analyze_syntax(
+            &self,
+            request: Request<AnalyzeSyntaxRequest>,
+        ) -> Result<Response<AnalyzeSyntaxResponse>, Status> {
+            let content = request.into_inner().document.unwrap_or_default().content;
+            self.handle(content).await?;
+            Ok(Response::new(AnalyzeSyntaxResponse::default()))
+        }
+
+        async fn analyze_syntax_list(
+            &self,
+            _request: Request<AnalyzeSyntaxListRequest>,
+        ) -> Result<Response<AnalyzeSyntaxListResponse>, Status> {
+            Err(Status::unimplemented("not used"))
+        }
+
+        async fn tokenize(
+            &self,
+            _request: Request<TokenizeRequest>,
+        ) -> Result<Response<TokenizeResponse>, Status> {
+            Err(Status::unimplemented("not used"))
+        }
+    }
+
+    type ResponseStream =
+        std::pin::Pin<Box<dyn Stream<Item = Result<StreamCorrectErrorResponse, Status>> + Send>>;
+
+    #[tonic::async_trait]
+    impl RevisionService for Backend {
+        async fn correct_error(
+            &self,
+            request: Request<CorrectErrorRequest>,
+        ) -> Result<Response<CorrectErrorResponse>, Status> {
+            let content = request.into_inner().document.unwrap_or_default().content;
+            self.handle(content).await?;
+            Ok(Response::new(CorrectErrorResponse::default()))
+        }
+
+        type StreamCorrectErrorStream = ResponseStream;
+
+        async fn stream_correct_error(
+            &self,
+            request: Request<StreamCorrectErrorRequest>,
+        ) -> Result<Response<Self::StreamCorrectErrorStream>, Status> {
+            let content = request.into_inner().document.unwrap_or_default().content;
+            self.handle(content).await?;
+            let stream = tokio_stream::iter(vec![Err(Status::unavailable("draining"))]);
+            Ok(Response::new(Box::pin(stream)))
+        }
+    }
+
+    async fn spawn_backend() -> (u16, Backend) {
+        let backend = Backend::default();
+        let incoming = TcpIncoming::bind("127.0.0.1:0".parse().unwrap()).unwrap();
+        let port = incoming.local_addr().unwrap().port();
+        tokio::spawn(
+            Server::builder()
+                .add_service(LanguageServiceServer::new(backend.clone()))
+                .add_service(RevisionServiceServer::new(backend.clone()))
+                .serve_with_incoming(incoming),
+        );
+        (port, backend)
+    }
+
+    fn config(ports: &[u16], balancing: LoadBalancing) -> BareunClientConfig {
+        let mut builder = BareunClientConfig::builder("appppppiiii");
+        for port in ports {
+            builder = builder.endpoint("127.0.0.1", *port);
+        }
+        builder
+            .load_balancing(balancing)
+            .retry_policy(RetryPolicy::default().initial_backoff(Duration::from_millis(10)))
+            .build()
+    }
+
+    #[test]
+    fn test_first_endpoint_becomes_host() {
+        let config = BareunClientConfig::builder("appppppiiii")
+            .endpoint("10.0.0.1", 5656)
+            .endpoint("10.0.0.2", 5657)
+            .endpoint("10.0.0.1", 5656)
+            .build();
+        assert_eq!(config.host, "10.0.0.1");
+        assert_eq!(config.port, 5656);
+        assert_eq!(
+            config.endpoints,
+            vec![
+                ("10.0.0.1".to_string(), 5656),
+                ("10.0.0.2".to_string(), 5657)
+            ]
+        );
+
+        let single = BareunClientConfig::new("appppppiiii", "localhost", None);
+        assert_eq!(single.endpoints, vec![("localhost".to_string(), 5656)]);
+    }
+
+    #[tokio::test]
+    async fn test_round_robin_spreads_requests() {
+        let (p1, b1) = spawn_backend().await;
+        let (p2, b2) = spawn_backend().await;
+        let (p3, b3) = spawn_backend().await;
+        let tagger = Tagger::from_config(config(&[p1, p2, p3], LoadBalancing::default()), vec![])
+            .await
+            .unwrap();
+
+        for _ in 0..6 {
+            tagger.tag("오늘은", false, true, false).await.unwrap();
+        }
+        assert_eq!((b1.count(), b2.count(), b3.count()), (2, 2, 2));
+    }
+
+    #[tokio::test]
+    async fn test_least_outstanding_avoids_busy_endpoint() {
+        let (p1, b1) = spawn_backend().await;
+        let (p2, b2) = spawn_backend().await;
+        let balancing = LoadBalancing::new(BalanceStrategy::LeastOutstanding);
+        let tagger = Tagger::from_config(config(&[p1, p2], balancing), vec![])
+            .await
+            .unwrap();
+
+        let slow = {
+            let tagger = tagger.clone();
+            tokio::spawn(async move { tagger.tag("slow", false, true, false).await })
+        };
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        for _ in 0..4 {
+            tagger.tag("fast", false, true, false).await.unwrap();
+        }
+        slow.await.unwrap().unwrap();
+
+        let busy = if b1.seen.lock().unwrap().contains(&"slow".to_string()) {
+            &b1
+        } else {
+            &b2
+        };
+        assert_eq!(busy.count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_unavailable_endpoint_is_ejected_and_readmitted() {
+        let (p1, b1) = spawn_backend().await;
+        let (p2, b2) = spawn_backend().await;
+        b1.down.store(true, Ordering::SeqCst);
+        let balancing = LoadBalancing::default().eject_cooldown(Duration::from_millis(300));
+        let tagger = Tagger::from_config(config(&[p1, p2], balancing), vec![])
+            .await
+            .unwrap();
+
+        for _ in 0..4 {
+            tagger.tag("오늘은", false, true, false).await.unwrap();
+        }
+        // 첫 요청에서 한 번 실패한 뒤로는 후보에서 빠진다.
+        assert_eq!(b1.count(), 1);
+        assert_eq!(b2.count(), 4);
+
+        b1.down.store(false, Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(350)).await;
+        for _ in 0..4 {
+            tagger.tag("오늘은", false, true, false).await.unwrap();
+        }
+        assert_eq!(b1.count(), 3);
+        assert_eq!(b2.count(), 6);
+    }
+
+    #[tokio::test]
+    async fn test_ejection_is_shared_across_services() {
+        let (p1, b1) = spawn_backend().await;
+        let (p2, b2) = spawn_backend().await;
+        b1.down.store(true, Ordering::SeqCst);
+        let session = BareunSession::connect(config(&[p1, p2], LoadBalancing::default()))
+            .await
+            .unwrap();
+
+        session
+            .tagger()
+            .tag("오늘은", false, true, false)
+            .await
+            .unwrap();
+        assert_eq!(b1.count(), 1);
+
+        // 교정 요청은 이미 빠진 서버로 가지 않는다.
+        for _ in 0..3 {
+            session
+                .corrector()
+                .correct_error("영수 도 줄기가", &[], None)
+                .await
+                .unwrap();
+        }
+        assert_eq!(b1.count(), 1);
+        assert_eq!(b2.count(), 4);
+
+        let status = session.pool().status();
+        assert!(status[0].ejected);
+        assert!(!status[1].ejected);
+    }
+
+    #[tokio::test]
+    async fn test_stream_error_names_picked_endpoint() {
+        let (p1, b1) = spawn_backend().await;
+        let (p2, b2) = spawn_backend().await;
+        let corrector = Corrector::from_config(config(&[p1, p2], LoadBalancing::default()))
+            .await
+            .unwrap();
+
+        corrector.correct_error("첫째", &[], None).await.unwrap();
+        let e = corrector
+            .stream_correct_error_with("둘째", &[], None, 1, |_| true)
+            .await
+            .unwrap_err();
+        assert_eq!((b1.count(), b2.count()), (1, 1));
+        // 설정의 첫 서버(p1)가 아니라 스트림을 연 서버(p2)를 알려준다.
+        match e {
+            BareunError::ServerUnavailable { host, port, .. } => {
+                assert_eq!((host.as_str(), port), ("127.0.0.1", p2));
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_connect_tolerates_dead_endpoint() {
+        let dead = {
+            let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+            listener.local_addr().unwrap().port()
+        };
+        let (port, backend) = spawn_backend().await;
+        let tagger = Tagger::from_config(config(&[dead, port], LoadBalancing::default()), vec![])
+            .await
+            .unwrap();
+
+        for _ in 0..3 {
+            tagger.tag("오늘은", false, true, false).await.unwrap();
+        }
+        assert_eq!(backend.count(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_connect_fails_when_all_endpoints_are_dead() {
+        let dead = {
+            let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+            listener.local_addr().unwrap().port()
+        };
+        let result = Tagger::from_config(config(&[dead], LoadBalancing::default()), vec![]).await;
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,305 @@
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    use bareun_rs::bareun::CorrectErrorResponse;
+    use bareun_rs::mock::{MockMethod, MockServer, analyze_syntax_response};
+    use bareun_rs::{
+        BareunClientConfig, BareunError, BareunSession, Cassette, CassetteMode, Corrector,
+        RevisionConfigBuilder, StreamRevisionEvent, Tagger, Tokenizer,
+    };
+    use tokio_stream::StreamExt;
+    use tonic::Status;
+
+    const API_KEY: &str = "koba-ABCDEFG-1234567-LMNOPQR-7654321";
+
+    fn cassette_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "bareun_rs_cassette_{}_{}.json",
+            std::process::id(),
+            name
+        ))
+    }
+
+    /// 서버가 없는 주소를 가리키는 재생용 설정
+    fn replay_config(path: &PathBuf) -> BareunClientConfig {
+        let dead = {
+            let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+            listener.local_addr().unwrap().port()
+        };
+        BareunClientConfig::builder(API_KEY)
+            .host("127.0.0.1")
+            .port(dead)
+            .cassette(Cassette::replay(path).unwrap())
+            .build()
+    }
+
+    fn record_config(server: &MockServer, cassette: &Cassette) -> BareunClientConfig {
+        BareunClientConfig::builder(API_KEY)
+            .host("127.0.0.1")
+            .port(server.port())
+            .cassette(cassette.clone())
+            .build()
+    }
+
+    #[tokio::test]
+    async fn test_record_and_replay_tagger() {
+        let path = cassette_path("tagger");
+        let cassette = Cassette::record(&path);
+        {
+            let server = MockServer::start().await.unwrap();
+            server.on_analyze_syntax(
+                "나비가 난다",
+                analyze_syntax_response(
+                    "나비가 난다",
+                    &[
+                        ("나비가", &[("나비", "NNG"), ("가", "JKS")]),
+                        ("난다", &[("날", "VV"), ("ㄴ다", "EF")]),
+                    ],
+                ),
+            );
+            let tagger = Tagger::from_config(record_config(&server, &cassette), vec![])
+                .await
+                .unwrap();
+            assert_eq!(
+                tagger.morphs("나비가 난다").await.unwrap(),
+                vec!["나비", "가", "날", "ㄴ다"]
+            );
+            let tokenizer = Tokenizer::from_config(record_config(&server, &cassette))
+                .await
+                .unwrap();
+            tokenizer.segments("나비가 난다").await.unwrap();
+        }
+
+        // 서버 없이 같은 결과를 돌려준다.
+        let tagger = Tagger::from_config(replay_config(&path), vec![])
+            .await
+            .unwrap();
+        tagger.ready().await.unwrap();
+        assert_eq!(
+            tagger.morphs("나비가 난다").await.unwrap(),
+            vec!["나비", "가", "날", "ㄴ다"]
+        );
+        let tokenizer = Tokenizer::from_config(replay_config(&path)).await.unwrap();
+        assert_eq!(
+            tokenizer.segments("나비가 난다").await.unwrap(),
+            vec!["나비가", "난다"]
+        );
+
+        let e = tagger.morphs("기록하지 않은 문장").await.unwrap_err();
+        assert!(matches!(
+            e,
+            BareunError::CassetteMiss { method, .. } if method == "bareun.LanguageService/AnalyzeSyntax"
+        ));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_match_on_custom_dicts_and_revision_config() {
+        let path = cassette_path("matching");
+        let cassette = Cassette::record(&path);
+        let config = RevisionConfigBuilder::new()
+            .disable_typo_correction(true)
+            .build();
+        {
+            let server = MockServer::start().await.unwrap();
+            let tagger =
+                Tagger::from_config(record_config(&server, &cassette), vec!["my".to_string()])
+                    .await
+                    .unwrap();
+            tagger.morphs("오늘은").await.unwrap();
+
+            let corrector = Corrector::from_config(record_config(&server, &cassette))
+                .await
+                .unwrap();
+            corrector
+                .correct_error("됬다", &[], Some(config))
+                .await
+                .unwrap();
+        }
+
+        let replayed = replay_config(&path);
+        let tagger = Tagger::from_config(replayed.clone(), vec!["my".to_string()])
+            .await
+            .unwrap();
+        assert!(tagger.morphs("오늘은").await.is_ok());
+        let other = Tagger::from_config(replayed.clone(), vec!["other".to_string()])
+            .await
+            .unwrap();
+        assert!(matches!(
+            other.morphs("오늘은").await,
+            Err(BareunError::CassetteMiss { .. })
+        ));
+
+        let corrector = Corrector::from_config(replayed).await.unwrap();
+        assert!(
+            corrector
+                .correct_error("됬다", &[], Some(config))
+                .await
+                .is_ok()
+        );
+        assert!(matches!(
+            corrector.correct_error("됬다", &[], None).await,
+            Err(BareunError::CassetteMiss { .. })
+        ));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_replay_in_recorded_order() {
+        let path = cassette_path("order");
+        let cassette = Cassette::record(&path);
+        {
+            let server = MockServer::start().await.unwrap();
+            let tagger = Tagger::from_config(record_config(&server, &cassette), vec![])
+                .await
+                .unwrap();
+            server.fail_times(
+                MockMethod::AnalyzeSyntax,
+                1,
+                Status::permission_denied("denied"),
+            );
+            assert!(tagger.morphs("오늘은").await.is_err());
+            assert!(tagger.morphs("오늘은").await.is_ok());
+        }
+
+        let cassette = Cassette::replay(&path).unwrap();
+        assert_eq!(cassette.mode(), CassetteMode::Replay);
+        assert_eq!(cassette.len(), 2);
+
+        let tagger = Tagger::from_config(replay_config(&path), vec![])
+            .await
+            .unwrap();
+        assert!(matches!(
+            tagger.morphs("오늘은").await,
+            Err(BareunError::PermissionDenied { .. })
+        ));
+        // 기록을 다 쓰면 마지막 응답을 반복한다.
+        for _ in 0..2 {
+            assert_eq!(tagger.morphs("오늘은").await.unwrap(), vec!["오늘은"]);
+        }
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_record_and_replay_stream() {
+        let path = cassette_path("stream");
+        let cassette = Cassette::record(&path);
+        {
+            let server = MockServer::start().await.unwrap();
+            server.on_correct_error(
+                "됬다",
+                CorrectErrorResponse {
+                    origin: "됬다".to_string(),
+                    revised: "됐다".to_string(),
+                    ..Default::default()
+                },
+            );
+            let corrector = Corrector::from_config(record_config(&server, &cassette))
+                .await
+                .unwrap();
+            let mut stream = corrector
+                .stream_correct_error("됬다", &[], None, 7)
+                .await
+                .unwrap();
+            assert!(stream.next().await.transpose().unwrap().is_some());
+            assert!(stream.next().await.transpose().unwrap().is_none());
+        }
+
+        let corrector = Corrector::from_config(replay_config(&path)).await.unwrap();
+        let mut stream = corrector
+            .stream_correct_error("됬다", &[], None, 7)
+            .await
+            .unwrap();
+        match stream
+            .next()
+            .await
+            .transpose()
+            .unwrap()
+            .and_then(StreamRevisionEvent::from_message)
+        {
+            Some(StreamRevisionEvent::First(first)) => {
+                assert_eq!(first.revised, "됐다");
+                assert_eq!(first.req_id, 7);
+            }
+            _ => panic!("expected the first revision"),
+        }
+        assert!(stream.next().await.transpose().unwrap().is_none());
+
+        assert!(matches!(
+            corrector.stream_correct_error("됬다", &[], None, 8).await,
+            Err(BareunError::CassetteMiss { .. })
+        ));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_record_and_replay_custom_dictionary() {
+        let path = cassette_path("custom_dict");
+        let cassette = Cassette::record(&path);
+        {
+            let server = MockServer::start().await.unwrap();
+            let session = BareunSession::connect(record_config(&server, &cassette))
+                .await
+                .unwrap();
+            let mut dict = session.custom_dict("my").unwrap();
+            dict.copy_np_set(["바른".to_string()].into_iter().collect());
+            assert!(dict.update().await.unwrap());
+            dict.np_set.clear();
+            dict.load().await.unwrap();
+            assert_eq!(
+                session.custom_dict_client().get_list().await.unwrap().len(),
+                1
+            );
+        }
+
+        let session = BareunSession::connect(replay_config(&path)).await.unwrap();
+        let mut dict = session.custom_dict("my").unwrap();
+        dict.copy_np_set(["바른".to_string()].into_iter().collect());
+        assert!(dict.update().await.unwrap());
+        dict.np_set.clear();
+        dict.load().await.unwrap();
+        assert!(dict.np_set.contains("바른"));
+        assert_eq!(
+            session.custom_dict_client().get_list().await.unwrap().len(),
+            1
+        );
+
+        // 다른 내용으로 갱신하는 요청은 기록과 일치하지 않는다.
+        dict.copy_np_set(["바르다".to_string()].into_iter().collect());
+        assert!(matches!(
+            dict.update().await,
+            Err(BareunError::CassetteMiss { .. })
+        ));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_replay_errors() {
+        assert!(matches!(
+            Cassette::replay("/nonexistent/bareun/cassette.json"),
+            Err(BareunError::CassetteIo { .. })
+        ));
+
+        let path = cassette_path("malformed");
+        std::fs::write(&path, "{").unwrap();
+        assert!(matches!(
+            Cassette::replay(&path),
+            Err(BareunError::SerializationError(_))
+        ));
+        std::fs::remove_file(&path).unwrap();
+
+        // 서버에 닿지 못한 요청은 기록하지 않는다.
+        let path = cassette_path("unreachable");
+        let server = MockServer::start().await.unwrap();
+        let cassette = Cassette::record(&path);
+        let config = record_config(&server, &cassette);
+        drop(server);
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        let tagger = Tagger::connect_lazy(config, vec![]).unwrap();
+        assert!(tagger.morphs("오늘은").await.is_err());
+        assert!(cassette.is_empty());
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,147 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::{Duration, Instant};
+
+    use bareun_rs::bareun::language_service_server::{LanguageService, LanguageServiceServer};
+    use bareun_rs::bareun::morpheme::Tag;
+    use bareun_rs::bareun::{
+        AnalyzeSyntaxListRequest, AnalyzeSyntaxListResponse, AnalyzeSyntaxRequest,
+        AnalyzeSyntaxResponse, Morpheme, Sentence, TextSpan, Token, TokenizeRequest,
+        TokenizeResponse,
+    };
+    use bareun_rs::{BareunSession, Tagger};
+    use tonic::transport::Server;
+    use tonic::transport::server::TcpIncoming;
+    use tonic::{Request, Response, Status};
+
+    const DELAY: Duration = Duration::from_millis(200);
+
+    /// 요청마다 DELAY만큼 기다린 뒤 입력 전체를 하나의 명사로 돌려주는 서버
+    #[derive(Default)]
+    struct SlowLanguageService {
+        in_flight: Arc<AtomicUsize>,
+        max_in_flight: Arc<AtomicUsize>,
+    }
+
+    #[tonic::async_trait]
+    impl LanguageService for SlowLanguageService {
+        async fn analyze_syntax(
+            &self,
+            request: Request<AnalyzeSyntaxRequest>,
+        ) -> Result<Response<AnalyzeSyntaxResponse>, Status> {
+            let now = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_in_flight.fetch_max(now, Ordering::SeqCst);
+            tokio::time::sleep(DELAY).await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+            let content = request.into_inner().document.unwrap_or_default().content;
+            let span = TextSpan {
+                content: content.clone(),
+                begin_offset: 0,
+                length: content.chars().count() as i32,
+            };
+            Ok(Response::new(AnalyzeSyntaxResponse {
+                sentences: vec![Sentence {
+                    text: Some(span.clone()),
+                    tokens: vec![Token {
+                        text: Some(span.clone()),
+                        morphemes: vec![Morpheme {
+                            text: Some(span),
+                            tag: Tag::Nng as i32,
+                            ..Default::default()
+                        }],
+                        ..Default::default()
+                    }],
+                    refined: String::new(),
+                }],
+                language: "ko_KR".to_string(),
+                tokens_count: 1,
+            }))
+        }
+
+        async fn analyze_syntax_list(
+            &self,
+            _request: Request<AnalyzeSyntaxListRequest>,
+        ) -> Result<Response<AnalyzeSyntaxListResponse>, Status> {
+            Err(Status::unimplemented("not used"))
+        }
+
+        async fn tokenize(
+            &self,
+            _request: Request<TokenizeRequest>,
+        ) -> Result<Response<TokenizeResponse>, Status> {
+            Err(Status::unimplemented("not used"))
+        }
+    }
+
+    async fn spawn_server(service: SlowLanguageService) -> u16 {
+        let incoming = TcpIncoming::bind("127.0.0.1:0".parse().unwrap()).unwrap();
+        let port = incoming.local_addr().unwrap().port();
+        tokio::spawn(
+            Server::builder()
+                .add_service(LanguageServiceServer::new(service))
+                .serve_with_incoming(incoming),
+        );
+        port
+    }
+
+    fn assert_send_sync_clone<T: Send + Sync + Clone>() {}
+
+    #[test]
+    fn test_wrappers_are_send_sync_clone() {
+        assert_send_sync_clone::<Tagger>();
+        assert_send_sync_clone::<bareun_rs::Tokenizer>();
+        assert_send_sync_clone::<bareun_rs::Corrector>();
+        assert_send_sync_clone::<bareun_rs::CustomDict>();
+        assert_send_sync_clone::<BareunSession>();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_parallel_tags_share_one_tagger() {
+        const N: usize = 8;
+
+        let service = SlowLanguageService::default();
+        let max_in_flight = service.max_in_flight.clone();
+        let port = spawn_server(service).await;
+
+        let tagger = Tagger::new("appppppiiii", "127.0.0.1", Some(port), vec![])
+            .await
+            .unwrap();
+
+        let started = Instant::now();
+        let handles: Vec<_> = (0..N)
+            .map(|i| {
+                let tagger = tagger.clone();
+                tokio::spawn(async move { tagger.morphs(&format!("문장{}", i)).await })
+            })
+            .collect();
+
+        for (i, handle) in handles.into_iter().enumerate() {
+            let morphs = handle.await.unwrap().unwrap();
+            assert_eq!(morphs, vec![format!("문장{}", i)]);
+        }
+
+        // 직렬로 처리됐다면 N * DELAY 이상 걸린다.
+        assert!(started.elapsed() < DELAY * (N as u32 / 2));
+        assert!(max_in_flight.load(Ordering::SeqCst) > 1);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_parallel_tags_by_reference() {
+        let service = SlowLanguageService::default();
+        let max_in_flight = service.max_in_flight.clone();
+        let port = spawn_server(service).await;
+
+        let tagger = Tagger::new("appppppiiii", "127.0.0.1", Some(port), vec![])
+            .await
+            .unwrap();
+
+        let (a, b, c) = tokio::join!(tagger.nouns("하나"), tagger.nouns("둘"), tagger.nouns("셋"));
+        assert_eq!(a.unwrap(), vec!["하나"]);
+        assert_eq!(b.unwrap(), vec!["둘"]);
+        assert_eq!(c.unwrap(), vec!["셋"]);
+        assert_eq!(max_in_flight.load(Ordering::SeqCst), 3);
+    }
+}
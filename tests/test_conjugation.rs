@@ -0,0 +1,203 @@
+#[cfg(test)]
+mod tests {
+    use bareun_rs::Tagged;
+    use bareun_rs::mock::analyze_syntax_response;
+    use bareun_rs::model::Tag;
+    use bareun_rs::surface_form;
+
+    /// (어절, 형태소/태그) 합성 말뭉치
+    ///
+    /// 서버 분석 결과가 아니라 서버가 돌려주는 모양을 따라 손으로 나눈 형태소다.
+    const CORPUS: &[(&str, &str)] = &[
+        // 자모 어미, 조사
+        ("난다", "날/VV+ㄴ다/EF"),
+        ("간다", "가/VV+ㄴ다/EF"),
+        ("갑니다", "가/VV+ㅂ니다/EF"),
+        ("삽니다", "살/VV+ㅂ니다/EF"),
+        ("삶", "살/VV+ㅁ/ETN"),
+        ("만들", "만들/VV+ㄹ/ETM"),
+        ("할", "하/VV+ㄹ/ETM"),
+        ("먹은", "먹/VV+ㄴ/ETM"),
+        ("난", "나/NP+ㄴ/JX"),
+        ("널", "너/NP+ㄹ/JKO"),
+        // ㄹ 탈락, 으 탈락
+        ("사니", "살/VV+니/EC"),
+        ("노는", "놀/VV+는/ETM"),
+        ("사세요", "살/VV+세요/EF"),
+        ("사오", "살/VV+오/EC"),
+        ("살면서", "살/VV+면서/EC"),
+        ("알면", "알/VV+으면/EC"),
+        ("가면", "가/VV+으면/EC"),
+        ("먹으면", "먹/VV+으면/EC"),
+        ("긴", "길/VA+ㄴ/ETM"),
+        // ㅂ 불규칙
+        ("추운", "춥/VA+ㄴ/ETM"),
+        ("추워서", "춥/VA+어서/EC"),
+        ("추우면", "춥/VA+으면/EC"),
+        ("도왔다", "돕/VV+았/EP+다/EF"),
+        ("고와", "곱/VA+아/EC"),
+        ("아름다운", "아름/XR+답/XSA+ㄴ/ETM"),
+        ("자연스러워", "자연/NNG+스럽/XSA+어/EF"),
+        ("잡아", "잡/VV+아/EC"),
+        ("좁은", "좁/VA+은/ETM"),
+        ("수줍어", "수줍/VA+어/EC"),
+        // ㄷ 불규칙
+        ("들어", "듣/VV+어/EC"),
+        ("걸으면", "걷/VV+으면/EC"),
+        ("듣고", "듣/VV+고/EC"),
+        ("받아", "받/VV+아/EC"),
+        // ㅅ 불규칙
+        ("지어", "짓/VV+어/EC"),
+        ("나았다", "낫/VV+았/EP+다/EF"),
+        ("씻어", "씻/VV+어/EC"),
+        // 르 불규칙
+        ("불러", "부르/VV+어/EC"),
+        ("몰랐다", "모르/VV+았/EP+다/EF"),
+        ("빨라", "빠르/VA+아/EC"),
+        ("따라", "따르/VV+아/EC"),
+        // ㅎ 불규칙
+        ("빨간", "빨갛/VA+ㄴ/ETM"),
+        ("빨개", "빨갛/VA+아/EF"),
+        ("그랬다", "그렇/VA+었/EP+다/EF"),
+        ("하얘", "하얗/VA+아/EC"),
+        ("빨가면", "빨갛/VA+으면/EC"),
+        ("좋아", "좋/VA+아/EC"),
+        ("놓은", "놓/VV+은/ETM"),
+        // 모음 축약
+        ("했다", "하/VV+았/EP+다/EF"),
+        ("카톡했다", "카톡/NNG+하/XSV+았/EP+다/EF"),
+        ("깨끗해", "깨끗/XR+하/XSA+아/EF"),
+        ("갔다", "가/VV+았/EP+다/EF"),
+        ("섰다", "서/VV+었/EP+다/EF"),
+        ("봐", "보/VV+아/EF"),
+        ("왔어요", "오/VV+았/EP+어요/EF"),
+        ("줘", "주/VV+어/EF"),
+        ("마셔", "마시/VV+어/EC"),
+        ("가르쳤다", "가르치/VV+었/EP+다/EF"),
+        ("돼", "되/VV+어/EF"),
+        ("보냈다", "보내/VV+었/EP+다/EF"),
+        ("켰다", "켜/VV+었/EP+다/EF"),
+        ("써", "쓰/VV+어/EC"),
+        ("바빠", "바쁘/VA+아/EF"),
+        ("퍼", "푸/VV+어/EC"),
+        ("가셨다", "가/VV+시/EP+었/EP+다/EF"),
+        ("하여", "하/VV+여/EC"),
+        // 지정사
+        ("학생이었다", "학생/NNG+이/VCP+었/EP+다/EF"),
+        ("의사였다", "의사/NNG+이/VCP+었/EP+다/EF"),
+        ("학생이다.", "학생/NNG+이/VCP+다/EF+./SF"),
+        ("먹어봤다", "먹/VV+어/EC+보/VX+았/EP+다/EF"),
+    ];
+
+    fn parse(tagged: &str) -> Vec<(&str, Tag)> {
+        tagged
+            .split('+')
+            .map(|m| {
+                let (text, tag) = m.rsplit_once('/').unwrap();
+                (text, tag.parse().unwrap())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_corpus_round_trip() {
+        let failed: Vec<_> = CORPUS
+            .iter()
+            .filter_map(|(eojeol, tagged)| {
+                let surface = surface_form(parse(tagged));
+                (surface != *eojeol)
+                    .then(|| format!("{} -> {} (expected {})", tagged, surface, eojeol))
+            })
+            .collect();
+        assert!(failed.is_empty(), "{:#?}", failed);
+    }
+
+    #[test]
+    fn test_rules() {
+        for (tagged, expected) in [
+            ("살/VV+습니다/EF", "삽니다"),
+            ("살/VV+오/EF", "사오"),
+            ("살/VV+시/EP+었/EP+다/EF", "사셨다"),
+            ("살/VV+아요/EF", "살아요"),
+            ("수줍/VA+어/EC", "수줍어"),
+            ("굽/VA+은/ETM", "굽은"),
+            ("춥/VA+어/EC", "추워"),
+            ("먹/VV+습니다/EF", "먹습니다"),
+            // 받침으로 쓸 수 없는 자모는 그대로 남는다.
+            ("가/VV+ㄸ다/EF", "가ㄸ다"),
+            ("먹/VV+ㄸ다/EF", "먹ㄸ다"),
+        ] {
+            assert_eq!(surface_form(parse(tagged)), expected, "{}", tagged);
+        }
+    }
+
+    #[test]
+    fn test_b_irregular_glide() {
+        for (tagged, expected) in [
+            ("돕/VV+아/EC", "도와"),
+            ("돕/VV+ㄹ/ETM", "도울"),
+            ("돕/VV+으면/EC", "도우면"),
+            ("곱/VA+ㄴ/ETM", "고운"),
+            ("곱/VA+았/EP+다/EF", "고왔다"),
+            ("춥/VA+ㅂ니다/EF", "춥습니다"),
+            ("춥/VA+ㅂ시다/EF", "추웁시다"),
+            ("춥/VA+ㅁ/ETN", "추움"),
+            ("먹/VV+ㅂ니다/EF", "먹습니다"),
+            ("먹/VV+ㅂ시다/EF", "먹읍시다"),
+        ] {
+            assert_eq!(surface_form(parse(tagged)), expected, "{}", tagged);
+        }
+    }
+
+    #[test]
+    fn test_d_s_irregular_jamo_endings() {
+        for (tagged, expected) in [
+            ("듣/VV+ㄴ/ETM", "들은"),
+            ("듣/VV+ㄹ/ETM", "들을"),
+            ("듣/VV+ㅂ니다/EF", "듣습니다"),
+            ("짓/VV+ㄴ/ETM", "지은"),
+            ("짓/VV+ㄹ/ETM", "지을"),
+            ("낫/VA+ㄴ/ETM", "나은"),
+            ("씻/VV+ㄴ/ETM", "씻은"),
+        ] {
+            assert_eq!(surface_form(parse(tagged)), expected, "{}", tagged);
+        }
+    }
+
+    #[test]
+    fn test_substitute_stem() {
+        let mut morphemes = parse("먹/VV+었/EP+다/EF");
+        for (stem, tag, expected) in [
+            ("가", Tag::Vv, "갔다"),
+            ("돕", Tag::Vv, "도왔다"),
+            ("듣", Tag::Vv, "들었다"),
+            ("부르", Tag::Vv, "불렀다"),
+            ("춥", Tag::Va, "추웠다"),
+        ] {
+            morphemes[0] = (stem, tag);
+            assert_eq!(surface_form(morphemes.clone()), expected);
+        }
+    }
+
+    #[test]
+    fn test_eojeol_surface_form() {
+        let phrase = "추운 날 도왔다";
+        let res = analyze_syntax_response(
+            phrase,
+            &[
+                ("추운", &[("춥", "VA"), ("ㄴ", "ETM")]),
+                ("날", &[("날", "NNG")]),
+                ("도왔다", &[("돕", "VV"), ("았", "EP"), ("다", "EF")]),
+            ],
+        );
+        let tagged = Tagged::new(phrase.to_string(), res);
+        for e in tagged.eojeols() {
+            assert_eq!(e.surface_form(), e.text());
+        }
+
+        let mut doc = tagged.document().unwrap();
+        let eojeol = &mut doc.sentences[0].eojeols[2];
+        eojeol.morphemes[0].text = "듣".to_string();
+        assert_eq!(eojeol.surface_form(), "들었다");
+    }
+}
@@ -0,0 +1,143 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use bareun_rs::Tagged;
+    use bareun_rs::model::{Document, Span, Tag, Upos};
+
+    use crate::common;
+
+    fn tagged() -> Tagged {
+        common::tagged(
+            "새 차가 깨끗했다.",
+            &[
+                ("새", &[("새", "MMA")]),
+                ("차가", &[("차", "NNG"), ("가", "JKS")]),
+                (
+                    "깨끗했다.",
+                    &[
+                        ("깨끗", "XR"),
+                        ("하", "XSA"),
+                        ("었", "EP"),
+                        ("다", "EF"),
+                        (".", "SF"),
+                    ],
+                ),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_upos_mapping() {
+        assert_eq!(Tag::Nng.upos(), Upos::Noun);
+        assert_eq!(Tag::Nnp.upos(), Upos::Propn);
+        assert_eq!(Tag::Vx.upos(), Upos::Aux);
+        assert_eq!(Tag::Jks.upos(), Upos::Adp);
+        assert_eq!(Tag::Jc.upos(), Upos::Cconj);
+        assert_eq!(Tag::Sf.upos(), Upos::Punct);
+        assert_eq!(Tag::Unk.upos(), Upos::X);
+        assert_eq!(Tag::Mmn.upos(), Upos::Det);
+        assert_eq!("cconj".parse::<Upos>().unwrap(), Upos::Cconj);
+        assert!("NNG".parse::<Upos>().is_err());
+        for upos in Upos::ALL {
+            assert_eq!(upos.as_str().parse::<Upos>().unwrap(), upos);
+        }
+    }
+
+    #[test]
+    fn test_to_conllu() {
+        let conllu = tagged().to_conllu().unwrap();
+        assert_eq!(
+            conllu,
+            "# sent_id = 1\n\
+             # text = 새 차가 깨끗했다.\n\
+             1\t새\t새\tDET\tMMA\t_\t_\t_\t_\t_\n\
+             2\t차가\t차+가\tNOUN\tNNG+JKS\t_\t_\t_\t_\t_\n\
+             3\t깨끗했다.\t깨끗+하+었+다+.\tADJ\tXR+XSA+EP+EF+SF\t_\t_\t_\t_\t_\n\
+             \n"
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let doc = tagged().document().unwrap();
+        let read = Document::from_conllu(&doc.to_conllu()).unwrap();
+        assert_eq!(read.text, doc.text);
+        assert_eq!(read.sentences.len(), 1);
+        let (a, b) = (&read.sentences[0], &doc.sentences[0]);
+        assert_eq!(a.text, b.text);
+        assert_eq!(a.span, b.span);
+        for (x, y) in a.eojeols.iter().zip(&b.eojeols) {
+            assert_eq!(x.text, y.text);
+            assert_eq!(x.span, y.span);
+            assert_eq!(x.tagged, y.tagged);
+            // 축약된 형태소(했 = 하+었)의 위치는 CoNLL-U에 남지 않는다.
+            let tags = |e: &bareun_rs::model::Eojeol| {
+                e.morphemes
+                    .iter()
+                    .map(|m| (m.text.clone(), m.tag))
+                    .collect::<Vec<_>>()
+            };
+            assert_eq!(tags(x), tags(y));
+        }
+    }
+
+    #[test]
+    fn test_round_trip_plus_and_empty_morphemes() {
+        let mut doc = common::tagged(
+            "1+1은 C++로 _",
+            &[
+                (
+                    "1+1은",
+                    &[("1", "SN"), ("+", "SW"), ("1", "SN"), ("은", "JX")],
+                ),
+                ("C++로", &[("C++", "SL"), ("로", "JKB")]),
+                ("_", &[("_", "SW")]),
+            ],
+        )
+        .document()
+        .unwrap();
+        doc.sentences[0].eojeols[1].morphemes[1].text.clear();
+
+        let conllu = doc.to_conllu();
+        assert!(conllu.contains("\t1+\\++1+은\tNUM\tSN+SW+SN+JX\t"));
+        assert!(conllu.contains("\tC\\+\\++_\tX\tSL+JKB\t"));
+        assert!(conllu.contains("\t\\_\tSYM\tSW\t"));
+
+        let read = Document::from_conllu(&conllu).unwrap();
+        let texts = |d: &Document| {
+            d.morphemes()
+                .map(|m| (m.text.clone(), m.tag))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(texts(&read), texts(&doc));
+        assert_eq!(read.sentences[0].eojeols[1].morphemes[1].text, "");
+    }
+
+    #[test]
+    fn test_read_without_text_comment() {
+        let input = "# sent_id = a\n\
+                     1-2\t나비가\t_\t_\t_\t_\t_\t_\t_\t_\n\
+                     1\t나비가\t나비+가\tNOUN\tNNG+JKS\t_\t2\tnsubj\t_\t_\n\
+                     2\t난다\t날+ㄴ다\tVERB\tVV+EF\t_\t0\troot\t_\tSpaceAfter=No\n\
+                     3\t.\t.\tPUNCT\tSF\t_\t2\tpunct\t_\t_\n\
+                     \n\
+                     1\t끝\t끝\tNOUN\tNNG\t_\t0\troot\t_\t_\n";
+        let doc = Document::from_conllu(input).unwrap();
+        assert_eq!(doc.text, "나비가 난다.\n끝");
+        assert_eq!(doc.sentences[0].eojeols.len(), 3);
+        assert_eq!(doc.sentences[0].eojeols[2].span, Span::new(6, 1));
+        // 원형으로 바뀐 형태소는 어절 전체를 가리킨다.
+        assert_eq!(
+            doc.sentences[0].eojeols[1].morphemes[1].span,
+            Span::new(4, 2)
+        );
+        assert_eq!(doc.sentences[1].span, Span::new(8, 1));
+        assert_eq!(doc.sentences[1].eojeols[0].span, Span::new(8, 1));
+
+        let err = Document::from_conllu("1\t나비\t나비\tNOUN\tNNG+JKS\t_\t_\t_\t_\t_\n");
+        assert!(err.unwrap_err().to_string().contains("line 1"));
+        assert!(Document::from_conllu("1\t나비\t나비\tNOUN\n").is_err());
+        assert!(Document::from_conllu("1\t나비\t나비\tNOUN\tFOO\t_\t_\t_\t_\t_\n").is_err());
+    }
+}
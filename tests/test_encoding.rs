@@ -0,0 +1,178 @@
+#[cfg(test)]
+mod tests {
+    use bareun_rs::bareun::EncodingType;
+    use bareun_rs::mock::{MockMessage, MockMethod, MockServer, analyze_syntax_response};
+    use bareun_rs::model::Span;
+    use bareun_rs::{
+        BareunClientConfig, CallOptions, Corrector, OffsetIndex, TagOptions, Tagged, Tagger,
+        Tokenizer,
+    };
+    use tokio_stream::StreamExt;
+
+    const API_KEY: &str = "koba-ABCDEFG-1234567-LMNOPQR-7654321";
+
+    /// 서버에 보낸 요청들의 `encoding_type`
+    fn requested(server: &MockServer) -> Vec<EncodingType> {
+        server
+            .requests()
+            .into_iter()
+            .filter_map(|r| match r.message {
+                MockMessage::AnalyzeSyntax(req) => Some(req.encoding_type()),
+                MockMessage::AnalyzeSyntaxList(req) => Some(req.encoding_type()),
+                MockMessage::Tokenize(req) => Some(req.encoding_type()),
+                MockMessage::CorrectError(req) => Some(req.encoding_type()),
+                MockMessage::StreamCorrectError(req) => Some(req.encoding_type()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_default_is_utf32() {
+        assert_eq!(
+            BareunClientConfig::builder(API_KEY).build().encoding,
+            EncodingType::Utf32
+        );
+        assert_eq!(CallOptions::new().encoding, None);
+        assert_eq!(TagOptions::new().encoding, None);
+    }
+
+    #[tokio::test]
+    async fn test_client_and_per_call_encoding() {
+        let server = MockServer::start().await.unwrap();
+        let mut config = server.config(API_KEY);
+        config.encoding = EncodingType::Utf16;
+
+        let tagger = Tagger::from_config(config.clone(), vec![]).await.unwrap();
+        assert_eq!(tagger.encoding(), EncodingType::Utf16);
+        let tagged = tagger.tag("오늘은", false, true, false).await.unwrap();
+        assert_eq!(tagged.encoding(), EncodingType::Utf16);
+        let tagged = tagger
+            .tag_with_options("오늘은", &TagOptions::new().encoding(EncodingType::Utf8))
+            .await
+            .unwrap();
+        assert_eq!(tagged.encoding(), EncodingType::Utf8);
+        let tagged = tagger
+            .taglist(&["오늘은".to_string()], true, false)
+            .await
+            .unwrap();
+        assert_eq!(tagged.encoding(), EncodingType::Utf16);
+
+        let mut tokenizer = Tokenizer::from_config(config.clone()).await.unwrap();
+        assert_eq!(
+            tokenizer
+                .tokenize("오늘은", false)
+                .await
+                .unwrap()
+                .encoding(),
+            EncodingType::Utf16
+        );
+        tokenizer.set_encoding(EncodingType::Utf32);
+        tokenizer.tokenize("오늘은", false).await.unwrap();
+
+        let corrector = Corrector::from_config(config).await.unwrap();
+        let res = corrector
+            .correct_error_with_options(
+                "됬다",
+                &[],
+                None,
+                &CallOptions::new().encoding(EncodingType::Utf8),
+            )
+            .await
+            .unwrap();
+        assert_eq!(res.encoding(), EncodingType::Utf8);
+        // 역참조로 응답 필드를 그대로 읽는다.
+        assert_eq!(res.origin, "됬다");
+        let mut stream = corrector
+            .stream_correct_error("됬다", &[], None, 1)
+            .await
+            .unwrap();
+        while stream.next().await.transpose().unwrap().is_some() {}
+
+        assert_eq!(
+            requested(&server),
+            vec![
+                EncodingType::Utf16,
+                EncodingType::Utf8,
+                EncodingType::Utf16,
+                EncodingType::Utf16,
+                EncodingType::Utf32,
+                EncodingType::Utf8,
+                EncodingType::Utf16,
+            ]
+        );
+        assert_eq!(server.requests_for(MockMethod::StreamCorrectError).len(), 1);
+    }
+
+    #[test]
+    fn test_offset_index_units() {
+        let text = "🦋 나비";
+        let utf8 = OffsetIndex::with_encoding(text, EncodingType::Utf8);
+        let range = utf8.range(Span::new(5, 6)).unwrap();
+        assert_eq!(range.chars, 2..4);
+        assert_eq!(range.utf16, 3..5);
+        // 문자 중간을 가리키는 위치
+        assert!(utf8.range(Span::new(1, 3)).is_err());
+
+        let utf16 = OffsetIndex::with_encoding(text, EncodingType::Utf16);
+        let range = utf16.range(Span::new(3, 2)).unwrap();
+        assert_eq!(range.chars, 2..4);
+        assert_eq!(&text[range.utf8], "나비");
+        // 서로게이트 쌍의 가운데
+        assert!(utf16.range(Span::new(1, 1)).is_err());
+        assert!(utf16.range(Span::new(3, 3)).is_err());
+
+        assert!(
+            OffsetIndex::with_encoding(text, EncodingType::None)
+                .range(Span::new(0, 1))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_tagged_spans_follow_encoding() {
+        let phrase = "🦋 나비가";
+        let mut res = analyze_syntax_response(
+            phrase,
+            &[
+                ("🦋", &[("🦋", "SW")]),
+                ("나비가", &[("나비", "NNG"), ("가", "JKS")]),
+            ],
+        );
+        // 서버가 UTF-16 위치로 돌려준 것처럼 바꾼다.
+        let sentence = &mut res.sentences[0];
+        sentence.text.as_mut().unwrap().length = 6;
+        let spans = [(0, 2), (3, 3)];
+        for (token, (begin, length)) in sentence.tokens.iter_mut().zip(spans) {
+            let text = token.text.as_mut().unwrap();
+            text.begin_offset = begin;
+            text.length = length;
+        }
+        let morphs = [(0, 2), (3, 2), (5, 1)];
+        let all = sentence.tokens.iter_mut().flat_map(|t| &mut t.morphemes);
+        for (m, (begin, length)) in all.zip(morphs) {
+            let text = m.text.as_mut().unwrap();
+            text.begin_offset = begin;
+            text.length = length;
+        }
+
+        let tagged = Tagged::new(phrase.to_string(), res).with_encoding(EncodingType::Utf16);
+        tagged.validate_offsets().unwrap();
+        let index = tagged.offset_index();
+        let ranges: Vec<_> = tagged
+            .morphemes()
+            .map(|m| m.range(&index).unwrap().chars)
+            .collect();
+        assert_eq!(ranges, vec![0..1, 2..4, 4..5]);
+
+        // 같은 응답을 UTF-32로 읽으면 위치가 맞지 않는다.
+        let utf32 = Tagged::new(tagged.phrase.clone(), tagged.r.clone());
+        assert!(utf32.validate_offsets().is_err());
+
+        let doc = tagged.document().unwrap();
+        assert_eq!(
+            serde_json::to_value(&doc).unwrap()["encoding"],
+            serde_json::json!("utf16")
+        );
+    }
+}
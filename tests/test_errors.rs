@@ -0,0 +1,190 @@
+#[cfg(test)]
+mod tests {
+    use bareun_rs::bareun::language_service_server::{LanguageService, LanguageServiceServer};
+    use bareun_rs::bareun::{
+        AnalyzeSyntaxListRequest, AnalyzeSyntaxListResponse, AnalyzeSyntaxRequest,
+        AnalyzeSyntaxResponse, TokenizeRequest, TokenizeResponse,
+    };
+    use bareun_rs::{BareunClientConfig, BareunError, Tagger, mask_api_key};
+    use tonic::metadata::MetadataMap;
+    use tonic::transport::Server;
+    use tonic::transport::server::TcpIncoming;
+    use tonic::{Code, Request, Response, Status};
+
+    const API_KEY: &str = "koba-ABCDEFG-1234567-LMNOPQR-7654321";
+
+    /// 문장으로 받은 상태 코드 이름으로 실패하는 서버
+    struct FailingLanguageService;
+
+    fn status_for(name: &str) -> Status {
+        let code = match name {
+            "PERMISSION_DENIED" => Code::PermissionDenied,
+            "UNAUTHENTICATED" => Code::Unauthenticated,
+            "RESOURCE_EXHAUSTED" => Code::ResourceExhausted,
+            "NOT_FOUND" => Code::NotFound,
+            "DEADLINE_EXCEEDED" => Code::DeadlineExceeded,
+            "INVALID_ARGUMENT" => Code::InvalidArgument,
+            _ => Code::Internal,
+        };
+        let mut metadata = MetadataMap::new();
+        metadata.insert("x-request-id", "req-42".parse().unwrap());
+        Status::with_details_and_metadata(code, "rejected", b"detail".to_vec().into(), metadata)
+    }
+
+    #[tonic::async_trait]
+    impl LanguageService for FailingLanguageService {
+        async fn analyze_syntax(
+            &self,
+            request: Request<AnalyzeSyntaxRequest>,
+        ) -> Result<Response<AnalyzeSyntaxResponse>, Status> {
+            let content = request.into_inner().document.unwrap_or_default().content;
+            Err(status_for(&content))
+        }
+
+        async fn analyze_syntax_list(
+            &self,
+            _request: Request<AnalyzeSyntaxListRequest>,
+        ) -> Result<Response<AnalyzeSyntaxListResponse>, Status> {
+            Err(Status::unimplemented("not used"))
+        }
+
+        async fn tokenize(
+            &self,
+            _request: Request<TokenizeRequest>,
+        ) -> Result<Response<TokenizeResponse>, Status> {
+            Err(Status::unimplemented("not used"))
+        }
+    }
+
+    async fn tagger() -> Tagger {
+        let incoming = TcpIncoming::bind("127.0.0.1:0".parse().unwrap()).unwrap();
+        let port = incoming.local_addr().unwrap().port();
+        tokio::spawn(
+            Server::builder()
+                .add_service(LanguageServiceServer::new(FailingLanguageService))
+                .serve_with_incoming(incoming),
+        );
+        let config = BareunClientConfig::builder(API_KEY)
+            .host("127.0.0.1")
+            .port(port)
+            .build();
+        Tagger::from_config(config, vec![]).await.unwrap()
+    }
+
+    async fn fail_with(tagger: &Tagger, code: &str) -> BareunError {
+        tagger.tag(code, false, true, false).await.err().unwrap()
+    }
+
+    #[test]
+    fn test_mask_api_key() {
+        assert_eq!(mask_api_key(API_KEY), "koba-****-321");
+        assert_eq!(mask_api_key("short-key"), "****");
+        assert_eq!(mask_api_key("nodashesatallinthiskey"), "****");
+        assert_eq!(mask_api_key(""), "****");
+        // 앞부분이 길어도 몇 글자만 보인다.
+        assert_eq!(mask_api_key("SECRETSECRET-123"), "SECR-****-123");
+    }
+
+    #[test]
+    fn test_config_debug_masks_api_key() {
+        let config = BareunClientConfig::new(API_KEY, "localhost", None);
+        let debug = format!("{:?}", config);
+        assert!(!debug.contains(API_KEY));
+        assert!(debug.contains("koba-****-321"));
+        assert!(!format!("{:?}", BareunClientConfig::builder(API_KEY)).contains(API_KEY));
+    }
+
+    #[tokio::test]
+    async fn test_permission_denied_does_not_leak_api_key() {
+        let tagger = tagger().await;
+        let e = fail_with(&tagger, "PERMISSION_DENIED").await;
+        assert!(matches!(e, BareunError::PermissionDenied { .. }));
+        let display = e.to_string();
+        assert!(!display.contains(API_KEY));
+        assert!(display.contains("koba-****-321"));
+        assert!(!format!("{:?}", e).contains(API_KEY));
+
+        let e = fail_with(&tagger, "UNAUTHENTICATED").await;
+        assert!(matches!(e, BareunError::Unauthenticated { .. }));
+        assert!(!e.to_string().contains(API_KEY));
+    }
+
+    #[tokio::test]
+    async fn test_status_is_preserved() {
+        let tagger = tagger().await;
+        let e = fail_with(&tagger, "INTERNAL").await;
+        assert!(matches!(e, BareunError::GrpcError(_)));
+        assert_eq!(e.code(), Some(Code::Internal));
+        assert_eq!(e.method(), Some("bareun.LanguageService/AnalyzeSyntax"));
+
+        let status = e.status().unwrap();
+        assert_eq!(status.message, "rejected");
+        assert_eq!(status.details, b"detail");
+        assert_eq!(
+            status
+                .metadata
+                .get("x-request-id")
+                .unwrap()
+                .to_str()
+                .unwrap(),
+            "req-42"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_distinct_variants() {
+        let tagger = tagger().await;
+        assert!(matches!(
+            fail_with(&tagger, "RESOURCE_EXHAUSTED").await,
+            BareunError::ResourceExhausted { .. }
+        ));
+        assert!(matches!(
+            fail_with(&tagger, "NOT_FOUND").await,
+            BareunError::NotFound { .. }
+        ));
+        assert!(matches!(
+            fail_with(&tagger, "DEADLINE_EXCEEDED").await,
+            BareunError::DeadlineExceeded { .. }
+        ));
+        let e = fail_with(&tagger, "INVALID_ARGUMENT").await;
+        assert!(matches!(
+            e,
+            BareunError::InvalidArgument {
+                status: Some(_),
+                ..
+            }
+        ));
+        assert_eq!(e.code(), Some(Code::InvalidArgument));
+    }
+
+    #[tokio::test]
+    async fn test_is_retryable() {
+        let tagger = tagger().await;
+        assert!(
+            fail_with(&tagger, "RESOURCE_EXHAUSTED")
+                .await
+                .is_retryable()
+        );
+        assert!(fail_with(&tagger, "DEADLINE_EXCEEDED").await.is_retryable());
+        assert!(!fail_with(&tagger, "PERMISSION_DENIED").await.is_retryable());
+        assert!(!fail_with(&tagger, "INVALID_ARGUMENT").await.is_retryable());
+        assert!(!fail_with(&tagger, "NOT_FOUND").await.is_retryable());
+        assert!(!BareunError::MissingApiKey.is_retryable());
+
+        let dead = {
+            let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+            listener.local_addr().unwrap().port()
+        };
+        let e = Tagger::connect_lazy(
+            BareunClientConfig::new(API_KEY, "127.0.0.1", Some(dead)),
+            vec![],
+        )
+        .unwrap()
+        .tag("오늘은", false, true, false)
+        .await
+        .err()
+        .unwrap();
+        assert!(matches!(e, BareunError::ServerUnavailable { .. }));
+        assert!(e.is_retryable());
+    }
+}
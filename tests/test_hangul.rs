@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod tests {
+    use bareun_rs::Tagged;
+    use bareun_rs::hangul::{self, Syllable};
+    use bareun_rs::mock::analyze_syntax_response;
+
+    #[test]
+    fn test_decompose_and_compose() {
+        assert_eq!(
+            hangul::decompose('가'),
+            Some(Syllable::new('ㄱ', 'ㅏ', None))
+        );
+        assert_eq!(
+            hangul::decompose('힣'),
+            Some(Syllable::new('ㅎ', 'ㅣ', Some('ㅎ')))
+        );
+        assert_eq!(hangul::decompose('ㄱ'), None);
+        assert_eq!(hangul::decompose('a'), None);
+        for c in ['가', '닭', '뷁', '힣', '읽'] {
+            assert_eq!(hangul::decompose(c).unwrap().compose(), Some(c));
+        }
+        // 겹자음은 초성에 쓸 수 없다.
+        assert_eq!(hangul::compose('ㄺ', 'ㅏ', None), None);
+        assert_eq!(hangul::compose('ㄱ', 'ㄱ', None), None);
+    }
+
+    #[test]
+    fn test_batchim_and_choseong() {
+        assert!(hangul::has_batchim('책'));
+        assert!(!hangul::has_batchim('나'));
+        assert!(!hangul::has_batchim('A'));
+        assert_eq!(hangul::batchim('삶'), Some('ㄻ'));
+        assert_eq!(hangul::ends_with_batchim("사과"), Some(false));
+        assert_eq!(hangul::ends_with_batchim("수박"), Some(true));
+        assert_eq!(hangul::ends_with_batchim("CPU"), None);
+        assert_eq!(hangul::choseong("바른 AI, 2024"), "ㅂㄹ AI, 2024");
+        assert!(hangul::is_hangul('ㅋ') && hangul::is_hangul('한'));
+        assert!(!hangul::is_syllable('ㅋ'));
+    }
+
+    #[test]
+    fn test_jamo_normalization() {
+        let nfd = hangul::to_conjoining("한글 ok");
+        assert_eq!(nfd, "\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF} ok");
+        assert_eq!(hangul::to_compatibility(&nfd), "한글 ok");
+        // 짝이 없는 첫가끝 자모는 호환 자모가 된다.
+        assert_eq!(hangul::to_compatibility("\u{1100}\u{11AB}"), "ㄱㄴ");
+        assert_eq!(hangul::compatibility_jamo('\u{1161}'), 'ㅏ');
+        assert!(hangul::is_jamo_fragment("ㅂ니다"));
+        assert!(!hangul::is_jamo_fragment("습니다"));
+        assert!(!hangul::is_jamo_fragment(""));
+    }
+
+    #[test]
+    fn test_morpheme_jamo_fragment() {
+        let res = analyze_syntax_response("난다", &[("난다", &[("날", "VV"), ("ㄴ다", "EF")])]);
+        let tagged = Tagged::new("난다".to_string(), res);
+        let fragments: Vec<bool> = tagged.morphemes().map(|m| m.is_jamo_fragment()).collect();
+        assert_eq!(fragments, vec![false, true]);
+        let doc = tagged.document().unwrap();
+        assert!(doc.morphemes().nth(1).unwrap().is_jamo_fragment());
+    }
+}
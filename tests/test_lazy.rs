@@ -0,0 +1,127 @@
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use bareun_rs::bareun::language_service_server::{LanguageService, LanguageServiceServer};
+    use bareun_rs::bareun::{
+        AnalyzeSyntaxListRequest, AnalyzeSyntaxListResponse, AnalyzeSyntaxRequest,
+        AnalyzeSyntaxResponse, TokenizeRequest, TokenizeResponse,
+    };
+    use bareun_rs::{BareunClientConfig, BareunError, BareunSession, Corrector, Tagger, Tokenizer};
+    use tonic::transport::Server;
+    use tonic::transport::server::TcpIncoming;
+    use tonic::{Request, Response, Status};
+
+    struct EmptyLanguageService;
+
+    #[tonic::async_trait]
+    impl LanguageService for EmptyLanguageService {
+        async fn analyze_syntax(
+            &self,
+            _request: Request<AnalyzeSyntaxRequest>,
+        ) -> Result<Response<AnalyzeSyntaxResponse>, Status> {
+            Ok(Response::new(AnalyzeSyntaxResponse::default()))
+        }
+
+        async fn analyze_syntax_list(
+            &self,
+            _request: Request<AnalyzeSyntaxListRequest>,
+        ) -> Result<Response<AnalyzeSyntaxListResponse>, Status> {
+            Ok(Response::new(AnalyzeSyntaxListResponse::default()))
+        }
+
+        async fn tokenize(
+            &self,
+            _request: Request<TokenizeRequest>,
+        ) -> Result<Response<TokenizeResponse>, Status> {
+            Ok(Response::new(TokenizeResponse::default()))
+        }
+    }
+
+    /// 아무도 듣고 있지 않은 포트
+    fn free_port() -> u16 {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        listener.local_addr().unwrap().port()
+    }
+
+    fn serve_on(port: u16) {
+        let incoming = TcpIncoming::bind(([127, 0, 0, 1], port).into()).unwrap();
+        tokio::spawn(
+            Server::builder()
+                .add_service(LanguageServiceServer::new(EmptyLanguageService))
+                .serve_with_incoming(incoming),
+        );
+    }
+
+    fn config(port: u16) -> BareunClientConfig {
+        BareunClientConfig::builder("appppppiiii")
+            .host("127.0.0.1")
+            .port(port)
+            .build()
+    }
+
+    #[tokio::test]
+    async fn test_lazy_constructors_do_not_touch_network() {
+        let port = free_port();
+        assert!(Tagger::connect_lazy(config(port), vec![]).is_ok());
+        assert!(Tokenizer::connect_lazy(config(port)).is_ok());
+        assert!(Corrector::connect_lazy(config(port)).is_ok());
+        assert!(BareunSession::connect_lazy(config(port)).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_lazy_connection_error_on_first_request() {
+        let tagger = Tagger::connect_lazy(config(free_port()), vec![]).unwrap();
+        let result = tagger.tag("오늘은", false, true, false).await;
+        assert!(matches!(result, Err(BareunError::ServerUnavailable { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_lazy_missing_api_key() {
+        let config = BareunClientConfig::builder("").host("127.0.0.1").build();
+        assert!(matches!(
+            Tagger::connect_lazy(config, vec![]),
+            Err(BareunError::MissingApiKey)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_server_started_after_construction() {
+        let port = free_port();
+        let tagger = Tagger::connect_lazy(config(port), vec![]).unwrap();
+        assert!(tagger.ready().await.is_err());
+
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(200)).await;
+            serve_on(port);
+        });
+
+        tagger.wait_ready(Duration::from_secs(5)).await.unwrap();
+        assert!(tagger.ready().await.is_ok());
+        assert!(tagger.tag("오늘은", false, true, false).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_wait_ready_times_out() {
+        let session = BareunSession::connect_lazy(config(free_port())).unwrap();
+
+        let started = Instant::now();
+        let result = session.wait_ready(Duration::from_millis(300)).await;
+        assert!(started.elapsed() >= Duration::from_millis(300));
+        assert!(started.elapsed() < Duration::from_secs(2));
+        match result {
+            Err(BareunError::Timeout { timeout, .. }) => {
+                assert_eq!(timeout, Some(Duration::from_millis(300)))
+            }
+            other => panic!("expected Timeout, got {:?}", other.err()),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_ready_on_eager_connection() {
+        let port = free_port();
+        serve_on(port);
+        let tokenizer = Tokenizer::from_config(config(port)).await.unwrap();
+        assert!(tokenizer.ready().await.is_ok());
+    }
+}
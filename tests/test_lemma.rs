@@ -0,0 +1,103 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use bareun_rs::model::Tag;
+    use bareun_rs::{LemmaOptions, Tagged, lemmatize};
+
+    use crate::common;
+
+    /// 서버가 기본형을 주지 않은 응답
+    fn tagged() -> Tagged {
+        let mut tagged = common::tagged(
+            "\"친구가 학생이다\" 카톡했다 먹어보았다 깨끗한 방.",
+            &[
+                ("\"친구가", &[("\"", "SS"), ("친구", "NNG"), ("가", "JKS")]),
+                (
+                    "학생이다\"",
+                    &[("학생", "NNG"), ("이", "VCP"), ("다", "EF"), ("\"", "SS")],
+                ),
+                (
+                    "카톡했다",
+                    &[("카톡", "NNG"), ("하", "XSV"), ("았", "EP"), ("다", "EF")],
+                ),
+                (
+                    "먹어보았다",
+                    &[
+                        ("먹", "VV"),
+                        ("어", "EC"),
+                        ("보", "VX"),
+                        ("았", "EP"),
+                        ("다", "EF"),
+                    ],
+                ),
+                ("깨끗한", &[("깨끗", "XR"), ("하", "XSA"), ("ㄴ", "ETM")]),
+                ("방.", &[("방", "NNG"), (".", "SF")]),
+            ],
+        );
+        for token in &mut tagged.r.sentences[0].tokens {
+            token.lemma.clear();
+        }
+        tagged
+    }
+
+    #[test]
+    fn test_lemmas_from_rules() {
+        let tagged = tagged();
+        assert_eq!(
+            tagged.lemmas(),
+            vec!["친구", "학생이다", "카톡하다", "먹다", "깨끗하다", "방"]
+        );
+        let opts = LemmaOptions::new()
+            .keep_derivation(false)
+            .keep_copula(false);
+        assert_eq!(
+            tagged.lemmas_with(&opts),
+            vec!["친구", "학생", "카톡", "먹다", "깨끗", "방"]
+        );
+    }
+
+    #[test]
+    fn test_server_lemma_is_preferred() {
+        let mut tagged = tagged();
+        tagged.r.sentences[0].tokens[2].lemma = "카톡하다".to_string();
+        tagged.r.sentences[0].tokens[3].lemma = "먹어보다".to_string();
+        let eojeols: Vec<_> = tagged.eojeols().collect();
+        assert_eq!(eojeols[3].lemma(), "먹어보다");
+        assert_eq!(eojeols[2].lemma(), "카톡하다");
+        // 옵션이 빼라는 형태소가 있으면 규칙으로 만든다.
+        let opts = LemmaOptions::new().keep_derivation(false);
+        assert_eq!(eojeols[2].lemma_with(&opts), "카톡");
+        assert_eq!(eojeols[3].lemma_with(&opts), "먹어보다");
+    }
+
+    #[test]
+    fn test_lemmatize() {
+        let opts = LemmaOptions::default();
+        assert_eq!(
+            lemmatize([("가", Tag::Vv), ("았", Tag::Ep), ("다", Tag::Ef)], &opts),
+            "가다"
+        );
+        assert_eq!(
+            lemmatize([("예쁘", Tag::Va), ("게", Tag::Ec)], &opts),
+            "예쁘다"
+        );
+        assert_eq!(
+            lemmatize(
+                [("헛", Tag::Xpn), ("디디", Tag::Vv), ("었", Tag::Ep)],
+                &opts
+            ),
+            "헛디디다"
+        );
+        assert_eq!(
+            lemmatize(
+                [("학생", Tag::Nng), ("들", Tag::Xsn), ("이", Tag::Jks)],
+                &opts
+            ),
+            "학생들"
+        );
+        assert_eq!(lemmatize([("!", Tag::Sf)], &opts), "");
+        assert_eq!(lemmatize([("은", Tag::Jx)], &opts), "");
+        assert_eq!(lemmatize([], &opts), "");
+    }
+}
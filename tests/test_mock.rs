@@ -0,0 +1,340 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    use bareun_rs::bareun::CorrectErrorResponse;
+    use bareun_rs::mock::{MockMessage, MockMethod, MockServer, analyze_syntax_response};
+    use bareun_rs::{
+        BareunClientConfig, BareunError, BareunSession, CallOptions, Corrector, CustomDict,
+        RetryPolicy, StreamRevisionEvent, Tagger,
+    };
+    use tokio_stream::StreamExt;
+    use tonic::Status;
+
+    const API_KEY: &str = "koba-ABCDEFG-1234567-LMNOPQR-7654321";
+
+    #[tokio::test]
+    async fn test_canned_and_fallback_responses() {
+        let server = MockServer::start().await.unwrap();
+        server.on_analyze_syntax(
+            "나비가 난다",
+            analyze_syntax_response(
+                "나비가 난다",
+                &[
+                    ("나비가", &[("나비", "NNG"), ("가", "JKS")]),
+                    ("난다", &[("날", "VV"), ("ㄴ다", "EF")]),
+                ],
+            ),
+        );
+        let tagger = Tagger::from_config(server.config(API_KEY), vec![])
+            .await
+            .unwrap();
+
+        let tagged = tagger.tag("나비가 난다", false, true, false).await.unwrap();
+        assert_eq!(tagged.morphs(), vec!["나비", "가", "날", "ㄴ다"]);
+        assert_eq!(
+            tagged.msg().sentences[0].tokens[0].tagged,
+            "나비/NNG+가/JKS"
+        );
+        let offsets: Vec<i32> = tagged.msg().sentences[0]
+            .tokens
+            .iter()
+            .map(|t| t.text.as_ref().unwrap().begin_offset)
+            .collect();
+        assert_eq!(offsets, vec![0, 4]);
+
+        // 등록하지 않은 문장은 어절마다 형태소 하나로 분석한다.
+        assert_eq!(
+            tagger.morphs("처음 보는 문장").await.unwrap(),
+            vec!["처음", "보는", "문장"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_records_requests() {
+        let server = MockServer::start().await.unwrap();
+        let tagger = Tagger::from_config(server.config(API_KEY), vec!["my".to_string()])
+            .await
+            .unwrap();
+        tagger
+            .tag_with(
+                "오늘은",
+                false,
+                true,
+                false,
+                &CallOptions::new().timeout(Duration::from_secs(5)),
+            )
+            .await
+            .unwrap();
+
+        let requests = server.requests_for(MockMethod::AnalyzeSyntax);
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].api_key(), Some(API_KEY));
+        assert!(requests[0].grpc_timeout().is_some());
+        match &requests[0].message {
+            MockMessage::AnalyzeSyntax(req) => {
+                assert_eq!(req.document.as_ref().unwrap().content, "오늘은");
+                assert_eq!(req.custom_dict_names, vec!["my"]);
+            }
+            other => panic!("unexpected message {:?}", other),
+        }
+
+        server.clear_requests();
+        assert!(server.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_injected_errors() {
+        let server = MockServer::start().await.unwrap();
+        let tagger = Tagger::from_config(server.config(API_KEY), vec![])
+            .await
+            .unwrap();
+
+        server.fail_times(
+            MockMethod::AnalyzeSyntax,
+            1,
+            Status::permission_denied("denied"),
+        );
+        assert!(matches!(
+            tagger
+                .tag("오늘은", false, true, false)
+                .await
+                .err()
+                .unwrap(),
+            BareunError::PermissionDenied { .. }
+        ));
+        assert!(tagger.tag("오늘은", false, true, false).await.is_ok());
+
+        server.fail(MockMethod::AnalyzeSyntax, Status::unavailable("down"));
+        for _ in 0..2 {
+            assert!(matches!(
+                tagger
+                    .tag("오늘은", false, true, false)
+                    .await
+                    .err()
+                    .unwrap(),
+                BareunError::ServerUnavailable { .. }
+            ));
+        }
+        // 다른 메서드에는 영향이 없다.
+        assert!(
+            tagger
+                .taglist(&["오늘은".to_string()], true, false)
+                .await
+                .is_ok()
+        );
+
+        server.reset();
+        assert!(tagger.tag("오늘은", false, true, false).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_retry_against_mock() {
+        let server = MockServer::start().await.unwrap();
+        server.fail_times(MockMethod::AnalyzeSyntax, 2, Status::unavailable("busy"));
+        let config = BareunClientConfig::builder(API_KEY)
+            .host("127.0.0.1")
+            .port(server.port())
+            .retry_policy(
+                RetryPolicy::new()
+                    .max_attempts(3)
+                    .initial_backoff(Duration::from_millis(1)),
+            )
+            .build();
+        let tagger = Tagger::from_config(config, vec![]).await.unwrap();
+        assert!(tagger.tag("오늘은", false, true, false).await.is_ok());
+        assert_eq!(server.requests_for(MockMethod::AnalyzeSyntax).len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_delay() {
+        let server = MockServer::start().await.unwrap();
+        server.delay(MockMethod::AnalyzeSyntax, Duration::from_millis(300));
+        let tagger = Tagger::from_config(server.config(API_KEY), vec![])
+            .await
+            .unwrap();
+        let e = tagger
+            .tag_with(
+                "오늘은",
+                false,
+                true,
+                false,
+                &CallOptions::new().timeout(Duration::from_millis(50)),
+            )
+            .await
+            .err()
+            .unwrap();
+        assert!(matches!(e, BareunError::Timeout { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_call_options_reach_custom_dict_and_tokenize_list() {
+        let server = MockServer::start().await.unwrap();
+        server.delay(
+            MockMethod::GetCustomDictionaryList,
+            Duration::from_millis(300),
+        );
+        server.delay(MockMethod::CheckConflict, Duration::from_millis(300));
+        server.delay(MockMethod::Tokenize, Duration::from_millis(300));
+        let session = BareunSession::connect(server.config(API_KEY))
+            .await
+            .unwrap();
+        let opts = CallOptions::new().timeout(Duration::from_millis(50));
+
+        let client = session.custom_dict_client();
+        assert!(matches!(
+            client.get_list_with(&opts).await,
+            Err(BareunError::Timeout { .. })
+        ));
+        assert!(matches!(
+            client.check_conflict_with(&["my".to_string()], &opts).await,
+            Err(BareunError::Timeout { .. })
+        ));
+        assert!(client.get_list().await.is_ok());
+
+        let tokenizer = session.tokenizer();
+        assert!(matches!(
+            tokenizer
+                .tokenize_list_with(&["오늘은".to_string()], &opts)
+                .await
+                .err()
+                .unwrap(),
+            BareunError::Timeout { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_require_api_key() {
+        let server = MockServer::start().await.unwrap();
+        server.require_api_key(API_KEY);
+        let tagger = Tagger::from_config(server.config("koba-WRONG-KEY-0000000"), vec![])
+            .await
+            .unwrap();
+        assert!(matches!(
+            tagger
+                .tag("오늘은", false, true, false)
+                .await
+                .err()
+                .unwrap(),
+            BareunError::PermissionDenied { .. }
+        ));
+    }
+
+    /// `target`으로 이어 주면서 받은 TCP 연결 수를 세는 프록시
+    async fn counting_proxy(target: std::net::SocketAddr) -> (u16, Arc<AtomicUsize>) {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let accepted = Arc::new(AtomicUsize::new(0));
+        let count = accepted.clone();
+        tokio::spawn(async move {
+            while let Ok((mut inbound, _)) = listener.accept().await {
+                count.fetch_add(1, Ordering::SeqCst);
+                tokio::spawn(async move {
+                    let mut outbound = tokio::net::TcpStream::connect(target).await.unwrap();
+                    let _ = tokio::io::copy_bidirectional(&mut inbound, &mut outbound).await;
+                });
+            }
+        });
+        (port, accepted)
+    }
+
+    #[tokio::test]
+    async fn test_custom_dict_with_config_reuses_connection() {
+        let server = MockServer::start().await.unwrap();
+        let (port, accepted) = counting_proxy(server.addr()).await;
+        let config = BareunClientConfig::new(API_KEY, "127.0.0.1", Some(port));
+
+        let mut dict = CustomDict::with_config("my", config.clone()).unwrap();
+        dict.copy_np_set(["바른".to_string()].into_iter().collect());
+        assert!(dict.update().await.unwrap());
+        dict.get().await.unwrap();
+        dict.load().await.unwrap();
+        dict.clear().await.unwrap();
+        assert_eq!(accepted.load(Ordering::SeqCst), 1);
+
+        // set_connection도 클라이언트를 한 번만 만든다.
+        let mut dict = CustomDict::new("other").unwrap();
+        dict.set_connection(API_KEY, "127.0.0.1", i32::from(port));
+        assert!(dict.update().await.unwrap());
+        dict.get().await.unwrap();
+        dict.clear().await.unwrap();
+        assert_eq!(accepted.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_custom_dictionary_store() {
+        let server = MockServer::start().await.unwrap();
+        let session = BareunSession::connect(server.config(API_KEY))
+            .await
+            .unwrap();
+        let mut dict = session.custom_dict("my").unwrap();
+        dict.copy_np_set(["바른".to_string()].into_iter().collect());
+        assert!(dict.update().await.unwrap());
+        assert!(server.custom_dictionary("my").is_some());
+
+        let list = session.custom_dict_client().get_list().await.unwrap();
+        assert_eq!(list.len(), 1);
+        assert_eq!(list[0].np_set.as_ref().unwrap().items_count, 1);
+
+        dict.np_set.clear();
+        dict.load().await.unwrap();
+        assert!(dict.np_set.contains("바른"));
+
+        assert_eq!(dict.clear().await.unwrap(), vec!["my"]);
+        assert!(matches!(
+            dict.get().await,
+            Err(BareunError::NotFound { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_corrector() {
+        let server = MockServer::start().await.unwrap();
+        server.on_correct_error(
+            "됬다",
+            CorrectErrorResponse {
+                origin: "됬다".to_string(),
+                revised: "됐다".to_string(),
+                ..Default::default()
+            },
+        );
+        let corrector = Corrector::from_config(server.config(API_KEY))
+            .await
+            .unwrap();
+
+        let res = corrector.correct_error("됬다", &[], None).await.unwrap();
+        assert_eq!(res.revised, "됐다");
+        let res = corrector.correct_error("맞다", &[], None).await.unwrap();
+        assert_eq!(res.revised, "맞다");
+
+        let mut stream = corrector
+            .stream_correct_error("됬다", &[], None, 7)
+            .await
+            .unwrap();
+        match stream
+            .next()
+            .await
+            .transpose()
+            .unwrap()
+            .and_then(StreamRevisionEvent::from_message)
+        {
+            Some(StreamRevisionEvent::First(first)) => {
+                assert_eq!(first.revised, "됐다");
+                assert_eq!(first.req_id, 7);
+            }
+            _ => panic!("expected the first revision"),
+        }
+        assert!(stream.next().await.transpose().unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_drop_stops_server() {
+        let server = MockServer::start().await.unwrap();
+        let config = server.config(API_KEY);
+        drop(server);
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(Tagger::from_config(config, vec![]).await.is_err());
+    }
+}
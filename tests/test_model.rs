@@ -0,0 +1,122 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use bareun_rs::bareun::morpheme::OutOfVocab;
+    use bareun_rs::model::{Document, OovKind, Span, Tag};
+    use bareun_rs::{BareunError, Tagged};
+
+    use crate::common;
+
+    fn tagged() -> Tagged {
+        let mut tagged = common::tagged(
+            "나비가 난다",
+            &[
+                ("나비가", &[("나비", "NNG"), ("가", "JKS")]),
+                ("난다", &[("날", "VV"), ("ㄴ다", "EF")]),
+            ],
+        );
+        let m = &mut tagged.r.sentences[0].tokens[0].morphemes[0];
+        m.probability = 0.5;
+        m.out_of_vocab = OutOfVocab::InCustomDict as i32;
+        m.custom_dict_name = Some("my".to_string());
+        tagged
+    }
+
+    #[test]
+    fn test_document_from_tagged() {
+        let doc = tagged().document().unwrap();
+        assert_eq!(doc.text, "나비가 난다");
+        assert_eq!(doc.language, "ko_KR");
+        assert_eq!(doc.sentences.len(), 1);
+
+        let sentence = &doc.sentences[0];
+        assert_eq!(sentence.span, Span::new(0, 6));
+        assert_eq!(sentence.refined, None);
+        assert_eq!(sentence.eojeols[1].text, "난다");
+        assert_eq!(sentence.eojeols[1].span, Span::new(4, 2));
+        assert_eq!(sentence.eojeols[0].tagged, "나비/NNG+가/JKS");
+
+        let morphemes: Vec<_> = doc.morphemes().collect();
+        assert_eq!(morphemes.len(), 4);
+        assert_eq!(morphemes[0].tag, Tag::Nng);
+        assert_eq!(morphemes[0].oov, OovKind::InCustomDict);
+        assert_eq!(morphemes[0].custom_dict.as_deref(), Some("my"));
+        assert_eq!(morphemes[0].probability, 0.5);
+        assert_eq!(morphemes[1].span, Span::new(2, 1));
+        assert_eq!(morphemes[3].tag, Tag::Ef);
+        assert_eq!(morphemes[3].oov, OovKind::InWordEmbedding);
+    }
+
+    #[test]
+    fn test_negative_offsets_become_empty_spans() {
+        let mut tagged = tagged();
+        for sentence in &mut tagged.r.sentences {
+            sentence.text.as_mut().unwrap().begin_offset = -1;
+            for token in &mut sentence.tokens {
+                token.text.as_mut().unwrap().begin_offset = -1;
+                for m in &mut token.morphemes {
+                    m.text.as_mut().unwrap().begin_offset = -1;
+                    m.text.as_mut().unwrap().length = -1;
+                }
+            }
+        }
+
+        let doc = tagged.document().unwrap();
+        assert_eq!(doc.sentences[0].span, Span::default());
+        assert_eq!(doc.sentences[0].eojeols[1].span, Span::default());
+        assert!(doc.morphemes().all(|m| m.span == Span::default()));
+
+        let eojeols: Vec<_> = tagged.eojeols().collect();
+        assert_eq!(eojeols[1].span(), Span::default());
+        assert!(eojeols[1].morphemes().all(|m| m.span() == Span::default()));
+    }
+
+    #[test]
+    fn test_json_shape_and_round_trip() {
+        let doc = tagged().document().unwrap();
+        let json = serde_json::to_value(&doc).unwrap();
+        let m = &json["sentences"][0]["eojeols"][0]["morphemes"][0];
+        assert_eq!(m["tag"], "NNG");
+        assert_eq!(m["oov"], "in_custom_dict");
+        assert_eq!(m["custom_dict"], "my");
+        assert_eq!(m["span"]["begin"], 0);
+        assert_eq!(m["span"]["length"], 2);
+        // 값이 없는 선택 필드는 쓰지 않는다.
+        assert!(json["sentences"][0].get("refined").is_none());
+        assert!(
+            json["sentences"][0]["eojeols"][0]["morphemes"][1]
+                .get("custom_dict")
+                .is_none()
+        );
+
+        let back: Document = serde_json::from_value(json).unwrap();
+        assert_eq!(back, doc);
+    }
+
+    #[test]
+    fn test_tag_names() {
+        assert_eq!(Tag::ALL.len(), 48);
+        for tag in Tag::ALL {
+            assert_eq!(tag.as_str().parse::<Tag>().unwrap(), tag);
+            assert_eq!(
+                serde_json::to_value(tag).unwrap(),
+                serde_json::Value::String(tag.to_string())
+            );
+        }
+        assert_eq!("nnp".parse::<Tag>().unwrap(), Tag::Nnp);
+        assert!("XYZ".parse::<Tag>().is_err());
+        assert_eq!(Tag::from_proto(9999), Tag::Unk);
+        assert_eq!(Tag::Vv.description(), "동사");
+    }
+
+    #[test]
+    fn test_missing_text_is_malformed() {
+        let mut t = tagged();
+        t.r.sentences[0].tokens[1].morphemes[0].text = None;
+        assert!(matches!(
+            t.document(),
+            Err(BareunError::MalformedResponse(_))
+        ));
+    }
+}
@@ -0,0 +1,187 @@
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use bareun_rs::bareun::{
+        AnalyzeSyntaxResponse, Morpheme, Segment, SegmentSentence, SegmentToken, Sentence,
+        TextSpan, Token, TokenizeResponse,
+    };
+    use bareun_rs::{
+        BareunClientConfig, BareunError, BareunSession, CustomDict, SegResult, Tagged, Tagger,
+        Tokenized, read_dic_file,
+    };
+
+    const API_KEY: &str = "koba-ABCDEFG-1234567-LMNOPQR-7654321";
+
+    fn temp_file(name: &str, content: &[u8]) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("bareun_rs_{}_{}", std::process::id(), name));
+        std::fs::write(&path, content).unwrap();
+        path
+    }
+
+    fn text(content: &str) -> Option<TextSpan> {
+        Some(TextSpan {
+            content: content.to_string(),
+            ..Default::default()
+        })
+    }
+
+    fn lazy_config(apikey: &str) -> BareunClientConfig {
+        let dead = {
+            let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+            listener.local_addr().unwrap().port()
+        };
+        BareunClientConfig::new(apikey, "127.0.0.1", Some(dead))
+    }
+
+    #[test]
+    fn test_read_dic_file_missing() {
+        let e = read_dic_file("/nonexistent/bareun/dict.txt").unwrap_err();
+        assert!(
+            matches!(e, BareunError::DictFile { ref path, .. } if path == "/nonexistent/bareun/dict.txt")
+        );
+    }
+
+    #[test]
+    fn test_read_dic_file_invalid_utf8() {
+        let path = temp_file("invalid_utf8.txt", b"\xed\x8c\x8c\xec\x9d\xbc\n\xff\xfe\n");
+        let e = read_dic_file(path.to_str().unwrap()).unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+        assert!(matches!(e, BareunError::DictFile { .. }));
+    }
+
+    #[test]
+    fn test_read_dic_file() {
+        let path = temp_file("dict.txt", "# 주석\n코로나19\n\n  K방역  \n".as_bytes());
+        let set = read_dic_file(path.to_str().unwrap()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(set.len(), 2);
+        assert!(set.contains("코로나19"));
+        assert!(set.contains("K방역"));
+
+        let mut dict = CustomDict::new("my").unwrap();
+        assert!(matches!(
+            dict.read_np_set_from_file("/nonexistent/bareun/np.txt"),
+            Err(BareunError::DictFile { .. })
+        ));
+    }
+
+    #[test]
+    fn test_custom_dict_empty_name() {
+        assert!(matches!(
+            CustomDict::new(""),
+            Err(BareunError::InvalidCustomDictName(_))
+        ));
+        assert!(matches!(
+            CustomDict::with_config("", BareunClientConfig::new(API_KEY, "localhost", None)),
+            Err(BareunError::InvalidCustomDictName(_))
+        ));
+        assert!(matches!(
+            CustomDict::with_connection("", API_KEY, "localhost", 5656),
+            Err(BareunError::InvalidCustomDictName(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_tagger_custom_dict_empty_name() {
+        let mut tagger = Tagger::connect_lazy(lazy_config(API_KEY), vec![]).unwrap();
+        assert!(matches!(
+            tagger.custom_dict(""),
+            Err(BareunError::InvalidCustomDictName(_))
+        ));
+        assert_eq!(tagger.custom_dict("my").unwrap().domain, "my");
+
+        let session = BareunSession::connect_lazy(lazy_config(API_KEY)).unwrap();
+        assert!(session.custom_dict("").is_err());
+    }
+
+    #[test]
+    fn test_pos_without_text() {
+        let res = AnalyzeSyntaxResponse {
+            sentences: vec![Sentence {
+                tokens: vec![Token {
+                    morphemes: vec![
+                        Morpheme {
+                            text: text("오늘"),
+                            ..Default::default()
+                        },
+                        Morpheme::default(),
+                    ],
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let tagged = Tagged::new("오늘은".to_string(), res);
+        for flatten in [true, false] {
+            assert!(matches!(
+                tagged.pos(flatten, true, true),
+                Err(BareunError::MalformedResponse(_))
+            ));
+        }
+        assert_eq!(tagged.morphs(), vec!["오늘"]);
+    }
+
+    #[test]
+    fn test_seg_without_text() {
+        let res = TokenizeResponse {
+            sentences: vec![SegmentSentence {
+                tokens: vec![SegmentToken {
+                    segments: vec![
+                        Segment {
+                            text: text("오늘"),
+                            hint: "N".to_string(),
+                        },
+                        Segment::default(),
+                    ],
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let tokenized = Tokenized::new("오늘은".to_string(), res);
+        for flatten in [true, false] {
+            assert!(matches!(
+                tokenized.seg(flatten, false, false),
+                Err(BareunError::MalformedResponse(_))
+            ));
+        }
+        assert_eq!(tokenized.segments(), vec!["오늘"]);
+
+        let res = TokenizeResponse {
+            sentences: vec![SegmentSentence {
+                tokens: vec![SegmentToken {
+                    segments: vec![Segment {
+                        text: text("오늘"),
+                        hint: "N".to_string(),
+                    }],
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        match Tokenized::new("오늘".to_string(), res).seg(true, true, false) {
+            Ok(SegResult::Flat(v)) => assert_eq!(v, vec!["오늘/N"]),
+            _ => panic!("expected a flat result"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_non_ascii_api_key() {
+        let tagger =
+            Tagger::connect_lazy(lazy_config("koba-한글키-1234567-7654321"), vec![]).unwrap();
+        let e = tagger
+            .tag("오늘은", false, true, false)
+            .await
+            .err()
+            .unwrap();
+        assert!(matches!(
+            e,
+            BareunError::InvalidArgument { status: None, .. }
+        ));
+        assert!(!e.to_string().contains("한글키"));
+    }
+}
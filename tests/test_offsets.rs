@@ -0,0 +1,106 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use bareun_rs::model::Span;
+    use bareun_rs::{BareunError, OffsetIndex, Tagged};
+
+    use crate::common;
+
+    const PHRASE: &str = "🦋 나비가 a난다";
+
+    fn tagged() -> Tagged {
+        common::tagged(
+            PHRASE,
+            &[
+                ("🦋", &[("🦋", "SW")]),
+                ("나비가", &[("나비", "NNG"), ("가", "JKS")]),
+                ("a난다", &[("a", "SL"), ("난다", "VV")]),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_offset_index() {
+        let index = OffsetIndex::new(PHRASE);
+        assert_eq!(index.char_len(), 9);
+
+        let range = index.range(Span::new(2, 3)).unwrap();
+        assert_eq!(range.chars, 2..5);
+        // 🦋는 UTF-8 4바이트, UTF-16 2단위이고 한글은 UTF-8 3바이트, UTF-16 1단위다.
+        assert_eq!(range.utf8, 5..14);
+        assert_eq!(range.utf16, 3..6);
+        assert_eq!(&PHRASE[range.utf8], "나비가");
+
+        let end = index.range(Span::new(9, 0)).unwrap();
+        assert_eq!(end.utf8, PHRASE.len()..PHRASE.len());
+        assert_eq!(
+            end.utf16,
+            PHRASE.encode_utf16().count()..PHRASE.encode_utf16().count()
+        );
+
+        assert!(matches!(
+            index.range(Span::new(7, 3)),
+            Err(BareunError::InvalidArgument { .. })
+        ));
+    }
+
+    #[test]
+    fn test_view_ranges() {
+        let tagged = tagged();
+        let index = tagged.offset_index();
+
+        let eojeols: Vec<_> = tagged.eojeols().collect();
+        let range = eojeols[2].range(&index).unwrap();
+        assert_eq!(range.chars, 6..9);
+        assert_eq!(range.utf16, 7..10);
+        assert_eq!(tagged.slice(&range).unwrap(), "a난다");
+
+        let sentence = tagged.sentences_iter().next().unwrap();
+        assert_eq!(
+            tagged.slice(&sentence.range(&index).unwrap()).unwrap(),
+            PHRASE
+        );
+
+        for m in tagged.morphemes() {
+            let range = m.range(&index).unwrap();
+            assert_eq!(tagged.slice(&range).unwrap(), m.text());
+            let utf16: Vec<u16> = PHRASE.encode_utf16().collect();
+            assert_eq!(String::from_utf16(&utf16[range.utf16]).unwrap(), m.text());
+        }
+    }
+
+    #[test]
+    fn test_validate_offsets() {
+        assert!(tagged().validate_offsets().is_ok());
+
+        // 어절 위치가 한 칸 밀린 응답
+        let mut shifted = tagged();
+        shifted.r.sentences[0].tokens[1]
+            .text
+            .as_mut()
+            .unwrap()
+            .begin_offset = 3;
+        let e = shifted.validate_offsets().unwrap_err();
+        assert!(matches!(e, BareunError::MalformedResponse(_)));
+        assert!(e.to_string().contains("token 1 of sentence 0"), "{}", e);
+
+        // 어절 밖으로 나간 형태소
+        let mut outside = tagged();
+        outside.r.sentences[0].tokens[1].morphemes[1]
+            .text
+            .as_mut()
+            .unwrap()
+            .begin_offset = 6;
+        let e = outside.validate_offsets().unwrap_err();
+        assert!(e.to_string().contains("morpheme 1 of token 1"), "{}", e);
+
+        // 입력보다 긴 위치
+        let mut long = tagged();
+        long.r.sentences[0].tokens[2].text.as_mut().unwrap().length = 10;
+        assert!(matches!(
+            long.validate_offsets(),
+            Err(BareunError::MalformedResponse(_))
+        ));
+    }
+}
@@ -0,0 +1,92 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use bareun_rs::Tagged;
+    use bareun_rs::model::{Tag, TagCategory};
+
+    use crate::common;
+
+    fn tagged() -> Tagged {
+        common::tagged(
+            "아! 이 새 차는 정말 예쁘게 빛나는군요.",
+            &[
+                ("아!", &[("아", "IC"), ("!", "SF")]),
+                ("이", &[("이", "MMD")]),
+                ("새", &[("새", "MMA")]),
+                ("차는", &[("차", "NNG"), ("는", "JX")]),
+                ("정말", &[("정말", "MAG")]),
+                ("예쁘게", &[("예쁘", "VA"), ("게", "EC")]),
+                (
+                    "빛나는군요.",
+                    &[("빛나", "VV"), ("는군요", "EF"), (".", "SF")],
+                ),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_every_tag_has_one_category() {
+        let mut total = 0;
+        for category in [
+            TagCategory::Substantive,
+            TagCategory::Predicate,
+            TagCategory::Prenoun,
+            TagCategory::Adverb,
+            TagCategory::Interjection,
+            TagCategory::Particle,
+            TagCategory::Ending,
+            TagCategory::Affix,
+            TagCategory::Root,
+            TagCategory::Symbol,
+            TagCategory::Unknown,
+        ] {
+            for tag in category.tags() {
+                assert_eq!(tag.category(), category);
+                total += 1;
+            }
+        }
+        assert_eq!(total, Tag::ALL.len());
+    }
+
+    #[test]
+    fn test_predicates() {
+        assert!(Tag::Nr.is_substantive());
+        assert!(!Tag::Nr.is_noun());
+        assert!(Tag::Np.is_noun());
+        assert!(Tag::Vcp.is_predicate());
+        assert!(Tag::Jkb.is_particle());
+        assert!(Tag::Etm.is_ending());
+        assert!(Tag::Xsv.is_affix());
+        assert!(Tag::Sn.is_symbol());
+        assert!(Tag::Mmd.is_modifier() && Tag::Maj.is_modifier());
+        assert!(Tag::Xr.is_content());
+        assert!(!Tag::Jx.is_content());
+        assert_eq!(TagCategory::Particle.tags().count(), 9);
+        // `Tokenized`의 hint와 같은 글자
+        assert_eq!(Tag::Mag.category().hint(), 'A');
+        assert_eq!(Tag::Mmd.category().hint(), 'M');
+        assert_eq!(Tag::Vx.category().hint(), 'V');
+    }
+
+    #[test]
+    fn test_tagged_extractors() {
+        let tagged = tagged();
+        assert_eq!(tagged.nouns(), vec!["차"]);
+        assert_eq!(tagged.substantives(), vec!["차"]);
+        assert_eq!(tagged.verbs(), vec!["빛나"]);
+        assert_eq!(tagged.adjectives(), vec!["예쁘"]);
+        assert_eq!(tagged.predicates(), vec!["예쁘", "빛나"]);
+        assert_eq!(tagged.adverbs(), vec!["정말"]);
+        assert_eq!(tagged.prenouns(), vec!["이", "새"]);
+        assert_eq!(tagged.postpositions(), vec!["는"]);
+        assert_eq!(tagged.interjections(), vec!["아"]);
+        assert_eq!(tagged.endings(), vec!["게", "는군요"]);
+        assert!(tagged.affixes().is_empty());
+        assert_eq!(tagged.symbols(), vec!["!", "."]);
+        assert_eq!(
+            tagged.filter_by(|tag| tag.is_content() && !tag.is_predicate()),
+            vec!["아", "이", "새", "차", "정말"]
+        );
+    }
+}
@@ -0,0 +1,234 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    use bareun_rs::bareun::language_service_server::{LanguageService, LanguageServiceServer};
+    use bareun_rs::bareun::{
+        AnalyzeSyntaxListRequest, AnalyzeSyntaxListResponse, AnalyzeSyntaxRequest,
+        AnalyzeSyntaxResponse, TokenizeRequest, TokenizeResponse,
+    };
+    use bareun_rs::{BareunClientConfig, BareunError, RetryPolicy, Tagger, Tokenizer};
+    use tonic::transport::Server;
+    use tonic::transport::server::TcpIncoming;
+    use tonic::{Code, Request, Response, Status};
+
+    /// 처음 `failures`번은 `code`로 실패하고 그 뒤로는 빈 결과를 돌려주는 서버
+    struct FlakyLanguageService {
+        failures: usize,
+        code: Code,
+        delay: Duration,
+        calls: Arc<AtomicUsize>,
+    }
+
+    impl FlakyLanguageService {
+        async fn check(&self) -> Result<(), Status> {
+            tokio::time::sleep(self.delay).await;
+            let n = self.calls.fetch_add(1, Ordering::SeqCst);
+            if n < self.failures {
+                Err(Status::new(self.code, "container restarting"))
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    #[tonic::async_trait]
+    impl LanguageService for FlakyLanguageService {
+        async fn analyze_syntax(
+            &self,
+            _request: Request<AnalyzeSyntaxRequest>,
+        ) -> Result<Response<AnalyzeSyntaxResponse>, Status> {
+            self.check().await?;
+            Ok(Response::new(AnalyzeSyntaxResponse::default()))
+        }
+
+        async fn analyze_syntax_list(
+            &self,
+            _request: Request<AnalyzeSyntaxListRequest>,
+        ) -> Result<Response<AnalyzeSyntaxListResponse>, Status> {
+            self.check().await?;
+            Ok(Response::new(AnalyzeSyntaxListResponse::default()))
+        }
+
+        async fn tokenize(
+            &self,
+            _request: Request<TokenizeRequest>,
+        ) -> Result<Response<TokenizeResponse>, Status> {
+            self.check().await?;
+            Ok(Response::new(TokenizeResponse::default()))
+        }
+    }
+
+    async fn spawn_server(failures: usize, code: Code) -> (u16, Arc<AtomicUsize>) {
+        spawn_slow_server(failures, code, Duration::ZERO).await
+    }
+
+    async fn spawn_slow_server(
+        failures: usize,
+        code: Code,
+        delay: Duration,
+    ) -> (u16, Arc<AtomicUsize>) {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let service = FlakyLanguageService {
+            failures,
+            code,
+            delay,
+            calls: calls.clone(),
+        };
+        let incoming = TcpIncoming::bind("127.0.0.1:0".parse().unwrap()).unwrap();
+        let port = incoming.local_addr().unwrap().port();
+        tokio::spawn(
+            Server::builder()
+                .add_service(LanguageServiceServer::new(service))
+                .serve_with_incoming(incoming),
+        );
+        (port, calls)
+    }
+
+    fn config(port: u16, policy: RetryPolicy) -> BareunClientConfig {
+        BareunClientConfig::builder("appppppiiii")
+            .host("127.0.0.1")
+            .port(port)
+            .retry_policy(policy)
+            .build()
+    }
+
+    fn fast_policy() -> RetryPolicy {
+        RetryPolicy::default()
+            .initial_backoff(Duration::from_millis(10))
+            .max_backoff(Duration::from_millis(50))
+    }
+
+    #[tokio::test]
+    async fn test_retry_recovers_from_unavailable() {
+        let (port, calls) = spawn_server(2, Code::Unavailable).await;
+        let tagger = Tagger::from_config(config(port, fast_policy()), vec![])
+            .await
+            .unwrap();
+
+        let result = tagger
+            .tag("오늘은 정말 추운 날이네요.", false, true, false)
+            .await;
+        assert!(result.is_ok());
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_retry_applies_to_tokenize() {
+        let (port, calls) = spawn_server(1, Code::Unavailable).await;
+        let tokenizer = Tokenizer::from_config(config(port, fast_policy()))
+            .await
+            .unwrap();
+
+        assert!(tokenizer.tokenize("오늘은", false).await.is_ok());
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_retry_gives_up_after_max_attempts() {
+        let (port, calls) = spawn_server(10, Code::Unavailable).await;
+        let tagger = Tagger::from_config(config(port, fast_policy().max_attempts(3)), vec![])
+            .await
+            .unwrap();
+
+        let result = tagger.tag("오늘은", false, true, false).await;
+        assert!(matches!(result, Err(BareunError::ServerUnavailable { .. })));
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_retry_skips_non_retryable_codes() {
+        let (port, calls) = spawn_server(1, Code::InvalidArgument).await;
+        let tagger = Tagger::from_config(config(port, fast_policy()), vec![])
+            .await
+            .unwrap();
+
+        let result = tagger.tag("오늘은", false, true, false).await;
+        assert!(matches!(result, Err(BareunError::InvalidArgument { .. })));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_retry_respects_custom_codes() {
+        let (port, calls) = spawn_server(1, Code::ResourceExhausted).await;
+        let policy =
+            fast_policy().retryable_codes(vec![Code::Unavailable, Code::ResourceExhausted]);
+        let tagger = Tagger::from_config(config(port, policy), vec![])
+            .await
+            .unwrap();
+
+        assert!(tagger.tag("오늘은", false, true, false).await.is_ok());
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_retry_stops_at_deadline() {
+        let (port, calls) = spawn_server(10, Code::Unavailable).await;
+        let policy = RetryPolicy::default()
+            .max_attempts(10)
+            .initial_backoff(Duration::from_millis(100))
+            .jitter(0.0)
+            .deadline(Duration::from_millis(250));
+        let tagger = Tagger::from_config(config(port, policy), vec![])
+            .await
+            .unwrap();
+
+        let result = tagger.tag("오늘은", false, true, false).await;
+        assert!(result.is_err());
+        // 100ms 대기 후 두 번째, 200ms 대기는 제한 시간을 넘기므로 중단
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_deadline_caps_in_flight_attempt() {
+        let (port, _) = spawn_slow_server(0, Code::Unavailable, Duration::from_secs(5)).await;
+        let policy = RetryPolicy::default().deadline(Duration::from_millis(300));
+        let tagger = Tagger::from_config(config(port, policy), vec![])
+            .await
+            .unwrap();
+
+        let started = std::time::Instant::now();
+        let result = tagger.tag("오늘은", false, true, false).await;
+        // 요청 제한 시간이 없어도 재시도 제한 시간에서 끊긴다.
+        match result {
+            Err(BareunError::Timeout { timeout, .. }) => {
+                assert!(timeout.unwrap() <= Duration::from_millis(300));
+            }
+            Err(e) => panic!("expected Timeout, got {e:?}"),
+            Ok(_) => panic!("expected Timeout"),
+        }
+        assert!(started.elapsed() < Duration::from_secs(2));
+    }
+
+    #[tokio::test]
+    async fn test_no_retry_without_policy() {
+        let (port, calls) = spawn_server(1, Code::Unavailable).await;
+        let tagger = Tagger::new("appppppiiii", "127.0.0.1", Some(port), vec![])
+            .await
+            .unwrap();
+
+        assert!(tagger.tag("오늘은", false, true, false).await.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_backoff_grows_and_is_capped() {
+        let policy = RetryPolicy::default()
+            .initial_backoff(Duration::from_millis(100))
+            .max_backoff(Duration::from_millis(300))
+            .jitter(0.0);
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3), Duration::from_millis(300));
+        assert_eq!(policy.backoff(1000), Duration::from_millis(300));
+
+        let jittered = RetryPolicy::default()
+            .initial_backoff(Duration::from_millis(100))
+            .jitter(0.5)
+            .backoff(1);
+        assert!(jittered <= Duration::from_millis(100));
+        assert!(jittered >= Duration::from_millis(50));
+    }
+}
@@ -0,0 +1,110 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use bareun_rs::{BareunError, SpacingEdit, SpacingEditKind, Tagged};
+
+    use crate::common;
+
+    fn tagged(phrase: &str) -> Tagged {
+        common::tagged(
+            phrase,
+            &[
+                (
+                    "나비가난다",
+                    &[("나비", "NNG"), ("가", "JKS"), ("날", "VV"), ("ㄴ다", "EF")],
+                ),
+                ("정말", &[("정말", "MAG")]),
+            ],
+        )
+    }
+
+    /// 수정을 뒤에서부터 적용한다. 결과는 refined_text와 같아야 한다.
+    fn apply(phrase: &str, edits: &[SpacingEdit]) -> String {
+        let mut fixed = phrase.to_string();
+        for edit in edits.iter().rev() {
+            fixed.replace_range(
+                edit.range.utf8.clone(),
+                match edit.kind {
+                    SpacingEditKind::Insert | SpacingEditKind::Replace => &edit.text,
+                    SpacingEditKind::Remove => "",
+                },
+            );
+        }
+        fixed
+    }
+
+    #[test]
+    fn test_refined_text_and_edits() {
+        let mut tagged = tagged("나비가난다  정말");
+        tagged.r.sentences[0].refined = "나비가 난다 정말".to_string();
+        assert_eq!(tagged.refined_text().unwrap(), "나비가 난다 정말");
+
+        let edits = tagged.spacing_edits().unwrap();
+        assert_eq!(edits.len(), 2);
+        assert_eq!(edits[0].kind, SpacingEditKind::Insert);
+        assert_eq!(edits[0].range.chars, 3..3);
+        assert_eq!(edits[0].range.utf8, 9..9);
+        assert_eq!(edits[0].text, " ");
+        assert_eq!(edits[1].kind, SpacingEditKind::Remove);
+        assert_eq!(edits[1].range.chars, 6..7);
+        assert_eq!(tagged.slice(&edits[1].range).unwrap(), " ");
+
+        assert_eq!(
+            apply(&tagged.phrase, &edits),
+            tagged.refined_text().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_whitespace_replacement_is_an_edit() {
+        let mut tagged = tagged("나비가난다\t정말");
+        tagged.r.sentences[0].refined = "나비가 난다 정말".to_string();
+
+        let edits = tagged.spacing_edits().unwrap();
+        assert_eq!(edits.len(), 2);
+        assert_eq!(edits[1].kind, SpacingEditKind::Replace);
+        assert_eq!(edits[1].range.chars, 5..6);
+        assert_eq!(tagged.slice(&edits[1].range).unwrap(), "\t");
+        assert_eq!(edits[1].text, " ");
+        assert_eq!(apply(&tagged.phrase, &edits), "나비가 난다 정말");
+    }
+
+    #[test]
+    fn test_refined_from_modified_tokens() {
+        let phrase = "나비가난다 정말";
+        let mut tagged = tagged(phrase);
+        assert_eq!(tagged.refined_text().unwrap(), phrase);
+        assert!(tagged.spacing_edits().unwrap().is_empty());
+
+        tagged.r.sentences[0].tokens[0].modified = "나비가 난다".to_string();
+        assert_eq!(tagged.refined_text().unwrap(), "나비가 난다 정말");
+        let edits = tagged.spacing_edits().unwrap();
+        assert_eq!(edits.len(), 1);
+        assert_eq!(edits[0].range.chars, 3..3);
+    }
+
+    #[test]
+    fn test_multiple_sentences_keep_separators() {
+        let mut tagged = common::tagged_sentences(
+            "가나\n다라마",
+            &[
+                &[("가나", &[("가나", "NNP")])],
+                &[("다라마", &[("다라마", "NNP")])],
+            ],
+        );
+        tagged.r.sentences[1].refined = "다라 마".to_string();
+        assert_eq!(tagged.refined_text().unwrap(), "가나\n다라 마");
+        assert_eq!(tagged.spacing_edits().unwrap()[0].range.chars, 5..5);
+    }
+
+    #[test]
+    fn test_refined_changing_letters_is_rejected() {
+        let mut tagged = tagged("나비가난다 정말");
+        tagged.r.sentences[0].refined = "나비가 난다 진짜".to_string();
+        assert!(matches!(
+            tagged.spacing_edits(),
+            Err(BareunError::MalformedResponse(_))
+        ));
+    }
+}
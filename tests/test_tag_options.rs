@@ -0,0 +1,266 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use bareun_rs::bareun::AnalyzeSyntaxRequest;
+    use bareun_rs::mock::{MockMessage, MockMethod, MockServer};
+    use bareun_rs::{BareunError, BareunSession, CallOptions, TagOptions, Tagger};
+
+    const API_KEY: &str = "koba-ABCDEFG-1234567-LMNOPQR-7654321";
+
+    fn last_request(server: &MockServer) -> AnalyzeSyntaxRequest {
+        match server
+            .requests_for(MockMethod::AnalyzeSyntax)
+            .pop()
+            .unwrap()
+            .message
+        {
+            MockMessage::AnalyzeSyntax(req) => req,
+            other => panic!("unexpected message {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_default_options() {
+        let opts = TagOptions::default();
+        assert!(!opts.auto_split);
+        assert!(opts.auto_spacing);
+        assert!(!opts.auto_jointing);
+        assert_eq!(opts.custom_dicts, None);
+        assert_eq!(opts.timeout, None);
+        assert_eq!(TagOptions::new(), opts);
+    }
+
+    #[tokio::test]
+    async fn test_extractors_use_tagger_options() {
+        let server = MockServer::start().await.unwrap();
+        let mut tagger = Tagger::from_config(server.config(API_KEY), vec!["my".to_string()])
+            .await
+            .unwrap();
+
+        tagger.morphs("오늘은").await.unwrap();
+        let req = last_request(&server);
+        assert!(req.auto_spacing);
+        assert!(!req.auto_split_sentence);
+        assert_eq!(req.custom_dict_names, vec!["my"]);
+
+        tagger.set_options(
+            TagOptions::new()
+                .auto_spacing(false)
+                .auto_jointing(true)
+                .auto_split(true),
+        );
+        assert!(!tagger.options().auto_spacing);
+        server.clear_requests();
+        tagger.morphs("오늘은").await.unwrap();
+        tagger.nouns("오늘은").await.unwrap();
+        tagger.verbs("오늘은").await.unwrap();
+        tagger.pos("오늘은", true, true, false).await.unwrap();
+        let requests = server.requests_for(MockMethod::AnalyzeSyntax);
+        assert_eq!(requests.len(), 4);
+        for request in requests {
+            match request.message {
+                MockMessage::AnalyzeSyntax(req) => {
+                    assert!(!req.auto_spacing);
+                    assert!(req.auto_jointing);
+                    assert!(req.auto_split_sentence);
+                }
+                other => panic!("unexpected message {:?}", other),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_per_call_options() {
+        let server = MockServer::start().await.unwrap();
+        let tagger = Tagger::from_config(server.config(API_KEY), vec!["my".to_string()])
+            .await
+            .unwrap();
+
+        let opts = TagOptions::new()
+            .auto_spacing(false)
+            .custom_dicts(vec!["other".to_string()])
+            .timeout(Duration::from_secs(3));
+        tagger.nouns_with_options("오늘은", &opts).await.unwrap();
+        let req = last_request(&server);
+        assert!(!req.auto_spacing);
+        assert_eq!(req.custom_dict_names, vec!["other"]);
+        assert!(
+            server
+                .requests_for(MockMethod::AnalyzeSyntax)
+                .pop()
+                .unwrap()
+                .grpc_timeout()
+                .is_some()
+        );
+
+        // 호출마다 지정한 옵션은 Tagger의 기본 옵션을 바꾸지 않는다.
+        tagger.morphs("오늘은").await.unwrap();
+        let req = last_request(&server);
+        assert!(req.auto_spacing);
+        assert_eq!(req.custom_dict_names, vec!["my"]);
+
+        tagger
+            .tags_with_options(
+                &["오늘은".to_string(), "내일은".to_string()],
+                &TagOptions::new().custom_dicts(vec![]),
+            )
+            .await
+            .unwrap();
+        let req = last_request(&server);
+        assert_eq!(req.document.unwrap().content, "오늘은\n내일은");
+        assert!(req.custom_dict_names.is_empty());
+
+        let list = tagger
+            .taglist_with_options(
+                &["오늘은".to_string()],
+                &TagOptions::new().auto_jointing(true),
+            )
+            .await
+            .unwrap();
+        assert_eq!(list.morphs(), vec!["오늘은"]);
+        match server
+            .requests_for(MockMethod::AnalyzeSyntaxList)
+            .pop()
+            .unwrap()
+            .message
+        {
+            MockMessage::AnalyzeSyntaxList(req) => {
+                assert!(req.auto_jointing);
+                assert_eq!(req.custom_dict_names, vec!["my"]);
+            }
+            other => panic!("unexpected message {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_positional_flags_still_work() {
+        let server = MockServer::start().await.unwrap();
+        let tagger = Tagger::from_config(server.config(API_KEY), vec![])
+            .await
+            .unwrap();
+        tagger
+            .tag_with(
+                "오늘은",
+                true,
+                false,
+                true,
+                &CallOptions::new().timeout(Duration::from_secs(3)),
+            )
+            .await
+            .unwrap();
+        let req = last_request(&server);
+        assert!(req.auto_split_sentence);
+        assert!(!req.auto_spacing);
+        assert!(req.auto_jointing);
+    }
+
+    #[test]
+    fn test_resolve_custom_dicts() {
+        let default = vec!["base".to_string(), "common".to_string()];
+        assert_eq!(
+            TagOptions::new().resolve_custom_dicts(&default),
+            vec!["base", "common"]
+        );
+        assert_eq!(
+            TagOptions::new()
+                .add_custom_dicts(vec!["tenant".to_string(), "base".to_string()])
+                .resolve_custom_dicts(&default),
+            vec!["base", "common", "tenant"]
+        );
+        assert_eq!(
+            TagOptions::new()
+                .custom_dicts(vec!["tenant".to_string()])
+                .add_custom_dicts(vec!["common".to_string()])
+                .resolve_custom_dicts(&default),
+            vec!["tenant", "common"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_per_request_dicts_on_shared_tagger() {
+        let server = MockServer::start().await.unwrap();
+        let tagger = Arc::new(
+            Tagger::from_config(server.config(API_KEY), vec!["common".to_string()])
+                .await
+                .unwrap(),
+        );
+
+        let mut handles = Vec::new();
+        for tenant in ["a", "b", "c"] {
+            let tagger = tagger.clone();
+            handles.push(tokio::spawn(async move {
+                let opts = TagOptions::new().add_custom_dicts(vec![format!("tenant-{}", tenant)]);
+                tagger
+                    .morphs_with_options(&format!("{} 문장", tenant), &opts)
+                    .await
+                    .unwrap();
+            }));
+        }
+        for handle in handles {
+            handle.await.unwrap();
+        }
+
+        let requests = server.requests_for(MockMethod::AnalyzeSyntax);
+        assert_eq!(requests.len(), 3);
+        for request in requests {
+            match request.message {
+                MockMessage::AnalyzeSyntax(req) => {
+                    let content = req.document.unwrap().content;
+                    let tenant = content.split(' ').next().unwrap();
+                    assert_eq!(
+                        req.custom_dict_names,
+                        vec!["common".to_string(), format!("tenant-{}", tenant)]
+                    );
+                }
+                other => panic!("unexpected message {:?}", other),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_validate_custom_dicts() {
+        let server = MockServer::start().await.unwrap();
+        let session = BareunSession::connect(server.config(API_KEY))
+            .await
+            .unwrap();
+        let mut dict = session.custom_dict("my").unwrap();
+        dict.copy_np_set(["바른".to_string()].into_iter().collect());
+        dict.update().await.unwrap();
+
+        let tagger = session.tagger();
+        let opts = TagOptions::new()
+            .custom_dicts(vec!["my".to_string()])
+            .validate_custom_dicts(true)
+            .timeout(Duration::from_secs(3));
+        assert!(tagger.morphs_with_options("오늘은", &opts).await.is_ok());
+        // 사전 확인에도 호출 옵션의 제한 시간을 적용한다.
+        assert!(
+            server
+                .requests_for(MockMethod::GetCustomDictionaryList)
+                .pop()
+                .unwrap()
+                .grpc_timeout()
+                .is_some()
+        );
+
+        let opts = opts.add_custom_dicts(vec!["nope".to_string(), "missing".to_string()]);
+        match tagger.morphs_with_options("오늘은", &opts).await {
+            Err(BareunError::UnknownCustomDicts(names)) => {
+                assert_eq!(names, vec!["nope", "missing"])
+            }
+            other => panic!("expected UnknownCustomDicts, got {:?}", other),
+        }
+        // 확인에 실패하면 분석 요청을 보내지 않는다.
+        assert_eq!(server.requests_for(MockMethod::AnalyzeSyntax).len(), 1);
+
+        assert!(tagger.check_custom_dicts(&["my".to_string()]).await.is_ok());
+        let e = session
+            .custom_dict_client()
+            .check_exists(&["nope".to_string()])
+            .await
+            .unwrap_err();
+        assert_eq!(e.to_string(), "Unknown custom dictionaries: nope");
+    }
+}
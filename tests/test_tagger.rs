@@ -1,12 +1,44 @@
 #[cfg(test)]
 mod tests {
     use bareun_rs::Tagger;
+    use bareun_rs::mock::{MockServer, analyze_syntax_response};
 
-    #[tokio::test]
-    async fn test_tagger_pos() {
-        let mut tagger = Tagger::new("appppppiiii", "127.0.0.1", Some(5656), vec![])
+    const API_KEY: &str = "appppppiiii";
+    const SAMPLE: &str = "오늘은 정말 추운 날이네요.";
+
+    /// 예제 문장의 분석 결과를 등록한 목 서버와 그 서버에 연결된 Tagger
+    async fn mock_tagger() -> (MockServer, Tagger) {
+        let server = MockServer::start().await.unwrap();
+        server.on_analyze_syntax(
+            SAMPLE,
+            analyze_syntax_response(
+                SAMPLE,
+                &[
+                    ("오늘은", &[("오늘", "NNG"), ("은", "JX")]),
+                    ("정말", &[("정말", "MAG")]),
+                    ("추운", &[("춥", "VA"), ("ㄴ", "ETM")]),
+                    (
+                        "날이네요.",
+                        &[
+                            ("날", "NNG"),
+                            ("이", "VCP"),
+                            ("네", "EF"),
+                            ("요", "JX"),
+                            (".", "SF"),
+                        ],
+                    ),
+                ],
+            ),
+        );
+        let tagger = Tagger::from_config(server.config(API_KEY), vec![])
             .await
             .unwrap();
+        (server, tagger)
+    }
+
+    #[tokio::test]
+    async fn test_tagger_pos() {
+        let (_server, tagger) = mock_tagger().await;
         let sample1 = "오늘은 정말 추운 날이네요.";
         let result = tagger.pos(sample1, true, false, false).await.unwrap();
         // flatten=true이므로 단일 벡터로 반환됨
@@ -41,9 +73,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_tagger_pos_join() {
-        let mut tagger = Tagger::new("appppppiiii", "127.0.0.1", Some(5656), vec![])
-            .await
-            .unwrap();
+        let (_server, tagger) = mock_tagger().await;
         let sample1 = "오늘은 정말 추운 날이네요.";
         let result = tagger.pos(sample1, true, true, false).await.unwrap();
         // flatten=true이므로 단일 벡터로 반환됨
@@ -67,9 +97,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_tagger_pos_detail() {
-        let mut tagger = Tagger::new("appppppiiii", "127.0.0.1", Some(5656), vec![])
-            .await
-            .unwrap();
+        let (_server, tagger) = mock_tagger().await;
         let sample1 = "오늘은 정말 추운 날이네요.";
         let result = tagger.pos(sample1, true, false, true).await.unwrap();
         // flatten=true이므로 단일 벡터로 반환됨
@@ -104,9 +132,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_tagger_morphs() {
-        let mut tagger = Tagger::new("appppppiiii", "127.0.0.1", Some(5656), vec![])
-            .await
-            .unwrap();
+        let (_server, tagger) = mock_tagger().await;
         let sample1 = "오늘은 정말 추운 날이네요.";
         let result = tagger.morphs(sample1).await.unwrap();
         assert_eq!(
@@ -119,9 +145,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_tagger_nouns() {
-        let mut tagger = Tagger::new("appppppiiii", "127.0.0.1", Some(5656), vec![])
-            .await
-            .unwrap();
+        let (_server, tagger) = mock_tagger().await;
         let sample1 = "오늘은 정말 추운 날이네요.";
         let result = tagger.nouns(sample1).await.unwrap();
         assert_eq!(result, vec!["오늘", "날"]);
@@ -178,9 +202,7 @@ mod tests {
     // }
     #[tokio::test]
     async fn test_tagger_tag_as_msg() {
-        let mut tagger = Tagger::new("appppppiiii", "127.0.0.1", Some(5656), vec![])
-            .await
-            .unwrap();
+        let (_server, tagger) = mock_tagger().await;
         let sample1 = "오늘은 정말 추운 날이네요.";
         let m = tagger
             .tag(sample1, false, true, false)
@@ -196,19 +218,15 @@ mod tests {
 
     #[tokio::test]
     async fn test_tagger_create_custom_dict() {
-        let mut tagger = Tagger::new("appppppiiii", "127.0.0.1", Some(5656), vec![])
-            .await
-            .unwrap();
-        let cd = tagger.custom_dict("my_create");
+        let (_server, mut tagger) = mock_tagger().await;
+        let cd = tagger.custom_dict("my_create").unwrap();
         assert!(cd.domain == "my_create");
     }
 
     #[tokio::test]
     async fn test_tagger_update_custom_dict() {
-        let mut tagger = Tagger::new("appppppiiii", "127.0.0.1", Some(5656), vec![])
-            .await
-            .unwrap();
-        let cd = tagger.custom_dict("my_update");
+        let (_server, mut tagger) = mock_tagger().await;
+        let cd = tagger.custom_dict("my_update").unwrap();
         cd.copy_np_set(
             vec![
                 "유리왕".to_string(),
@@ -236,10 +254,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_tagger_get_custom_dict_np_set() {
-        let mut tagger = Tagger::new("appppppiiii", "127.0.0.1", Some(5656), vec![])
-            .await
-            .unwrap();
-        let dic = tagger.custom_dict("my_np");
+        let (_server, mut tagger) = mock_tagger().await;
+        let dic = tagger.custom_dict("my_np").unwrap();
         dic.copy_np_set(
             vec![
                 "유리왕".to_string(),
@@ -265,10 +281,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_tagger_get_custom_dict_cp_set() {
-        let mut tagger = Tagger::new("appppppiiii", "127.0.0.1", Some(5656), vec![])
-            .await
-            .unwrap();
-        let dic = tagger.custom_dict("my_cp");
+        let (_server, mut tagger) = mock_tagger().await;
+        let dic = tagger.custom_dict("my_cp").unwrap();
         dic.copy_cp_set(vec!["코로나19".to_string()].into_iter().collect());
         dic.update().await.unwrap();
 
@@ -285,10 +299,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_tagger_get_custom_dict_cp_caret_set() {
-        let mut tagger = Tagger::new("appppppiiii", "127.0.0.1", Some(5656), vec![])
-            .await
-            .unwrap();
-        let dic = tagger.custom_dict("my_caret");
+        let (_server, mut tagger) = mock_tagger().await;
+        let dic = tagger.custom_dict("my_caret").unwrap();
         dic.copy_cp_caret_set(
             vec![
                 "인공지능^데이터^학습".to_string(),
@@ -308,20 +320,18 @@ mod tests {
     }
 
     #[tokio::test]
-    #[ignore] // 실제 서버 테스트 시에만 실행
     async fn test_exception_apikey() {
-        // 잘못된 API 키로 연결 시도
-        let mut tagger = Tagger::new("invalid-api-key", "api.bareun.ai", Some(443), vec![])
+        let (server, _) = mock_tagger().await;
+        server.require_api_key(API_KEY);
+        // 잘못된 API 키로 연결 시도. 연결 자체는 성공한다.
+        let tagger = Tagger::from_config(server.config("invalid-api-key"), vec![])
             .await
-            .unwrap(); // 연결 자체는 성공할 수 있음
+            .unwrap();
 
-        let sample1 = "오늘은 정말 추운 날이네요.";
         // API 키가 잘못되면 실제 요청 시 에러 발생
-        let result = tagger.pos(sample1, true, false, false).await;
-
-        // 잘못된 API 키로 인한 에러 발생 확인
+        let result = tagger.pos(SAMPLE, true, false, false).await;
         assert!(
-            result.is_err(),
+            matches!(result, Err(bareun_rs::BareunError::PermissionDenied { .. })),
             "Expected error for invalid API key during request"
         );
     }
@@ -0,0 +1,162 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, Instant};
+
+    use bareun_rs::bareun::language_service_server::{LanguageService, LanguageServiceServer};
+    use bareun_rs::bareun::{
+        AnalyzeSyntaxListRequest, AnalyzeSyntaxListResponse, AnalyzeSyntaxRequest,
+        AnalyzeSyntaxResponse, TokenizeRequest, TokenizeResponse,
+    };
+    use bareun_rs::{BareunClientConfig, BareunError, CallOptions, Tagger, Tokenizer};
+    use tonic::transport::Server;
+    use tonic::transport::server::TcpIncoming;
+    use tonic::{Request, Response, Status};
+
+    const DELAY: Duration = Duration::from_millis(300);
+
+    /// 요청마다 DELAY만큼 기다리고, 받은 grpc-timeout 헤더를 기록하는 서버
+    #[derive(Default)]
+    struct SlowLanguageService {
+        grpc_timeouts: Arc<Mutex<Vec<Option<String>>>>,
+    }
+
+    impl SlowLanguageService {
+        async fn handle<T>(&self, request: &Request<T>) {
+            let header = request
+                .metadata()
+                .get("grpc-timeout")
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_string);
+            self.grpc_timeouts.lock().unwrap().push(header);
+            tokio::time::sleep(DELAY).await;
+        }
+    }
+
+    #[tonic::async_trait]
+    impl LanguageService for SlowLanguageService {
+        async fn analyze_syntax(
+            &self,
+            request: Request<AnalyzeSyntaxRequest>,
+        ) -> Result<Response<AnalyzeSyntaxResponse>, Status> {
+            self.handle(&request).await;
+            Ok(Response::new(AnalyzeSyntaxResponse::default()))
+        }
+
+        async fn analyze_syntax_list(
+            &self,
+            _request: Request<AnalyzeSyntaxListRequest>,
+        ) -> Result<Response<AnalyzeSyntaxListResponse>, Status> {
+            Err(Status::deadline_exceeded("server gave up"))
+        }
+
+        async fn tokenize(
+            &self,
+            request: Request<TokenizeRequest>,
+        ) -> Result<Response<TokenizeResponse>, Status> {
+            self.handle(&request).await;
+            Ok(Response::new(TokenizeResponse::default()))
+        }
+    }
+
+    async fn spawn_server() -> (u16, Arc<Mutex<Vec<Option<String>>>>) {
+        let service = SlowLanguageService::default();
+        let grpc_timeouts = service.grpc_timeouts.clone();
+        let incoming = TcpIncoming::bind("127.0.0.1:0".parse().unwrap()).unwrap();
+        let port = incoming.local_addr().unwrap().port();
+        tokio::spawn(
+            Server::builder()
+                .add_service(LanguageServiceServer::new(service))
+                .serve_with_incoming(incoming),
+        );
+        (port, grpc_timeouts)
+    }
+
+    fn config(port: u16, request_timeout: Option<Duration>) -> BareunClientConfig {
+        let mut builder = BareunClientConfig::builder("appppppiiii")
+            .host("127.0.0.1")
+            .port(port);
+        if let Some(timeout) = request_timeout {
+            builder = builder.request_timeout(timeout);
+        }
+        builder.build()
+    }
+
+    #[tokio::test]
+    async fn test_default_deadline_from_config() {
+        let (port, grpc_timeouts) = spawn_server().await;
+        let tagger = Tagger::from_config(config(port, Some(Duration::from_millis(50))), vec![])
+            .await
+            .unwrap();
+
+        let started = Instant::now();
+        let result = tagger.tag("오늘은", false, true, false).await;
+        assert!(started.elapsed() < DELAY);
+        match result {
+            Err(BareunError::Timeout { timeout, .. }) => {
+                assert_eq!(timeout, Some(Duration::from_millis(50)))
+            }
+            other => panic!("expected Timeout, got {:?}", other.err()),
+        }
+        assert!(grpc_timeouts.lock().unwrap()[0].is_some());
+    }
+
+    #[tokio::test]
+    async fn test_per_call_timeout_overrides_config() {
+        let (port, _) = spawn_server().await;
+        let tagger = Tagger::from_config(config(port, Some(Duration::from_millis(50))), vec![])
+            .await
+            .unwrap();
+
+        let opts = CallOptions::new().timeout(Duration::from_secs(5));
+        let result = tagger.tag_with("오늘은", false, true, false, &opts).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_per_call_timeout_without_default() {
+        let (port, grpc_timeouts) = spawn_server().await;
+        let tokenizer = Tokenizer::from_config(config(port, None)).await.unwrap();
+
+        assert!(tokenizer.tokenize("오늘은", false).await.is_ok());
+
+        let opts = CallOptions::new().timeout(Duration::from_millis(50));
+        let result = tokenizer.tokenize_with("오늘은", false, &opts).await;
+        assert!(matches!(result, Err(BareunError::Timeout { .. })));
+
+        let headers = grpc_timeouts.lock().unwrap();
+        assert_eq!(headers[0], None);
+        assert!(headers[1].is_some());
+    }
+
+    #[tokio::test]
+    async fn test_server_deadline_without_client_timeout() {
+        let (port, _) = spawn_server().await;
+        let tagger = Tagger::from_config(config(port, None), vec![])
+            .await
+            .unwrap();
+
+        let result = tagger.taglist(&["오늘은".to_string()], true, false).await;
+        assert!(matches!(result, Err(BareunError::DeadlineExceeded { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_dropping_future_cancels_call() {
+        let (port, _) = spawn_server().await;
+        let tagger = Tagger::from_config(config(port, None), vec![])
+            .await
+            .unwrap();
+
+        let started = Instant::now();
+        let result = tokio::time::timeout(
+            Duration::from_millis(50),
+            tagger.tag("오늘은", false, true, false),
+        )
+        .await;
+        assert!(result.is_err());
+        assert!(started.elapsed() < DELAY);
+
+        // 취소된 호출 뒤에도 같은 Tagger를 계속 쓸 수 있다.
+        assert!(tagger.tag("오늘은", false, true, false).await.is_ok());
+    }
+}
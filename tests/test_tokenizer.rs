@@ -1,14 +1,44 @@
 #[cfg(test)]
 mod tests {
+    use bareun_rs::mock::{MockServer, tokenize_response};
     use bareun_rs::{SegResult, Tokenizer};
 
+    const API_KEY: &str = "appppppiiii";
     const TEST_STR: &str = "오늘은 정말 추운 날이네요.";
 
-    #[tokio::test]
-    async fn test_tokenizer_seg_not_flatten() {
-        let mut tokenizer = Tokenizer::new("appppppiiii", "127.0.0.1", Some(5656))
+    /// 예제 문장의 분절 결과를 등록한 목 서버와 그 서버에 연결된 Tokenizer
+    async fn mock_tokenizer() -> (MockServer, Tokenizer) {
+        let server = MockServer::start().await.unwrap();
+        server.on_tokenize(
+            TEST_STR,
+            tokenize_response(
+                TEST_STR,
+                &[
+                    ("오늘은", &[("오늘", "N"), ("은", "J")]),
+                    ("정말", &[("정말", "A")]),
+                    ("추운", &[("춥", "V"), ("ㄴ", "E")]),
+                    (
+                        "날이네요.",
+                        &[
+                            ("날", "N"),
+                            ("이", "V"),
+                            ("네", "E"),
+                            ("요", "J"),
+                            (".", "S"),
+                        ],
+                    ),
+                ],
+            ),
+        );
+        let tokenizer = Tokenizer::from_config(server.config(API_KEY))
             .await
             .unwrap();
+        (server, tokenizer)
+    }
+
+    #[tokio::test]
+    async fn test_tokenizer_seg_not_flatten() {
+        let (_server, tokenizer) = mock_tokenizer().await;
         let result = tokenizer.seg(TEST_STR, false, false, false).await.unwrap();
         if let bareun_rs::SegResult::Nested(nested) = result {
             // 실제 서버 응답 구조 확인
@@ -30,9 +60,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_tokenizer_seg_join() {
-        let mut tokenizer = Tokenizer::new("appppppiiii", "127.0.0.1", Some(5656))
-            .await
-            .unwrap();
+        let (_server, tokenizer) = mock_tokenizer().await;
         let result = tokenizer.seg(TEST_STR, true, true, false).await.unwrap();
         if let SegResult::Flat(flat) = result {
             println!("Flat result with join: {:?}", flat);
@@ -59,9 +87,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_tokenizer_seg_detail() {
-        let mut tokenizer = Tokenizer::new("appppppiiii", "127.0.0.1", Some(5656))
-            .await
-            .unwrap();
+        let (_server, tokenizer) = mock_tokenizer().await;
         let result = tokenizer.seg(TEST_STR, true, false, true).await.unwrap();
         if let SegResult::Flat(flat) = result {
             assert_eq!(
@@ -86,9 +112,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_tokenizer_seg() {
-        let mut tokenizer = Tokenizer::new("appppppiiii", "127.0.0.1", Some(5656))
-            .await
-            .unwrap();
+        let (_server, tokenizer) = mock_tokenizer().await;
         let result = tokenizer.seg(TEST_STR, true, false, false).await.unwrap();
         if let SegResult::Flat(flat) = result {
             assert_eq!(
@@ -113,18 +137,14 @@ mod tests {
 
     #[tokio::test]
     async fn test_tokenizer_nouns() {
-        let mut tokenizer = Tokenizer::new("appppppiiii", "127.0.0.1", Some(5656))
-            .await
-            .unwrap();
+        let (_server, tokenizer) = mock_tokenizer().await;
         let result = tokenizer.nouns(TEST_STR).await.unwrap();
         assert_eq!(result, vec!["오늘".to_string(), "날".to_string()]);
     }
 
     #[tokio::test]
     async fn test_tokenized_nouns() {
-        let mut tokenizer = Tokenizer::new("appppppiiii", "127.0.0.1", Some(5656))
-            .await
-            .unwrap();
+        let (_server, tokenizer) = mock_tokenizer().await;
         let tokenized = tokenizer.tokenize(TEST_STR, false).await.unwrap();
         assert_eq!(
             tokenized.nouns(),
@@ -134,9 +154,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_tokenized_verbs() {
-        let mut tokenizer = Tokenizer::new("appppppiiii", "127.0.0.1", Some(5656))
-            .await
-            .unwrap();
+        let (_server, tokenizer) = mock_tokenizer().await;
         let tokenized = tokenizer.tokenize(TEST_STR, false).await.unwrap();
         assert_eq!(
             tokenized.predicates(),
@@ -146,27 +164,21 @@ mod tests {
 
     #[tokio::test]
     async fn test_tokenized_symbols() {
-        let mut tokenizer = Tokenizer::new("appppppiiii", "127.0.0.1", Some(5656))
-            .await
-            .unwrap();
+        let (_server, tokenizer) = mock_tokenizer().await;
         let tokenized = tokenizer.tokenize(TEST_STR, false).await.unwrap();
         assert_eq!(tokenized.symbols(), vec![".".to_string()]);
     }
 
     #[tokio::test]
     async fn test_tokenized_adverbs() {
-        let mut tokenizer = Tokenizer::new("appppppiiii", "127.0.0.1", Some(5656))
-            .await
-            .unwrap();
+        let (_server, tokenizer) = mock_tokenizer().await;
         let tokenized = tokenizer.tokenize(TEST_STR, false).await.unwrap();
         assert_eq!(tokenized.adverbs(), vec!["정말".to_string()]);
     }
 
     #[tokio::test]
     async fn test_tokenized_endings() {
-        let mut tokenizer = Tokenizer::new("appppppiiii", "127.0.0.1", Some(5656))
-            .await
-            .unwrap();
+        let (_server, tokenizer) = mock_tokenizer().await;
         let tokenized = tokenizer.tokenize(TEST_STR, false).await.unwrap();
         assert_eq!(
             tokenized.endings(),
@@ -176,9 +188,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_tokenized_postpositions() {
-        let mut tokenizer = Tokenizer::new("appppppiiii", "127.0.0.1", Some(5656))
-            .await
-            .unwrap();
+        let (_server, tokenizer) = mock_tokenizer().await;
         let tokenized = tokenizer.tokenize(TEST_STR, false).await.unwrap();
         assert_eq!(
             tokenized.postpositions(),
@@ -187,19 +197,18 @@ mod tests {
     }
 
     #[tokio::test]
-    #[ignore] // 실제 서버 테스트 시에만 실행
     async fn test_exception_apikey_tokenizer() {
-        // 잘못된 API 키로 연결 시도
-        let mut tokenizer = Tokenizer::new("invalid-api-key", "10.3.8.44", Some(5757))
+        let (server, _) = mock_tokenizer().await;
+        server.require_api_key(API_KEY);
+        // 잘못된 API 키로 연결 시도. 연결 자체는 성공한다.
+        let tokenizer = Tokenizer::from_config(server.config("invalid-api-key"))
             .await
-            .unwrap(); // 연결 자체는 성공할 수 있음
+            .unwrap();
 
         // API 키가 잘못되면 실제 요청 시 에러 발생
         let result = tokenizer.seg(TEST_STR, true, false, false).await;
-
-        // 잘못된 API 키로 인한 에러 발생 확인
         assert!(
-            result.is_err(),
+            matches!(result, Err(bareun_rs::BareunError::PermissionDenied { .. })),
             "Expected error for invalid API key during request"
         );
     }
@@ -0,0 +1,92 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use bareun_rs::Tagged;
+    use bareun_rs::model::{OovKind, Span, Tag};
+
+    use crate::common;
+
+    fn tagged() -> Tagged {
+        common::tagged_sentences(
+            "나비가 난다\n허리",
+            &[
+                &[
+                    ("나비가", &[("나비", "NNG"), ("가", "JKS")]),
+                    ("난다", &[("날", "VV"), ("ㄴ다", "EF")]),
+                ],
+                &[("허리", &[("허리", "NNG")])],
+            ],
+        )
+    }
+
+    #[test]
+    fn test_views_carry_indices() {
+        let tagged = tagged();
+        let sentences: Vec<_> = tagged.sentences_iter().collect();
+        assert_eq!(sentences.len(), 2);
+        assert_eq!(sentences[1].index(), 1);
+        assert_eq!(sentences[1].text(), "허리");
+        assert_eq!(sentences[0].span(), Span::new(0, 6));
+
+        let eojeols: Vec<_> = tagged.eojeols().collect();
+        assert_eq!(eojeols.len(), 3);
+        assert_eq!(eojeols[1].text(), "난다");
+        assert_eq!(eojeols[1].tagged(), "날/VV+ㄴ다/EF");
+        assert_eq!((eojeols[2].sentence_index(), eojeols[2].index()), (1, 0));
+
+        let verb = tagged.morphemes().find(|m| m.tag() == Tag::Vv).unwrap();
+        assert_eq!(verb.text(), "날");
+        assert_eq!(
+            (verb.sentence_index(), verb.token_index(), verb.index()),
+            (0, 1, 0)
+        );
+        assert_eq!(verb.oov(), OovKind::InWordEmbedding);
+        assert_eq!(verb.custom_dict(), None);
+        // 뷰는 원래 응답을 가리킨다.
+        assert!(std::ptr::eq(
+            verb.raw(),
+            &tagged.r.sentences[0].tokens[1].morphemes[0]
+        ));
+    }
+
+    #[test]
+    fn test_views_match_vec_api() {
+        let tagged = tagged();
+        let morphs: Vec<&str> = tagged.morphemes().map(|m| m.text()).collect();
+        assert_eq!(morphs, tagged.morphs());
+        assert_eq!(tagged.nouns(), vec!["나비", "허리"]);
+        assert_eq!(tagged.verbs(), vec!["날"]);
+        assert_eq!(
+            tagged
+                .sentences_iter()
+                .map(|s| s.morphemes().count())
+                .sum::<usize>(),
+            5
+        );
+    }
+
+    #[test]
+    fn test_eojeol_modified() {
+        let mut tagged = tagged();
+        tagged.r.sentences[0].tokens[1].modified = "난 다".to_string();
+
+        let eojeols: Vec<_> = tagged.eojeols().collect();
+        assert_eq!(eojeols[0].modified(), None);
+        assert!(!eojeols[0].is_modified());
+        assert_eq!(eojeols[1].modified(), Some("난 다"));
+        assert_eq!(eojeols[1].span(), Span::new(4, 2));
+        let changed: Vec<_> = tagged
+            .eojeols()
+            .filter(|e| e.is_modified())
+            .map(|e| e.text())
+            .collect();
+        assert_eq!(changed, vec!["난다"]);
+        assert_eq!(
+            tagged.document().unwrap().sentences[0].eojeols[1]
+                .modified
+                .as_deref(),
+            Some("난 다")
+        );
+    }
+}